@@ -84,6 +84,9 @@ pub enum VariableDeclarationKind {
     Var,
     Let,
     Const,
+    Using,
+    #[serde(rename = "await using")]
+    AwaitUsing,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -106,12 +106,15 @@ pub enum ExportKind {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
 #[serde(tag = "type")]
 pub struct ExportSpecifier {
     #[serde(flatten)]
     pub base: BaseNode,
     pub local: Identifier,
     pub exported: IdOrString,
+    #[serde(default)]
+    pub export_kind: Option<ExportKind>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
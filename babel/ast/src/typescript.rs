@@ -329,6 +329,8 @@ pub struct TSIndexSignature {
     pub type_annotation: Option<Box<TSTypeAnnotation>>,
     #[serde(default)]
     pub readonly: Option<bool>,
+    #[serde(default, rename = "static")]
+    pub is_static: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -915,4 +917,10 @@ pub struct TSTypeParameter {
     pub default: Option<Box<TSType>>,
     #[serde(default)]
     pub name: JsWord,
+    #[serde(default, rename = "in")]
+    pub is_in: Option<bool>,
+    #[serde(default, rename = "out")]
+    pub is_out: Option<bool>,
+    #[serde(default, rename = "const")]
+    pub is_const: Option<bool>,
 }
@@ -106,6 +106,8 @@ impl Babelify for VarDeclKind {
             VarDeclKind::Var => VariableDeclarationKind::Var,
             VarDeclKind::Let => VariableDeclarationKind::Let,
             VarDeclKind::Const => VariableDeclarationKind::Const,
+            VarDeclKind::Using { is_await: false } => VariableDeclarationKind::Using,
+            VarDeclKind::Using { is_await: true } => VariableDeclarationKind::AwaitUsing,
         }
     }
 }
@@ -159,7 +159,7 @@ impl Babelify for ImportDecl {
             specifiers: self.specifiers.babelify(ctx),
             source: self.src.babelify(ctx),
             assertions: convert_import_asserts(self.asserts, ctx),
-            import_kind: if self.type_only {
+            import_kind: if self.import_kind.is_type() {
                 Some(ImportKind::Type)
             } else {
                 None
@@ -191,7 +191,7 @@ impl Babelify for NamedExport {
             specifiers: self.specifiers.babelify(ctx),
             source: self.src.map(|s| s.babelify(ctx)),
             assertions: convert_import_asserts(self.asserts, ctx),
-            export_kind: if self.type_only {
+            export_kind: if self.export_kind.is_type() {
                 Some(ExportKind::Type)
             } else {
                 None
@@ -265,7 +265,11 @@ impl Babelify for ImportNamedSpecifier {
             base: ctx.base(self.span),
             local: self.local.clone().babelify(ctx),
             imported: IdOrString::Id(self.imported.unwrap_or(self.local).babelify(ctx)),
-            import_kind: None,
+            import_kind: if self.import_kind.is_type() {
+                Some(ImportKind::Type)
+            } else {
+                None
+            },
         }
     }
 }
@@ -313,6 +317,11 @@ impl Babelify for ExportNamedSpecifier {
             base: ctx.base(self.span),
             local: self.orig.clone().babelify(ctx),
             exported: IdOrString::Id(self.exported.unwrap_or(self.orig).babelify(ctx)),
+            export_kind: if self.export_kind.is_type() {
+                Some(ExportKind::Type)
+            } else {
+                None
+            },
         }
     }
 }
@@ -150,6 +150,21 @@ impl VisitMut for Normalizer {
         }
         node.visit_mut_children_with(self);
     }
+
+    // ------------------------------------------------------------------------
+    // typescript
+    fn visit_mut_ts_type_parameter(&mut self, node: &mut TSTypeParameter) {
+        if node.is_in == None {
+            node.is_in = Some(false);
+        }
+        if node.is_out == None {
+            node.is_out = Some(false);
+        }
+        if node.is_const == None {
+            node.is_const = Some(false);
+        }
+        node.visit_mut_children_with(self);
+    }
 }
 
 pub fn normalize(ast: &mut File) {
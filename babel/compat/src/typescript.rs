@@ -128,6 +128,9 @@ impl Babelify for TsTypeParam {
             name: self.name.sym,
             constraint: self.constraint.map(|c| Box::alloc().init(c.babelify(ctx))),
             default: self.default.map(|d| Box::alloc().init(d.babelify(ctx))),
+            is_in: Some(self.is_in),
+            is_out: Some(self.is_out),
+            is_const: Some(self.is_const),
         }
     }
 }
@@ -303,6 +306,7 @@ impl Babelify for TsIndexSignature {
                 .type_ann
                 .map(|ann| Box::alloc().init(ann.babelify(ctx))),
             readonly: Some(self.readonly),
+            is_static: Some(self.is_static),
         }
     }
 }
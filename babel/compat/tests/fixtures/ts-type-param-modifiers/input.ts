@@ -0,0 +1 @@
+class C<const T, in U, out V> {}
@@ -435,6 +435,8 @@ define!({
         Var,
         Let,
         Const,
+        Using,
+        AwaitUsing,
     }
     pub struct VariableDeclarator {
         pub base: BaseNode,
@@ -1470,6 +1472,7 @@ define!({
         pub base: BaseNode,
         pub local: Identifier,
         pub exported: IdOrString,
+        pub export_kind: Option<ExportKind>,
     }
     pub struct ExportDefaultSpecifier {
         pub base: BaseNode,
@@ -1969,6 +1972,7 @@ define!({
         pub paramters: Vec<Identifier>,
         pub type_annotation: Option<Box<TSTypeAnnotation>>,
         pub readonly: Option<bool>,
+        pub is_static: Option<bool>,
     }
     pub struct TSAnyKeyword {
         pub base: BaseNode,
@@ -2240,5 +2244,8 @@ define!({
         pub constraint: Option<Box<TSType>>,
         pub default: Option<Box<TSType>>,
         pub name: JsWord,
+        pub is_in: Option<bool>,
+        pub is_out: Option<bool>,
+        pub is_const: Option<bool>,
     }
 });
@@ -72,6 +72,7 @@ where
                                         span: DUMMY_SP,
                                         orig: orig.clone(),
                                         exported: Some(exported.clone()),
+                                        export_kind: ExportKind::Value,
                                     });
                                     addtional_items.push((
                                         module_id,
@@ -80,7 +81,7 @@ where
                                                 span: DUMMY_SP.with_ctxt(injected_ctxt),
                                                 specifiers: vec![specifier],
                                                 src: None,
-                                                type_only: false,
+                                                export_kind: ExportKind::Value,
                                                 asserts: None,
                                             },
                                         )),
@@ -720,13 +720,14 @@ where
                             span: DUMMY_SP,
                             orig: local,
                             exported: Some(exported),
+                            export_kind: ExportKind::Value,
                         });
                         extra.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
                             NamedExport {
                                 span: export.span.with_ctxt(injected_ctxt),
                                 specifiers: vec![specifier],
                                 src: None,
-                                type_only: false,
+                                export_kind: ExportKind::Value,
                                 asserts: None,
                             },
                         )));
@@ -766,6 +767,7 @@ where
                             span: DUMMY_SP,
                             orig: local,
                             exported: Some(exported),
+                            export_kind: ExportKind::Value,
                         });
                         log::trace!("Exporting `default` with `export default expr`");
                         extra.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
@@ -773,7 +775,7 @@ where
                                 span: export.span.with_ctxt(injected_ctxt),
                                 specifiers: vec![specifier],
                                 src: None,
-                                type_only: false,
+                                export_kind: ExportKind::Value,
                                 asserts: None,
                             },
                         )));
@@ -832,12 +834,13 @@ where
                                                     span: DUMMY_SP,
                                                     orig: id,
                                                     exported: Some(exported),
+                                                    export_kind: ExportKind::Value,
                                                 }
                                             })
                                             .map(ExportSpecifier::Named)
                                             .collect(),
                                         src: None,
-                                        type_only: false,
+                                        export_kind: ExportKind::Value,
                                         asserts: None,
                                     }));
                                 extra.push(export);
@@ -870,6 +873,7 @@ where
                             span: DUMMY_SP,
                             orig: local,
                             exported: Some(exported),
+                            export_kind: ExportKind::Value,
                         });
 
                         extra.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
@@ -877,7 +881,7 @@ where
                                 span: export.span.with_ctxt(injected_ctxt),
                                 specifiers: vec![specifier],
                                 src: None,
-                                type_only: false,
+                                export_kind: ExportKind::Value,
                                 asserts: None,
                             },
                         )));
@@ -951,6 +955,7 @@ where
                                                         span: ns.span,
                                                         orig: module_var.into(),
                                                         exported: Some(ns.name.clone()),
+                                                        export_kind: ExportKind::Value,
                                                     });
                                                 extra.push(ModuleItem::ModuleDecl(
                                                     ModuleDecl::ExportNamed(NamedExport {
@@ -958,7 +963,7 @@ where
                                                         specifiers: vec![specifier],
                                                         src: None,
                                                         asserts: None,
-                                                        type_only: false,
+                                                        export_kind: ExportKind::Value,
                                                     }),
                                                 ));
                                             }
@@ -262,7 +262,7 @@ where
                             span,
                             specifiers: vec![],
                             src: src.clone(),
-                            type_only: false,
+                            import_kind: ImportKind::Value,
                             asserts: None,
                         };
 
@@ -478,6 +478,7 @@ where
                                         span: DUMMY_SP,
                                         local: Ident::new(id.0, DUMMY_SP.with_ctxt(id.1)),
                                         imported: None,
+                                        import_kind: ImportKind::Value,
                                     })
                                 })
                                 .collect();
@@ -637,11 +638,12 @@ where
                                     span,
                                     local: ident,
                                     imported: None,
+                                    import_kind: ImportKind::Value,
                                 })
                             })
                             .collect(),
                         src,
-                        type_only: false,
+                        import_kind: ImportKind::Value,
                         asserts: None,
                     };
 
@@ -14,8 +14,8 @@ use rayon::iter::ParallelIterator;
 use swc_atoms::js_word;
 use swc_common::{sync::Lrc, FileName, SourceFile, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::{
-    CallExpr, Expr, ExprOrSuper, Ident, ImportDecl, ImportSpecifier, Invalid, MemberExpr, Module,
-    ModuleDecl, Str,
+    CallExpr, Expr, ExprOrSuper, Ident, ImportDecl, ImportKind, ImportSpecifier, Invalid,
+    MemberExpr, Module, ModuleDecl, Str,
 };
 use swc_ecma_transforms::resolver_with_mark;
 use swc_ecma_visit::{noop_visit_type, FoldWith, Node, Visit, VisitWith};
@@ -294,7 +294,7 @@ where
                             span: src.span,
                             specifiers: vec![],
                             src,
-                            type_only: false,
+                            import_kind: ImportKind::Value,
                             asserts: None,
                         },
                         true,
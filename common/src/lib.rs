@@ -41,6 +41,21 @@ pub trait AstNode: Debug + PartialEq + Clone + Spanned + Serialize {
     const TYPE: &'static str;
 }
 
+/// The `type` of a node in its serialized form, like `"TsTypeReference"`.
+///
+/// This is implemented by `#[ast_node("Type")]` for structs. For enums,
+/// `#[ast_node]` returns the tag of the variant or, if the variant has multiple
+/// tags or the wildcard tag `*`, the type of the node in the variant.
+pub trait NodeType {
+    fn node_type(&self) -> &'static str;
+}
+
+impl<T: ?Sized + NodeType> NodeType for Box<T> {
+    fn node_type(&self) -> &'static str {
+        (**self).node_type()
+    }
+}
+
 pub mod comments;
 mod eq;
 pub mod errors;
@@ -1,4 +1,8 @@
-use serde::Deserialize;
+use serde::{
+    de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use std::{fmt, marker::PhantomData};
 
 #[derive(Deserialize)]
 pub struct Node<T> {
@@ -13,3 +17,231 @@ pub struct Type {
     #[serde(rename = "type")]
     pub ty: String,
 }
+
+/// Entered for every nested object and array while an enum with
+/// `#[deserialize_guard(Guard)]` buffers its input, and dropped when the
+/// object or array ends. An error aborts deserialization, which can be used to
+/// limit the depth of recursive nodes.
+pub trait DeserializeGuard: Sized {
+    fn enter<E: de::Error>() -> Result<Self, E>;
+}
+
+/// Deserializer which enters `G` for every nested map and sequence of `D`.
+///
+/// Only `deserialize_any` is supported, which is enough to buffer the input.
+pub struct GuardedDeserializer<D, G> {
+    inner: D,
+    _guard: PhantomData<G>,
+}
+
+impl<D, G> GuardedDeserializer<D, G> {
+    pub fn new(inner: D) -> Self {
+        GuardedDeserializer {
+            inner,
+            _guard: PhantomData,
+        }
+    }
+}
+
+impl<'de, D, G> Deserializer<'de> for GuardedDeserializer<D, G>
+where
+    D: Deserializer<'de>,
+    G: DeserializeGuard,
+{
+    type Error = D::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner.deserialize_any(Guarded::<_, G>::new(visitor))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Wraps the visitor, seeds and accesses of a [GuardedDeserializer], so that
+/// nested values are deserialized with a [GuardedDeserializer] too.
+struct Guarded<T, G> {
+    inner: T,
+    _guard: PhantomData<G>,
+}
+
+impl<T, G> Guarded<T, G> {
+    fn new(inner: T) -> Self {
+        Guarded {
+            inner,
+            _guard: PhantomData,
+        }
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty),)*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V, G> Visitor<'de> for Guarded<V, G>
+where
+    V: Visitor<'de>,
+    G: DeserializeGuard,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    forward_visit!(
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_str(&str),
+        visit_borrowed_str(&'de str),
+        visit_string(String),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    );
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.inner.visit_none()
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.inner.visit_unit()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.inner
+            .visit_some(GuardedDeserializer::<_, G>::new(deserializer))
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.inner
+            .visit_newtype_struct(GuardedDeserializer::<_, G>::new(deserializer))
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let _guard = G::enter::<A::Error>()?;
+        self.inner.visit_seq(Guarded::<_, G>::new(seq))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let _guard = G::enter::<A::Error>()?;
+        self.inner.visit_map(Guarded::<_, G>::new(map))
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::EnumAccess<'de>,
+    {
+        self.inner.visit_enum(data)
+    }
+}
+
+impl<'de, T, G> DeserializeSeed<'de> for Guarded<T, G>
+where
+    T: DeserializeSeed<'de>,
+    G: DeserializeGuard,
+{
+    type Value = T::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.inner
+            .deserialize(GuardedDeserializer::<_, G>::new(deserializer))
+    }
+}
+
+impl<'de, A, G> SeqAccess<'de> for Guarded<A, G>
+where
+    A: SeqAccess<'de>,
+    G: DeserializeGuard,
+{
+    type Error = A::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.inner.next_element_seed(Guarded::<_, G>::new(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A, G> MapAccess<'de> for Guarded<A, G>
+where
+    A: MapAccess<'de>,
+    G: DeserializeGuard,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        self.inner.next_key_seed(Guarded::<_, G>::new(seed))
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        self.inner.next_value_seed(Guarded::<_, G>::new(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
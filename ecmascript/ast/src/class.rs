@@ -5,7 +5,7 @@ use crate::{
     prop::PropName,
     stmt::BlockStmt,
     typescript::{
-        Accessibility, TsExprWithTypeArgs, TsIndexSignature, TsTypeAnn, TsTypeParamDecl,
+        Accessibility, Modifiers, TsExprWithTypeArgs, TsIndexSignature, TsTypeAnn, TsTypeParamDecl,
         TsTypeParamInstantiation,
     },
     EmptyStmt,
@@ -114,6 +114,18 @@ pub struct ClassProp {
     pub definite: bool,
 }
 
+impl ClassProp {
+    pub fn modifiers(&self) -> Modifiers {
+        Modifiers {
+            accessibility: self.accessibility,
+            is_static: self.is_static,
+            is_abstract: self.is_abstract,
+            is_override: self.is_override,
+            readonly: self.readonly,
+        }
+    }
+}
+
 #[ast_node("PrivateProperty")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -159,6 +171,18 @@ pub struct PrivateProp {
     pub definite: bool,
 }
 
+impl PrivateProp {
+    pub fn modifiers(&self) -> Modifiers {
+        Modifiers {
+            accessibility: self.accessibility,
+            is_static: self.is_static,
+            is_abstract: self.is_abstract,
+            is_override: self.is_override,
+            readonly: self.readonly,
+        }
+    }
+}
+
 macro_rules! method {
     ($name:ident, $ty:literal, $KEY:ty) => {
         #[ast_node($ty)]
@@ -191,6 +215,18 @@ macro_rules! method {
             #[serde(default)]
             pub is_override: bool,
         }
+
+        impl $name {
+            pub fn modifiers(&self) -> Modifiers {
+                Modifiers {
+                    accessibility: self.accessibility,
+                    is_static: self.is_static,
+                    is_abstract: self.is_abstract,
+                    is_override: self.is_override,
+                    readonly: false,
+                }
+            }
+        }
     };
 }
 
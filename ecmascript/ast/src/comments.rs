@@ -0,0 +1,139 @@
+//! Access to the comments of a node.
+
+use swc_common::{
+    comments::{Comment, Comments},
+    Span, Spanned,
+};
+
+/// Reads and writes the comments of a node in a [Comments] store.
+///
+/// Like the parser and the code generator, leading comments are keyed by the
+/// start of the span of a node and trailing comments by its end. For example,
+/// the doc comment of `interface I {}` is stored at the start of the
+/// [TsInterfaceDecl](crate::TsInterfaceDecl), or of the `declare` or `export`
+/// keyword if there's one.
+///
+/// Nodes created by transforms usually have a dummy span, so comments should
+/// be moved to a real span with [NodeComments::move_comments] when a node is
+/// replaced.
+pub trait NodeComments: Comments {
+    fn add_leading_to<N: ?Sized + Spanned>(&self, node: &N, cmt: Comment) {
+        self.add_leading(node.span().lo(), cmt)
+    }
+
+    fn add_trailing_to<N: ?Sized + Spanned>(&self, node: &N, cmt: Comment) {
+        self.add_trailing(node.span().hi(), cmt)
+    }
+
+    /// Returns the leading comments of `node` without removing them.
+    fn leading_of<N: ?Sized + Spanned>(&self, node: &N) -> Vec<Comment> {
+        let pos = node.span().lo();
+        match self.take_leading(pos) {
+            Some(comments) => {
+                self.add_leading_comments(pos, comments.clone());
+                comments
+            }
+            None => vec![],
+        }
+    }
+
+    /// Returns the trailing comments of `node` without removing them.
+    fn trailing_of<N: ?Sized + Spanned>(&self, node: &N) -> Vec<Comment> {
+        let pos = node.span().hi();
+        match self.take_trailing(pos) {
+            Some(comments) => {
+                self.add_trailing_comments(pos, comments.clone());
+                comments
+            }
+            None => vec![],
+        }
+    }
+
+    /// Moves the leading and trailing comments of a node at `from` to a node
+    /// at `to`.
+    fn move_comments(&self, from: Span, to: Span) {
+        if from.lo() != to.lo() {
+            self.move_leading(from.lo(), to.lo());
+        }
+        if from.hi() != to.hi() {
+            self.move_trailing(from.hi(), to.hi());
+        }
+    }
+}
+
+impl<C: ?Sized + Comments> NodeComments for C {}
+
+#[cfg(test)]
+mod tests {
+    use super::NodeComments;
+    use crate::*;
+    use swc_common::{
+        comments::{Comment, CommentKind, SingleThreadedComments},
+        BytePos, Span, Spanned, DUMMY_SP,
+    };
+
+    fn span(lo: u32, hi: u32) -> Span {
+        Span::new(BytePos(lo), BytePos(hi), Default::default())
+    }
+
+    fn doc(text: &str) -> Comment {
+        Comment {
+            kind: CommentKind::Block,
+            span: DUMMY_SP,
+            text: text.into(),
+        }
+    }
+
+    fn interface(span: Span) -> TsInterfaceDecl {
+        TsInterfaceDecl {
+            span,
+            id: Ident::new("I".into(), DUMMY_SP),
+            declare: false,
+            type_params: None,
+            extends: vec![],
+            body: TsInterfaceBody {
+                span: DUMMY_SP,
+                body: vec![],
+            },
+        }
+    }
+
+    fn with_comment<N: Spanned>(c: &SingleThreadedComments, node: N, text: &str) -> N {
+        c.add_leading_to(&node, doc(text));
+        node
+    }
+
+    #[test]
+    fn leading_and_trailing() {
+        let c = SingleThreadedComments::default();
+        let decl = with_comment(&c, interface(span(10, 25)), "* Docs ");
+        c.add_trailing_to(&decl, doc(" trailing "));
+
+        assert_eq!(c.leading_of(&decl), vec![doc("* Docs ")]);
+        // Reading the comments doesn't remove them.
+        assert_eq!(c.leading_of(&decl), vec![doc("* Docs ")]);
+        assert_eq!(c.trailing_of(&decl), vec![doc(" trailing ")]);
+        assert_eq!(c.leading_of(&interface(span(11, 25))), vec![]);
+    }
+
+    #[test]
+    fn move_to_replacement() {
+        let c = SingleThreadedComments::default();
+        let decl = with_comment(&c, interface(span(10, 25)), "* Docs ");
+        let alias = TsTypeAliasDecl {
+            span: span(40, 60),
+            declare: false,
+            id: Ident::new("T".into(), DUMMY_SP),
+            type_params: None,
+            type_ann: Box::new(TsType::TsTypeLit(TsTypeLit {
+                span: DUMMY_SP,
+                members: vec![],
+            })),
+        };
+
+        c.move_comments(decl.span, alias.span);
+
+        assert_eq!(c.leading_of(&decl), vec![]);
+        assert_eq!(c.leading_of(&alias), vec![doc("* Docs ")]);
+    }
+}
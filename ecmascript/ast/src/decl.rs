@@ -7,7 +7,11 @@ use crate::{
     typescript::{TsEnumDecl, TsInterfaceDecl, TsModuleDecl, TsTypeAliasDecl},
 };
 use is_macro::Is;
-use string_enum::StringEnum;
+use serde::{
+    de::{self, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{fmt, str::FromStr};
 use swc_common::EqIgnoreSpan;
 use swc_common::{ast_node, Span};
 
@@ -77,7 +81,7 @@ pub struct VarDecl {
     pub decls: Vec<VarDeclarator>,
 }
 
-#[derive(StringEnum, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, EqIgnoreSpan)]
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum VarDeclKind {
     /// `var`
@@ -86,6 +90,103 @@ pub enum VarDeclKind {
     Let,
     /// `const`
     Const,
+    /// `using` or `await using`
+    Using { is_await: bool },
+}
+
+impl VarDeclKind {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            VarDeclKind::Var => "var",
+            VarDeclKind::Let => "let",
+            VarDeclKind::Const => "const",
+            VarDeclKind::Using { is_await: false } => "using",
+            VarDeclKind::Using { is_await: true } => "await using",
+        }
+    }
+
+    /// Returns `true` for every kind except `var`, as the bindings of the
+    /// other kinds are scoped to the enclosing block.
+    pub fn is_block_scoped(self) -> bool {
+        self != VarDeclKind::Var
+    }
+
+    /// Returns `true` for `using` and `await using` declarations of the
+    /// explicit resource management proposal.
+    pub fn is_using(self) -> bool {
+        matches!(self, VarDeclKind::Using { .. })
+    }
+
+    /// Returns `true` for `await using`, whose resources are disposed
+    /// asynchronously.
+    pub fn is_await_using(self) -> bool {
+        self == VarDeclKind::Using { is_await: true }
+    }
+}
+
+impl fmt::Debug for VarDeclKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for VarDeclKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl FromStr for VarDeclKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        Ok(match s {
+            "var" => VarDeclKind::Var,
+            "let" => VarDeclKind::Let,
+            "const" => VarDeclKind::Const,
+            "using" => VarDeclKind::Using { is_await: false },
+            "await using" => VarDeclKind::Using { is_await: true },
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Serialized as the `kind` of babel's `VariableDeclaration`.
+impl Serialize for VarDeclKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for VarDeclKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct KindVisitor;
+
+        impl<'de> Visitor<'de> for KindVisitor {
+            type Value = VarDeclKind;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("one of 'var', 'let', 'const', 'using', 'await using'")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                value
+                    .parse()
+                    .map_err(|()| de::Error::invalid_value(Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_str(KindVisitor)
+    }
 }
 
 #[ast_node("VariableDeclarator")]
@@ -0,0 +1,373 @@
+//! Serialization without [TsParenthesizedType] nodes.
+//!
+//! `@babel/parser` doesn't create `TSParenthesizedType` nodes unless
+//! `createParenthesizedExpressions` is enabled, so tools consuming its ast
+//! expect the inner type directly.
+
+use crate::typescript::TsParenthesizedType;
+use serde::ser::{self, Serialize, Serializer};
+
+/// Serializes the wrapped node like its [Serialize] implementation, but
+/// replaces each [TsParenthesizedType] with the type it wraps.
+///
+/// The node itself is not modified.
+///
+/// ```rust,ignore
+/// // `type T = (A | B)` is serialized as `type T = A | B`
+/// serde_json::to_string(&ElideParenthesizedTypes(&module))
+/// ```
+#[derive(Debug)]
+pub struct ElideParenthesizedTypes<'a, T: ?Sized>(pub &'a T);
+
+impl<T: ?Sized + Serialize> Serialize for ElideParenthesizedTypes<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(Elider(serializer))
+    }
+}
+
+/// Name of [TsParenthesizedType] passed to `Serializer::serialize_struct`.
+const PARENS_NAME: &str = "TsParenthesizedType";
+/// Serialized name of [TsParenthesizedType::type_ann].
+const PARENS_TYPE_ANN: &str = "typeAnnotation";
+
+/// Forwards everything to the inner serializer, except for
+/// [TsParenthesizedType].
+struct Elider<S>(S);
+
+enum ElideStruct<S: Serializer> {
+    Struct(S::SerializeStruct),
+    Parens {
+        serializer: Option<S>,
+        ok: Option<S::Ok>,
+    },
+}
+
+macro_rules! forward {
+    ($($method:ident($ty:ty),)*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<S::Ok, S::Error> {
+                self.0.$method(v)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for Elider<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Elider<S::SerializeSeq>;
+    type SerializeTuple = Elider<S::SerializeTuple>;
+    type SerializeTupleStruct = Elider<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Elider<S::SerializeTupleVariant>;
+    type SerializeMap = Elider<S::SerializeMap>;
+    type SerializeStruct = ElideStruct<S>;
+    type SerializeStructVariant = Elider<S::SerializeStructVariant>;
+
+    forward!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_unit_struct(&'static str),
+    );
+
+    fn serialize_none(self) -> Result<S::Ok, S::Error> {
+        self.0.serialize_none()
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<S::Ok, S::Error> {
+        self.0.serialize_some(&ElideParenthesizedTypes(value))
+    }
+
+    fn serialize_unit(self) -> Result<S::Ok, S::Error> {
+        self.0.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.0
+            .serialize_newtype_struct(name, &ElideParenthesizedTypes(value))
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize_newtype_variant(
+            name,
+            variant_index,
+            variant,
+            &ElideParenthesizedTypes(value),
+        )
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        self.0.serialize_seq(len).map(Elider)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, S::Error> {
+        self.0.serialize_tuple(len).map(Elider)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, S::Error> {
+        self.0.serialize_tuple_struct(name, len).map(Elider)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        self.0
+            .serialize_tuple_variant(name, variant_index, variant, len)
+            .map(Elider)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        self.0.serialize_map(len).map(Elider)
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        if name == PARENS_NAME {
+            return Ok(ElideStruct::Parens {
+                serializer: Some(self.0),
+                ok: None,
+            });
+        }
+
+        self.0.serialize_struct(name, len).map(ElideStruct::Struct)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        self.0
+            .serialize_struct_variant(name, variant_index, variant, len)
+            .map(Elider)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+impl<S: Serializer> ser::SerializeStruct for ElideStruct<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), S::Error> {
+        match self {
+            ElideStruct::Struct(s) => s.serialize_field(key, &ElideParenthesizedTypes(value)),
+            ElideStruct::Parens { serializer, ok } => {
+                // The tag and the span of the parenthesized type are dropped.
+                if key == PARENS_TYPE_ANN {
+                    if let Some(serializer) = serializer.take() {
+                        *ok = Some(value.serialize(Elider(serializer))?);
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), S::Error> {
+        match self {
+            ElideStruct::Struct(s) => s.skip_field(key),
+            ElideStruct::Parens { .. } => Ok(()),
+        }
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        match self {
+            ElideStruct::Struct(s) => s.end(),
+            ElideStruct::Parens { ok, .. } => ok.ok_or_else(|| {
+                ser::Error::custom("parenthesized type without a type annotation")
+            }),
+        }
+    }
+}
+
+impl<S: ser::SerializeSeq> ser::SerializeSeq for Elider<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), S::Error> {
+        self.0.serialize_element(&ElideParenthesizedTypes(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: ser::SerializeTuple> ser::SerializeTuple for Elider<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), S::Error> {
+        self.0.serialize_element(&ElideParenthesizedTypes(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: ser::SerializeTupleStruct> ser::SerializeTupleStruct for Elider<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), S::Error> {
+        self.0.serialize_field(&ElideParenthesizedTypes(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: ser::SerializeTupleVariant> ser::SerializeTupleVariant for Elider<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), S::Error> {
+        self.0.serialize_field(&ElideParenthesizedTypes(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: ser::SerializeMap> ser::SerializeMap for Elider<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), S::Error> {
+        self.0.serialize_key(&ElideParenthesizedTypes(key))
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), S::Error> {
+        self.0.serialize_value(&ElideParenthesizedTypes(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: ser::SerializeStructVariant> ser::SerializeStructVariant for Elider<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), S::Error> {
+        self.0.serialize_field(key, &ElideParenthesizedTypes(value))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), S::Error> {
+        self.0.skip_field(key)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ElideParenthesizedTypes;
+    use crate::*;
+    use serde_json::json;
+    use swc_common::DUMMY_SP;
+
+    fn tref(name: &str) -> Box<TsType> {
+        Box::new(TsType::TsTypeRef(TsTypeRef {
+            span: DUMMY_SP,
+            type_name: TsEntityName::Ident(Ident::new(name.into(), DUMMY_SP)),
+            type_params: None,
+        }))
+    }
+
+    fn alias(type_ann: TsType) -> TsTypeAliasDecl {
+        TsTypeAliasDecl {
+            span: DUMMY_SP,
+            declare: false,
+            id: Ident::new("T".into(), DUMMY_SP),
+            type_params: None,
+            type_ann: Box::new(type_ann),
+        }
+    }
+
+    #[test]
+    fn elide_parenthesized_union() {
+        let union: TsType = TsUnionType {
+            span: DUMMY_SP,
+            types: vec![tref("A"), tref("B")],
+        }
+        .into();
+        // type T = (A | B)
+        let decl = alias(TsType::TsParenthesizedType(TsParenthesizedType {
+            span: DUMMY_SP,
+            type_ann: Box::new(union.clone()),
+        }));
+
+        let with_parens = serde_json::to_value(&decl).unwrap();
+        let elided = serde_json::to_value(&ElideParenthesizedTypes(&decl)).unwrap();
+
+        assert_eq!(
+            with_parens["typeAnnotation"]["type"],
+            json!("TsParenthesizedType")
+        );
+        assert_eq!(elided["typeAnnotation"]["type"], json!("TsUnionType"));
+        assert_eq!(elided, serde_json::to_value(&alias(union)).unwrap());
+    }
+}
@@ -22,7 +22,6 @@ use swc_common::{ast_node, Span, Spanned, DUMMY_SP};
 
 #[ast_node]
 #[derive(Eq, Hash, Is, EqIgnoreSpan)]
-#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Expr {
     #[tag("ThisExpression")]
     This(ThisExpr),
@@ -0,0 +1,176 @@
+//! [Arbitrary] implementations for recursive nodes.
+//!
+//! Derived implementations pick the first variant of an enum once the input is
+//! exhausted, so enums like [TsEntityName], whose first variant contains the
+//! enum itself, recurse until the stack overflows. The enums below generate a
+//! leaf node instead once the input is empty or they are nested deeper than
+//! [MAX_ARBITRARY_DEPTH].
+
+use crate::{Expr, JSXObject, TsEntityName, TsType};
+use arbitrary::{Arbitrary, Result, Unstructured};
+use std::cell::Cell;
+
+/// Maximum nesting depth of generated [TsType]s, [TsEntityName]s, [Expr]s and
+/// [JSXObject]s.
+pub const MAX_ARBITRARY_DEPTH: usize = 32;
+
+thread_local!(static DEPTH: Cell<usize> = Cell::new(0));
+
+/// Implements [Arbitrary] for `$T` using `$leaf` as the variant for the
+/// deepest nodes.
+macro_rules! bounded_arbitrary {
+    ($T:ident, leaf: $leaf:ident, [$($variant:ident),* $(,)?]) => {
+        impl Arbitrary for $T {
+            fn arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+                let depth = DEPTH.with(Cell::get);
+                if depth >= MAX_ARBITRARY_DEPTH || u.is_empty() {
+                    return Ok($T::$leaf(u.arbitrary()?));
+                }
+
+                let variants: &[fn(&mut Unstructured<'_>) -> Result<Self>] =
+                    &[$(|u| Ok($T::$variant(u.arbitrary()?))),*];
+                let index = u.int_in_range(0..=variants.len() as u32 - 1)?;
+
+                DEPTH.with(|d| d.set(depth + 1));
+                let node = variants[index as usize](u);
+                DEPTH.with(|d| d.set(depth));
+
+                node
+            }
+        }
+    };
+}
+
+bounded_arbitrary!(
+    TsType,
+    leaf: TsKeywordType,
+    [
+        TsKeywordType,
+        TsThisType,
+        TsFnOrConstructorType,
+        TsTypeRef,
+        TsTypeQuery,
+        TsTypeLit,
+        TsArrayType,
+        TsTupleType,
+        TsOptionalType,
+        TsRestType,
+        TsUnionOrIntersectionType,
+        TsConditionalType,
+        TsInferType,
+        TsParenthesizedType,
+        TsTypeOperator,
+        TsIndexedAccessType,
+        TsMappedType,
+        TsLitType,
+        TsTypePredicate,
+        TsImportType,
+    ]
+);
+
+bounded_arbitrary!(TsEntityName, leaf: Ident, [TsQualifiedName, Ident]);
+
+bounded_arbitrary!(
+    Expr,
+    leaf: This,
+    [
+        This,
+        Array,
+        Object,
+        Fn,
+        Unary,
+        Update,
+        Bin,
+        Assign,
+        Member,
+        Cond,
+        Call,
+        New,
+        Seq,
+        Ident,
+        Lit,
+        Tpl,
+        TaggedTpl,
+        Arrow,
+        Class,
+        Yield,
+        MetaProp,
+        Await,
+        Paren,
+        JSXMember,
+        JSXNamespacedName,
+        JSXEmpty,
+        JSXElement,
+        JSXFragment,
+        TsTypeAssertion,
+        TsConstAssertion,
+        TsNonNull,
+        TsAs,
+        PrivateName,
+        OptChain,
+        Invalid,
+    ]
+);
+
+bounded_arbitrary!(JSXObject, leaf: Ident, [JSXMemberExpr, Ident]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exhausted_input() {
+        // The derived implementation recursed forever here.
+        let mut u = Unstructured::new(&[]);
+        assert!(TsEntityName::arbitrary(&mut u).is_err());
+
+        let mut u = Unstructured::new(&[]);
+        assert!(TsType::arbitrary(&mut u).unwrap().is_ts_keyword_type());
+    }
+
+    /// Nesting depth of `ty`, counting `ty` itself.
+    fn type_depth(ty: &TsType) -> usize {
+        let mut max = 0;
+        crate::typescript::for_each_child_type(ty, &mut |ty| max = max.max(type_depth(ty)));
+        max + 1
+    }
+
+    #[test]
+    fn bounded_generation() {
+        let mut generated = 0;
+        let mut state = 0x2545_f491_u32;
+
+        for _ in 0..256 {
+            // Printable ascii, so generated identifiers are valid utf8.
+            let data = (0..4096)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 17;
+                    state ^= state << 5;
+                    (state % 95) as u8 + b' '
+                })
+                .collect::<Vec<_>>();
+            let mut u = Unstructured::new(&data);
+
+            if let Ok(ty) = TsType::arbitrary(&mut u) {
+                generated += 1;
+                // The root is generated at depth 0 and the leaves at
+                // `MAX_ARBITRARY_DEPTH`.
+                assert!(type_depth(&ty) <= MAX_ARBITRARY_DEPTH + 1);
+            }
+            assert_eq!(DEPTH.with(Cell::get), 0);
+        }
+
+        assert_ne!(generated, 0);
+    }
+
+    #[test]
+    fn depth_is_bounded() {
+        // Zeroes always pick `TsQualifiedName`, until the depth limit is reached.
+        let data = vec![0; 4096];
+        let mut u = Unstructured::new(&data);
+
+        assert!(TsEntityName::arbitrary(&mut u).is_err());
+        assert_eq!(DEPTH.with(Cell::get), 0);
+    }
+}
@@ -0,0 +1,295 @@
+//! Conversion of types to [JSON Schema](https://json-schema.org) (draft 7).
+//!
+//! Only the fragment of the type system which can be decided from the syntax
+//! alone is supported, as types like references can't be resolved without a
+//! type checker.
+
+use crate::{
+    Expr, Lit, TsInterfaceDecl, TsKeywordTypeKind, TsLit, TsTupleType, TsType, TsTypeElement,
+    TsUnionOrIntersectionType,
+};
+use serde_json::{json, Map, Number, Value};
+
+impl TsType {
+    /// Converts the type to a JSON Schema.
+    ///
+    /// Keyword types, literal types, arrays, tuples, unions, intersections and
+    /// type literals with property signatures are supported. Returns `None` if
+    /// the type contains anything else, like a type reference or a conditional
+    /// or mapped type.
+    pub fn to_json_schema(&self) -> Option<Value> {
+        match self {
+            TsType::TsKeywordType(ty) => keyword(ty.kind),
+            TsType::TsLitType(ty) => lit(&ty.lit),
+            TsType::TsArrayType(ty) => Some(json!({
+                "type": "array",
+                "items": ty.elem_type.to_json_schema()?,
+            })),
+            TsType::TsTupleType(ty) => tuple(ty),
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(ty)) => {
+                Some(json!({ "anyOf": all(&ty.types)? }))
+            }
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+                ty,
+            )) => Some(json!({ "allOf": all(&ty.types)? })),
+            TsType::TsParenthesizedType(ty) => ty.type_ann.to_json_schema(),
+            TsType::TsTypeLit(ty) => object(&ty.members),
+            _ => None,
+        }
+    }
+}
+
+impl TsInterfaceDecl {
+    /// Converts the body of the interface to a JSON Schema, like
+    /// [TsType::to_json_schema] does for type literals.
+    ///
+    /// Returns `None` for generic interfaces and interfaces with `extends`
+    /// clauses.
+    pub fn to_json_schema(&self) -> Option<Value> {
+        if self.type_params.is_some() || !self.extends.is_empty() {
+            return None;
+        }
+
+        object(&self.body.body)
+    }
+}
+
+fn all(types: &[Box<TsType>]) -> Option<Vec<Value>> {
+    types.iter().map(|ty| ty.to_json_schema()).collect()
+}
+
+fn keyword(kind: TsKeywordTypeKind) -> Option<Value> {
+    let ty = match kind {
+        TsKeywordTypeKind::TsAnyKeyword | TsKeywordTypeKind::TsUnknownKeyword => {
+            return Some(json!({}))
+        }
+        TsKeywordTypeKind::TsNeverKeyword => return Some(json!({ "not": {} })),
+        TsKeywordTypeKind::TsStringKeyword => json!("string"),
+        TsKeywordTypeKind::TsNumberKeyword => json!("number"),
+        TsKeywordTypeKind::TsBooleanKeyword => json!("boolean"),
+        TsKeywordTypeKind::TsNullKeyword => json!("null"),
+        // Arrays are objects in TypeScript, but not in JSON Schema.
+        TsKeywordTypeKind::TsObjectKeyword => json!(["object", "array"]),
+
+        // There are no such values in JSON.
+        TsKeywordTypeKind::TsBigIntKeyword
+        | TsKeywordTypeKind::TsSymbolKeyword
+        | TsKeywordTypeKind::TsVoidKeyword
+        | TsKeywordTypeKind::TsUndefinedKeyword
+        | TsKeywordTypeKind::TsIntrinsicKeyword => return None,
+    };
+
+    Some(json!({ "type": ty }))
+}
+
+fn lit(lit: &TsLit) -> Option<Value> {
+    let value = match lit {
+        TsLit::Number(n) => {
+            if n.value.fract() == 0.0 && n.value.abs() < (1u64 << 53) as f64 {
+                Value::from(n.value as i64)
+            } else {
+                Value::Number(Number::from_f64(n.value)?)
+            }
+        }
+        TsLit::Str(s) => Value::from(&*s.value),
+        TsLit::Bool(b) => Value::from(b.value),
+        TsLit::BigInt(..) | TsLit::Tpl(..) => return None,
+    };
+
+    Some(json!({ "const": value }))
+}
+
+fn tuple(ty: &TsTupleType) -> Option<Value> {
+    let mut items = vec![];
+    let mut min_items = 0;
+
+    for elem in &ty.elem_types {
+        match &elem.ty {
+            TsType::TsOptionalType(ty) => items.push(ty.type_ann.to_json_schema()?),
+            TsType::TsRestType(..) => return None,
+            ty => {
+                items.push(ty.to_json_schema()?);
+                min_items = items.len();
+            }
+        }
+    }
+
+    Some(json!({
+        "type": "array",
+        "items": items,
+        "minItems": min_items,
+        "maxItems": items.len(),
+    }))
+}
+
+/// Only property signatures with an identifier or a string as key are
+/// supported.
+fn object(members: &[TsTypeElement]) -> Option<Value> {
+    let mut properties = Map::new();
+    let mut required = vec![];
+
+    for member in members {
+        let prop = match member {
+            TsTypeElement::TsPropertySignature(prop) if !prop.computed => prop,
+            _ => return None,
+        };
+        let key = match &*prop.key {
+            Expr::Ident(i) => i.sym.to_string(),
+            Expr::Lit(Lit::Str(s)) => s.value.to_string(),
+            _ => return None,
+        };
+        let schema = match &prop.type_ann {
+            Some(ann) => ann.type_ann.to_json_schema()?,
+            None => json!({}),
+        };
+
+        if !prop.optional {
+            required.push(Value::from(&*key));
+        }
+        properties.insert(key, schema);
+    }
+
+    Some(json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Ident, Str, TsArrayType, TsKeywordType, TsLitType, TsOptionalType, TsPropertySignature,
+        TsTupleElement, TsTypeAnn, TsTypeLit, TsUnionType,
+    };
+    use swc_common::DUMMY_SP;
+
+    fn keyword(kind: TsKeywordTypeKind) -> TsType {
+        TsType::TsKeywordType(TsKeywordType {
+            span: DUMMY_SP,
+            kind,
+        })
+    }
+
+    fn str_lit(value: &str) -> TsType {
+        TsType::TsLitType(TsLitType {
+            span: DUMMY_SP,
+            lit: TsLit::Str(Str {
+                span: DUMMY_SP,
+                value: value.into(),
+                has_escape: false,
+                kind: Default::default(),
+            }),
+        })
+    }
+
+    fn prop(key: &str, optional: bool, ty: TsType) -> TsTypeElement {
+        TsTypeElement::TsPropertySignature(TsPropertySignature {
+            span: DUMMY_SP,
+            readonly: false,
+            key: Box::new(Expr::Ident(Ident::new(key.into(), DUMMY_SP))),
+            computed: false,
+            optional,
+            init: None,
+            params: vec![],
+            type_ann: Some(TsTypeAnn {
+                span: DUMMY_SP,
+                type_ann: Box::new(ty),
+            }),
+            type_params: None,
+        })
+    }
+
+    #[test]
+    fn keywords_and_literals() {
+        assert_eq!(
+            keyword(TsKeywordTypeKind::TsStringKeyword).to_json_schema(),
+            Some(json!({ "type": "string" }))
+        );
+        assert_eq!(keyword(TsKeywordTypeKind::TsAnyKeyword).to_json_schema(), Some(json!({})));
+        assert_eq!(keyword(TsKeywordTypeKind::TsUndefinedKeyword).to_json_schema(), None);
+        assert_eq!(str_lit("a").to_json_schema(), Some(json!({ "const": "a" })));
+    }
+
+    #[test]
+    fn compound_types() {
+        // ("a" | "b")[]
+        let union = TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(
+            TsUnionType {
+                span: DUMMY_SP,
+                types: vec![Box::new(str_lit("a")), Box::new(str_lit("b"))],
+            },
+        ));
+        let array = TsType::TsArrayType(TsArrayType {
+            span: DUMMY_SP,
+            elem_type: Box::new(union),
+        });
+        assert_eq!(
+            array.to_json_schema(),
+            Some(json!({
+                "type": "array",
+                "items": { "anyOf": [{ "const": "a" }, { "const": "b" }] },
+            }))
+        );
+
+        // [string, null?]
+        let tuple = TsType::TsTupleType(TsTupleType {
+            span: DUMMY_SP,
+            elem_types: vec![
+                TsTupleElement {
+                    span: DUMMY_SP,
+                    label: None,
+                    ty: keyword(TsKeywordTypeKind::TsStringKeyword),
+                },
+                TsTupleElement {
+                    span: DUMMY_SP,
+                    label: None,
+                    ty: TsType::TsOptionalType(TsOptionalType {
+                        span: DUMMY_SP,
+                        type_ann: Box::new(keyword(TsKeywordTypeKind::TsNullKeyword)),
+                    }),
+                },
+            ],
+        });
+        assert_eq!(
+            tuple.to_json_schema(),
+            Some(json!({
+                "type": "array",
+                "items": [{ "type": "string" }, { "type": "null" }],
+                "minItems": 1,
+                "maxItems": 2,
+            }))
+        );
+    }
+
+    #[test]
+    fn type_literal() {
+        // { a: string; b?: number }
+        let ty = TsType::TsTypeLit(TsTypeLit {
+            span: DUMMY_SP,
+            members: vec![
+                prop("a", false, keyword(TsKeywordTypeKind::TsStringKeyword)),
+                prop("b", true, keyword(TsKeywordTypeKind::TsNumberKeyword)),
+            ],
+        });
+        assert_eq!(
+            ty.to_json_schema(),
+            Some(json!({
+                "type": "object",
+                "properties": {
+                    "a": { "type": "string" },
+                    "b": { "type": "number" },
+                },
+                "required": ["a"],
+            }))
+        );
+
+        // { a: symbol }
+        let ty = TsType::TsTypeLit(TsTypeLit {
+            span: DUMMY_SP,
+            members: vec![prop("a", false, keyword(TsKeywordTypeKind::TsSymbolKeyword))],
+        });
+        assert_eq!(ty.to_json_schema(), None);
+    }
+}
@@ -13,7 +13,6 @@ use swc_common::{ast_node, Span};
 #[ast_node]
 #[derive(Eq, Hash, Is, EqIgnoreSpan)]
 #[allow(variant_size_differences)]
-#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum JSXObject {
     #[tag("JSXMemberExpression")]
     JSXMemberExpr(Box<JSXMemberExpr>),
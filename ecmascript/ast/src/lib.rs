@@ -11,7 +11,9 @@ pub use self::{
         Class, ClassMember, ClassMethod, ClassProp, Constructor, Decorator, MethodKind,
         PrivateMethod, PrivateProp,
     },
+    comments::NodeComments,
     decl::{ClassDecl, Decl, FnDecl, VarDecl, VarDeclKind, VarDeclarator},
+    elide_parens::ElideParenthesizedTypes,
     expr::{
         ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BlockStmtOrExpr, CallExpr, ClassExpr,
         CondExpr, Expr, ExprOrSpread, ExprOrSuper, FnExpr, MemberExpr, MetaPropExpr, NewExpr,
@@ -30,9 +32,9 @@ pub use self::{
     module::{Module, ModuleItem, Program, Script},
     module_decl::{
         DefaultDecl, ExportAll, ExportDecl, ExportDefaultDecl, ExportDefaultExpr,
-        ExportDefaultSpecifier, ExportNamedSpecifier, ExportNamespaceSpecifier, ExportSpecifier,
-        ImportDecl, ImportDefaultSpecifier, ImportNamedSpecifier, ImportSpecifier,
-        ImportStarAsSpecifier, ModuleDecl, NamedExport,
+        ExportDefaultSpecifier, ExportKind, ExportNamedSpecifier, ExportNamespaceSpecifier,
+        ExportSpecifier, ImportDecl, ImportDefaultSpecifier, ImportKind, ImportNamedSpecifier,
+        ImportSpecifier, ImportStarAsSpecifier, ModuleDecl, NamedExport,
     },
     operators::{AssignOp, BinaryOp, UnaryOp, UpdateOp},
     pat::{
@@ -42,28 +44,33 @@ pub use self::{
         AssignProp, ComputedPropName, GetterProp, KeyValueProp, MethodProp, Prop, PropName,
         SetterProp,
     },
+    spanless::SpanlessType,
     stmt::{
         BlockStmt, BreakStmt, CatchClause, ContinueStmt, DebuggerStmt, DoWhileStmt, EmptyStmt,
         ExprStmt, ForInStmt, ForOfStmt, ForStmt, IfStmt, LabeledStmt, ReturnStmt, Stmt, SwitchCase,
         SwitchStmt, ThrowStmt, TryStmt, VarDeclOrExpr, VarDeclOrPat, WhileStmt, WithStmt,
     },
     typescript::{
-        Accessibility, TruePlusMinus, TsArrayType, TsAsExpr, TsCallSignatureDecl,
-        TsConditionalType, TsConstAssertion, TsConstructSignatureDecl, TsConstructorType,
-        TsEntityName, TsEnumDecl, TsEnumMember, TsEnumMemberId, TsExportAssignment,
-        TsExprWithTypeArgs, TsExternalModuleRef, TsFnOrConstructorType, TsFnParam, TsFnType,
-        TsGetterSignature, TsImportEqualsDecl, TsImportType, TsIndexSignature, TsIndexedAccessType,
-        TsInferType, TsInterfaceBody, TsInterfaceDecl, TsIntersectionType, TsKeywordType,
-        TsKeywordTypeKind, TsLit, TsLitType, TsMappedType, TsMethodSignature, TsModuleBlock,
-        TsModuleDecl, TsModuleName, TsModuleRef, TsNamespaceBody, TsNamespaceDecl,
-        TsNamespaceExportDecl, TsNonNullExpr, TsOptionalType, TsParamProp, TsParamPropParam,
-        TsParenthesizedType, TsPropertySignature, TsQualifiedName, TsRestType, TsSetterSignature,
-        TsThisType, TsThisTypeOrIdent, TsTplLitType, TsTupleElement, TsTupleType, TsType,
-        TsTypeAliasDecl, TsTypeAnn, TsTypeAssertion, TsTypeElement, TsTypeLit, TsTypeOperator,
+        with_max_ts_type_depth, Accessibility, HasReturnType, Modifiers, TruePlusMinus,
+        TsArrayType, TsAsExpr, TsCallSignatureDecl, TsConditionalType, TsConstAssertion,
+        TsConstructSignatureDecl, TsConstructorType, TsEntityName, TsEnumDecl, TsEnumMember,
+        TsEnumMemberId, TsError, TsErrorKind, TsExportAssignment, TsExprWithTypeArgs,
+        TsExternalModuleRef, TsFnOrConstructorType, TsFnParam, TsFnType, TsGetterSignature,
+        TsImportEqualsDecl, TsImportType, TsIndexSignature, TsIndexedAccessType, TsInferType,
+        TsInterfaceBody, TsInterfaceDecl, TsIntersectionType, TsKeywordType, TsKeywordTypeKind,
+        TsLit, TsLitType, TsMappedType, TsMethodSignature, TsModuleBlock, TsModuleDecl,
+        TsModuleName, TsModuleRef, TsNamespaceBody, TsNamespaceDecl, TsNamespaceExportDecl,
+        TsNonNullExpr, TsOptionalType, TsParamProp, TsParamPropParam, TsParenthesizedType,
+        TsPropertySignature, TsQualifiedName, TsRestType, TsSetterSignature, TsThisType,
+        TsThisTypeOrIdent, TsTplLitType, TsTupleElement, TsTupleType, TsType, TsTypeAliasDecl,
+        TsTypeAnn, TsTypeAssertion, TsTypeContext, TsTypeElement, TsTypeLit, TsTypeOperator,
         TsTypeOperatorOp, TsTypeParam, TsTypeParamDecl, TsTypeParamInstantiation, TsTypePredicate,
         TsTypeQuery, TsTypeQueryExpr, TsTypeRef, TsUnionOrIntersectionType, TsUnionType,
+        WellKnownType, DEFAULT_MAX_TS_TYPE_DEPTH,
     },
 };
+#[cfg(feature = "arbitrary")]
+pub use self::fuzz::MAX_ARBITRARY_DEPTH;
 use serde::Deserialize;
 use serde::Serialize;
 use swc_common::EqIgnoreSpan;
@@ -72,10 +79,16 @@ use swc_common::{ast_node, Span};
 #[macro_use]
 mod macros;
 mod class;
+mod comments;
 mod decl;
+mod elide_parens;
 mod expr;
 mod function;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
 mod ident;
+#[cfg(feature = "json-schema")]
+mod json_schema;
 mod jsx;
 mod lit;
 mod module;
@@ -83,8 +96,10 @@ mod module_decl;
 mod operators;
 mod pat;
 mod prop;
+mod spanless;
 mod stmt;
 mod typescript;
+mod typescript_display;
 
 /// Represents a invalid node.
 #[ast_node("Invalid")]
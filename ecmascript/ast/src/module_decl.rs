@@ -7,6 +7,7 @@ use crate::{
     ObjectLit,
 };
 use is_macro::Is;
+use string_enum::StringEnum;
 use swc_common::EqIgnoreSpan;
 use swc_common::{ast_node, Span};
 
@@ -74,8 +75,9 @@ pub struct ImportDecl {
     #[serde(rename = "source")]
     pub src: Str,
 
-    #[serde(rename = "typeOnly")]
-    pub type_only: bool,
+    /// `type` in `import type { foo } from 'mod'`
+    #[serde(default)]
+    pub import_kind: ImportKind,
 
     #[serde(default)]
     pub asserts: Option<ObjectLit>,
@@ -108,8 +110,9 @@ pub struct NamedExport {
     #[serde(rename = "source")]
     pub src: Option<Str>,
 
-    #[serde(rename = "typeOnly")]
-    pub type_only: bool,
+    /// `type` in `export type { foo } from 'mod'`
+    #[serde(default)]
+    pub export_kind: ExportKind,
 
     #[serde(default)]
     pub asserts: Option<ObjectLit>,
@@ -182,6 +185,10 @@ pub struct ImportNamedSpecifier {
 
     #[serde(default)]
     pub imported: Option<Ident>,
+
+    /// `type` in `import { type foo } from 'mod'`
+    #[serde(default)]
+    pub import_kind: ImportKind,
 }
 
 #[ast_node]
@@ -227,4 +234,53 @@ pub struct ExportNamedSpecifier {
     /// `Some(bar)` in `export { foo as bar }`
     #[serde(default)]
     pub exported: Option<Ident>,
+    /// `type` in `export { type foo }`
+    #[serde(default)]
+    pub export_kind: ExportKind,
+}
+
+/// `importKind` of an import declaration or specifier.
+#[derive(StringEnum, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ImportKind {
+    /// `value`
+    Value,
+    /// `type`
+    Type,
+}
+
+impl Default for ImportKind {
+    fn default() -> Self {
+        ImportKind::Value
+    }
+}
+
+impl ImportKind {
+    /// Returns `true` for `import type` and `import { type foo }`.
+    pub fn is_type(self) -> bool {
+        self == ImportKind::Type
+    }
+}
+
+/// `exportKind` of an export declaration or specifier.
+#[derive(StringEnum, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ExportKind {
+    /// `value`
+    Value,
+    /// `type`
+    Type,
+}
+
+impl Default for ExportKind {
+    fn default() -> Self {
+        ExportKind::Value
+    }
+}
+
+impl ExportKind {
+    /// Returns `true` for `export type` and `export { type foo }`.
+    pub fn is_type(self) -> bool {
+        self == ExportKind::Type
+    }
 }
@@ -0,0 +1,194 @@
+use crate::{
+    Ident, TsEntityName, TsFnOrConstructorType, TsLit, TsThisTypeOrIdent, TsType,
+    TsTypeParamInstantiation, TsTypeQueryExpr, TsUnionOrIntersectionType,
+};
+use std::{
+    hash::{Hash, Hasher},
+    mem,
+};
+use swc_common::EqIgnoreSpan;
+
+/// A reference to a type which is compared with
+/// [eq_ignore_span](EqIgnoreSpan::eq_ignore_span) and hashed without spans,
+/// so it can be used as a key of a `HashMap` regardless of where the type
+/// appears in the source.
+///
+/// Only a part of the type is hashed, e.g. the members of type literals are
+/// skipped, but types which are equal always have the same hash.
+#[derive(Debug, Clone, Copy)]
+pub struct SpanlessType<'a>(pub &'a TsType);
+
+impl PartialEq for SpanlessType<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_span(other.0)
+    }
+}
+
+impl Eq for SpanlessType<'_> {}
+
+impl Hash for SpanlessType<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_type(self.0, state)
+    }
+}
+
+fn hash_type<H: Hasher>(ty: &TsType, state: &mut H) {
+    mem::discriminant(ty).hash(state);
+
+    match ty {
+        TsType::TsKeywordType(ty) => ty.kind.hash(state),
+        TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(ty)) => {
+            hash_type(&ty.type_ann.type_ann, state)
+        }
+        TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsConstructorType(ty)) => {
+            hash_type(&ty.type_ann.type_ann, state)
+        }
+        TsType::TsTypeRef(ty) => {
+            hash_entity_name(&ty.type_name, state);
+            hash_type_args(ty.type_params.as_ref(), state);
+        }
+        TsType::TsTypeQuery(ty) => match &ty.expr_name {
+            TsTypeQueryExpr::TsEntityName(name) => hash_entity_name(name, state),
+            TsTypeQueryExpr::Import(import) => import.arg.value.hash(state),
+        },
+        TsType::TsArrayType(ty) => hash_type(&ty.elem_type, state),
+        TsType::TsTupleType(ty) => {
+            ty.elem_types.len().hash(state);
+            for elem in &ty.elem_types {
+                hash_type(&elem.ty, state);
+            }
+        }
+        TsType::TsOptionalType(ty) => hash_type(&ty.type_ann, state),
+        TsType::TsRestType(ty) => hash_type(&ty.type_ann, state),
+        TsType::TsUnionOrIntersectionType(ty) => {
+            mem::discriminant(ty).hash(state);
+            let types = match ty {
+                TsUnionOrIntersectionType::TsUnionType(ty) => &ty.types,
+                TsUnionOrIntersectionType::TsIntersectionType(ty) => &ty.types,
+            };
+            types.len().hash(state);
+            for ty in types {
+                hash_type(ty, state);
+            }
+        }
+        TsType::TsConditionalType(ty) => {
+            hash_type(&ty.check_type, state);
+            hash_type(&ty.extends_type, state);
+            hash_type(&ty.true_type, state);
+            hash_type(&ty.false_type, state);
+        }
+        TsType::TsInferType(ty) => hash_ident(&ty.type_param.name, state),
+        TsType::TsParenthesizedType(ty) => hash_type(&ty.type_ann, state),
+        TsType::TsTypeOperator(ty) => {
+            ty.op.hash(state);
+            hash_type(&ty.type_ann, state);
+        }
+        TsType::TsIndexedAccessType(ty) => {
+            ty.readonly.hash(state);
+            hash_type(&ty.obj_type, state);
+            hash_type(&ty.index_type, state);
+        }
+        TsType::TsMappedType(ty) => hash_ident(&ty.type_param.name, state),
+        TsType::TsLitType(ty) => hash_lit(&ty.lit, state),
+        TsType::TsTypePredicate(ty) => {
+            ty.asserts.hash(state);
+            match &ty.param_name {
+                TsThisTypeOrIdent::TsThisType(..) => {}
+                TsThisTypeOrIdent::Ident(i) => hash_ident(i, state),
+            }
+        }
+        TsType::TsImportType(ty) => {
+            ty.arg.value.hash(state);
+            if let Some(qualifier) = &ty.qualifier {
+                hash_entity_name(qualifier, state);
+            }
+        }
+        TsType::TsThisType(..) | TsType::TsTypeLit(..) => {}
+    }
+}
+
+fn hash_type_args<H: Hasher>(args: Option<&TsTypeParamInstantiation>, state: &mut H) {
+    if let Some(args) = args {
+        args.params.len().hash(state);
+        for ty in &args.params {
+            hash_type(ty, state);
+        }
+    }
+}
+
+fn hash_entity_name<H: Hasher>(name: &TsEntityName, state: &mut H) {
+    for ident in name.segments() {
+        hash_ident(ident, state);
+    }
+}
+
+/// The syntax context is a part of the span, so it's ignored like the span.
+fn hash_ident<H: Hasher>(i: &Ident, state: &mut H) {
+    i.sym.hash(state);
+}
+
+fn hash_lit<H: Hasher>(lit: &TsLit, state: &mut H) {
+    mem::discriminant(lit).hash(state);
+
+    match lit {
+        // `0` and `-0` are equal.
+        TsLit::Number(n) if n.value == 0.0 => 0u64.hash(state),
+        TsLit::Number(n) => n.value.to_bits().hash(state),
+        TsLit::Str(s) => s.value.hash(state),
+        TsLit::Bool(b) => b.value.hash(state),
+        TsLit::BigInt(b) => b.value.hash(state),
+        TsLit::Tpl(tpl) => tpl.types.len().hash(state),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TsArrayType, TsTypeRef};
+    use std::collections::{hash_map::DefaultHasher, HashMap};
+    use swc_common::{BytePos, Span};
+
+    fn array_of(name: &str, lo: u32) -> TsType {
+        let span = Span::new(BytePos(lo), BytePos(lo + 3), Default::default());
+        TsType::TsArrayType(TsArrayType {
+            span,
+            elem_type: Box::new(TsType::TsTypeRef(TsTypeRef {
+                span,
+                type_name: TsEntityName::Ident(Ident::new(name.into(), span)),
+                type_params: None,
+            })),
+        })
+    }
+
+    fn hash(ty: &TsType) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        SpanlessType(ty).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn ignores_spans() {
+        let a = array_of("A", 0);
+        let moved = array_of("A", 10);
+        let b = array_of("B", 0);
+
+        assert_ne!(a, moved);
+        assert_eq!(SpanlessType(&a), SpanlessType(&moved));
+        assert_eq!(hash(&a), hash(&moved));
+
+        assert_ne!(SpanlessType(&a), SpanlessType(&b));
+        assert_ne!(hash(&a), hash(&b));
+    }
+
+    #[test]
+    fn hash_map_key() {
+        let a = array_of("A", 0);
+        let moved = array_of("A", 10);
+
+        let mut map = HashMap::new();
+        map.insert(SpanlessType(&a), 1);
+
+        assert_eq!(map.get(&SpanlessType(&moved)), Some(&1));
+        assert_eq!(map.get(&SpanlessType(&array_of("B", 0))), None);
+    }
+}
@@ -2,12 +2,16 @@
 #![allow(missing_copy_implementations)]
 use crate::BindingIdent;
 use crate::{
-    class::Decorator,
-    expr::Expr,
+    class::{ClassProp, Decorator},
+    expr::{AssignExpr, Expr, ExprOrSuper, MemberExpr, PatOrExpr, ThisExpr},
+    function::Param,
     ident::Ident,
-    lit::{Bool, Number, Str},
+    lit::{Bool, Lit, Number, Str},
     module::ModuleItem,
+    operators::AssignOp,
     pat::{ArrayPat, AssignPat, ObjectPat, Pat, RestPat},
+    prop::PropName,
+    stmt::{ExprStmt, Stmt},
     BigInt, TplElement,
 };
 use is_macro::Is;
@@ -15,10 +19,12 @@ use serde::{
     de::{self, Unexpected, Visitor},
     Deserialize, Deserializer, Serialize,
 };
-use std::fmt;
+use std::{cell::Cell, convert::TryFrom, fmt, iter::FromIterator, ops::Index};
 use string_enum::StringEnum;
+use swc_atoms::JsWord;
 use swc_common::EqIgnoreSpan;
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, serializer::DeserializeGuard, Span, Spanned, DUMMY_SP};
+use unicode_xid::UnicodeXID;
 
 #[ast_node("TsTypeAnnotation")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
@@ -38,6 +44,42 @@ pub struct TsTypeParamDecl {
     pub params: Vec<TsTypeParam>,
 }
 
+impl TsTypeParamDecl {
+    /// Creates a type parameter declaration with a dummy span.
+    pub fn from_params(params: Vec<TsTypeParam>) -> Self {
+        TsTypeParamDecl {
+            span: DUMMY_SP,
+            params,
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&TsTypeParam> {
+        self.params.get(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+}
+
+impl Index<usize> for TsTypeParamDecl {
+    type Output = TsTypeParam;
+
+    fn index(&self, index: usize) -> &TsTypeParam {
+        &self.params[index]
+    }
+}
+
+impl FromIterator<TsTypeParam> for TsTypeParamDecl {
+    fn from_iter<I: IntoIterator<Item = TsTypeParam>>(iter: I) -> Self {
+        Self::from_params(iter.into_iter().collect())
+    }
+}
+
 #[ast_node("TsTypeParameter")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -45,6 +87,18 @@ pub struct TsTypeParam {
     pub span: Span,
     pub name: Ident,
 
+    /// `in T`
+    #[serde(default, rename = "in")]
+    pub is_in: bool,
+
+    /// `out T`
+    #[serde(default, rename = "out")]
+    pub is_out: bool,
+
+    /// `const T`
+    #[serde(default, rename = "const")]
+    pub is_const: bool,
+
     #[serde(default)]
     pub constraint: Option<Box<TsType>>,
 
@@ -60,6 +114,42 @@ pub struct TsTypeParamInstantiation {
     pub params: Vec<Box<TsType>>,
 }
 
+impl TsTypeParamInstantiation {
+    /// Creates a type argument list with a dummy span.
+    pub fn from_types(params: Vec<Box<TsType>>) -> Self {
+        TsTypeParamInstantiation {
+            span: DUMMY_SP,
+            params,
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&TsType> {
+        self.params.get(index).map(|ty| &**ty)
+    }
+
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+}
+
+impl Index<usize> for TsTypeParamInstantiation {
+    type Output = TsType;
+
+    fn index(&self, index: usize) -> &TsType {
+        &self.params[index]
+    }
+}
+
+impl FromIterator<Box<TsType>> for TsTypeParamInstantiation {
+    fn from_iter<I: IntoIterator<Item = Box<TsType>>>(iter: I) -> Self {
+        Self::from_types(iter.into_iter().collect())
+    }
+}
+
 #[ast_node("TsParameterProperty")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -67,13 +157,103 @@ pub struct TsParamProp {
     pub span: Span,
     #[serde(default)]
     pub decorators: Vec<Decorator>,
-    /// At least one of `accessibility` or `readonly` must be set.
+    /// At least one of `accessibility`, `is_override` or `readonly` must be
+    /// set.
     #[serde(default)]
     pub accessibility: Option<Accessibility>,
     pub readonly: bool,
+    #[serde(default)]
+    pub is_override: bool,
     pub param: TsParamPropParam,
 }
 
+impl TsParamProp {
+    /// Returns the accessibility of the parameter property, which is
+    /// [Accessibility::Public] if there's no modifier.
+    pub fn effective_accessibility(&self) -> Accessibility {
+        self.accessibility.unwrap_or_default()
+    }
+
+    pub fn modifiers(&self) -> Modifiers {
+        Modifiers {
+            accessibility: self.accessibility,
+            is_override: self.is_override,
+            readonly: self.readonly,
+            ..Default::default()
+        }
+    }
+
+    /// Lowers the parameter property to the class field it declares and the
+    /// `this.x = x` statement which initializes the field in the constructor.
+    ///
+    /// `param` is the identifier of the parameter which replaces the
+    /// parameter property, see [TsParamProp::to_param]. The field keeps the
+    /// original name.
+    pub fn expand(&self, param: Ident) -> (ClassProp, Stmt) {
+        let binding = self.param.binding();
+
+        let prop = ClassProp {
+            span: self.span,
+            key: Box::new(Expr::Ident(binding.id.clone())),
+            value: None,
+            type_ann: binding.type_ann.clone(),
+            is_static: false,
+            decorators: self.decorators.clone(),
+            computed: false,
+            accessibility: self.accessibility,
+            is_abstract: false,
+            is_optional: false,
+            is_override: self.is_override,
+            readonly: self.readonly,
+            declare: false,
+            definite: false,
+        };
+
+        let assign = Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: AssignOp::Assign,
+                left: PatOrExpr::Expr(Box::new(Expr::Member(MemberExpr {
+                    span: DUMMY_SP,
+                    obj: ExprOrSuper::Expr(Box::new(Expr::This(ThisExpr { span: DUMMY_SP }))),
+                    prop: Box::new(Expr::Ident(binding.id.clone())),
+                    computed: false,
+                }))),
+                right: Box::new(Expr::Ident(param)),
+            })),
+        });
+
+        (prop, assign)
+    }
+
+    /// Returns the plain parameter which replaces the parameter property. The
+    /// default value of [TsParamPropParam::Assign] becomes the default value
+    /// of the parameter.
+    pub fn to_param(&self, param: Ident) -> Param {
+        let binding = BindingIdent {
+            id: param,
+            type_ann: self.param.binding().type_ann.clone(),
+        };
+
+        let pat = match &self.param {
+            TsParamPropParam::Ident(..) => Pat::Ident(binding),
+            TsParamPropParam::Assign(pat) => Pat::Assign(AssignPat {
+                span: pat.span,
+                left: Box::new(Pat::Ident(binding)),
+                right: pat.right.clone(),
+                type_ann: pat.type_ann.clone(),
+            }),
+        };
+
+        Param {
+            span: self.span,
+            decorators: Default::default(),
+            pat,
+        }
+    }
+}
+
 #[ast_node]
 #[derive(Eq, Hash, Is, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -85,6 +265,24 @@ pub enum TsParamPropParam {
     Assign(AssignPat),
 }
 
+impl TsParamPropParam {
+    /// Returns the identifier bound by the parameter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the left side of [TsParamPropParam::Assign] isn't an
+    /// identifier, which the parser doesn't allow.
+    pub fn binding(&self) -> &BindingIdent {
+        match self {
+            TsParamPropParam::Ident(i) => i,
+            TsParamPropParam::Assign(AssignPat { left, .. }) => match &**left {
+                Pat::Ident(i) => i,
+                _ => unreachable!("destructuring pattern inside TsParameterProperty"),
+            },
+        }
+    }
+}
+
 #[ast_node("TsQualifiedName")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -95,10 +293,28 @@ pub struct TsQualifiedName {
     pub right: Ident,
 }
 
+impl From<TsEntityName> for Expr {
+    fn from(n: TsEntityName) -> Self {
+        match n {
+            TsEntityName::Ident(i) => Expr::Ident(i),
+            TsEntityName::TsQualifiedName(q) => {
+                let span = q.span();
+                let TsQualifiedName { left, right } = *q;
+
+                Expr::Member(MemberExpr {
+                    span,
+                    obj: ExprOrSuper::Expr(Box::new(left.into())),
+                    prop: Box::new(Expr::Ident(right)),
+                    computed: false,
+                })
+            }
+        }
+    }
+}
+
 #[ast_node]
 #[derive(Eq, Hash, Is, EqIgnoreSpan)]
 #[allow(variant_size_differences)]
-#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum TsEntityName {
     #[tag("TsQualifiedName")]
     TsQualifiedName(Box<TsQualifiedName>),
@@ -107,6 +323,39 @@ pub enum TsEntityName {
     Ident(Ident),
 }
 
+impl TsEntityName {
+    /// Returns the identifiers of this name in source order, e.g. `A`, `B`
+    /// and `C` for `A.B.C`.
+    pub fn segments(&self) -> impl Iterator<Item = &Ident> {
+        let mut segments = vec![];
+        let mut name = self;
+        loop {
+            match name {
+                TsEntityName::TsQualifiedName(q) => {
+                    segments.push(&q.right);
+                    name = &q.left;
+                }
+                TsEntityName::Ident(i) => {
+                    segments.push(i);
+                    break;
+                }
+            }
+        }
+        segments.into_iter().rev()
+    }
+
+    /// Returns the root of this name, e.g. `A` for `A.B.C`.
+    pub fn leftmost(&self) -> &Ident {
+        let mut name = self;
+        loop {
+            match name {
+                TsEntityName::TsQualifiedName(q) => name = &q.left,
+                TsEntityName::Ident(i) => return i,
+            }
+        }
+    }
+}
+
 // ================
 // TypeScript type members (for type literal / interface / class)
 // ================
@@ -229,18 +478,67 @@ pub struct TsIndexSignature {
     pub type_ann: Option<TsTypeAnn>,
 
     pub readonly: bool,
-    #[serde(rename = "static")]
+    /// Only class index signatures can be static.
+    #[serde(default, rename = "static")]
     pub is_static: bool,
     pub span: Span,
 }
 
+impl TsIndexSignature {
+    pub fn modifiers(&self) -> Modifiers {
+        Modifiers {
+            is_static: self.is_static,
+            readonly: self.readonly,
+            ..Default::default()
+        }
+    }
+}
+
+/// Function-like nodes with a declared return type.
+pub trait HasReturnType {
+    /// Returns the declared return type, or `None` if it's omitted.
+    ///
+    /// Function and constructor types always have one.
+    fn return_type(&self) -> Option<&TsTypeAnn>;
+}
+
+impl HasReturnType for TsFnType {
+    fn return_type(&self) -> Option<&TsTypeAnn> {
+        Some(&self.type_ann)
+    }
+}
+
+impl HasReturnType for TsConstructorType {
+    fn return_type(&self) -> Option<&TsTypeAnn> {
+        Some(&self.type_ann)
+    }
+}
+
+impl HasReturnType for TsCallSignatureDecl {
+    fn return_type(&self) -> Option<&TsTypeAnn> {
+        self.type_ann.as_ref()
+    }
+}
+
+impl HasReturnType for TsConstructSignatureDecl {
+    fn return_type(&self) -> Option<&TsTypeAnn> {
+        self.type_ann.as_ref()
+    }
+}
+
+impl HasReturnType for TsMethodSignature {
+    fn return_type(&self) -> Option<&TsTypeAnn> {
+        self.type_ann.as_ref()
+    }
+}
+
 // ================
 // TypeScript types
 // ================
 
 #[ast_node]
 #[derive(Eq, Hash, Is, EqIgnoreSpan)]
-#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[deserialize_guard(TsTypeDepthGuard)]
 pub enum TsType {
     #[tag("TsKeywordType")]
     TsKeywordType(TsKeywordType),
@@ -339,6 +637,218 @@ impl From<TsIntersectionType> for TsType {
     }
 }
 
+/// Default value for [with_max_ts_type_depth].
+pub const DEFAULT_MAX_TS_TYPE_DEPTH: usize = 512;
+
+thread_local! {
+    static TS_TYPE_DEPTH: Cell<usize> = Cell::new(0);
+    static MAX_TS_TYPE_DEPTH: Cell<usize> = Cell::new(DEFAULT_MAX_TS_TYPE_DEPTH);
+}
+
+/// Runs `op` while deserialization of [TsType] fails for types whose objects
+/// and arrays are nested deeper than `max_depth`, instead of
+/// [DEFAULT_MAX_TS_TYPE_DEPTH].
+///
+/// The limit prevents a stack overflow while deserializing untrusted input,
+/// like `{"type":"TsArrayType","elemType":{"type":"TsArrayType",...}}`. It is
+/// checked while the input of the outermost [TsType] is buffered, so nodes
+/// containing types, like `Stmt`, which buffer their own input first, still
+/// rely on the recursion limit of the deserializer, like the one of
+/// `serde_json`.
+pub fn with_max_ts_type_depth<F, Ret>(max_depth: usize, op: F) -> Ret
+where
+    F: FnOnce() -> Ret,
+{
+    /// Restores the previous limit, even if `op` panics.
+    struct Reset(usize);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            MAX_TS_TYPE_DEPTH.with(|max| max.set(self.0));
+        }
+    }
+
+    let _reset = Reset(MAX_TS_TYPE_DEPTH.with(|max| max.replace(max_depth)));
+    op()
+}
+
+/// Entered for every nested object and array of a [TsType], and decrements
+/// the depth when dropped.
+struct TsTypeDepthGuard;
+
+impl Drop for TsTypeDepthGuard {
+    fn drop(&mut self) {
+        TS_TYPE_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+impl DeserializeGuard for TsTypeDepthGuard {
+    fn enter<E: de::Error>() -> Result<Self, E> {
+        let max_depth = MAX_TS_TYPE_DEPTH.with(Cell::get);
+        let depth = TS_TYPE_DEPTH.with(Cell::get);
+        if depth >= max_depth {
+            return Err(E::custom(format_args!(
+                "TsType is nested deeper than {} levels",
+                max_depth
+            )));
+        }
+
+        TS_TYPE_DEPTH.with(|d| d.set(depth + 1));
+        Ok(TsTypeDepthGuard)
+    }
+}
+
+/// Position of a type nested in another type, which determines whether it
+/// needs parentheses. See [TsType::needs_parens_in].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TsTypeContext {
+    /// The element type of an array type like `T[]`, and positions with the
+    /// same precedence, which are the object type of an indexed access type
+    /// and the type of an optional tuple element.
+    ArrayElement,
+    /// A member of a union type.
+    UnionMember,
+    /// A member of an intersection type.
+    IntersectionMember,
+    /// The check type or the extends type of a conditional type.
+    ConditionalOperand,
+    /// The return type of a function or constructor type. Any type is allowed
+    /// here.
+    FunctionReturn,
+    /// The operand of a type operator like `keyof T`.
+    TypeOperatorOperand,
+}
+
+impl TsType {
+    /// Returns the names of all types referenced in this type, including
+    /// nested ones, in source order.
+    ///
+    /// This includes the names of type references (`Foo<Bar>` yields `Foo` and
+    /// `Bar`) and of type queries (`typeof foo` yields `foo`). Type parameters
+    /// are not resolved, so their references are included as well.
+    pub fn referenced_type_names(&self) -> impl Iterator<Item = &TsEntityName> {
+        let mut names = vec![];
+        collect_type_names(self, &mut names);
+        names.into_iter()
+    }
+
+    /// Calls `f` for this type and all nested types, in pre-order.
+    ///
+    /// Unlike [TsType::referenced_type_names], this doesn't allocate. Nested
+    /// types include type arguments, constraints and defaults of type
+    /// parameters, and the types of members of type literals.
+    #[inline]
+    pub fn for_each_type<F>(&self, f: &mut F)
+    where
+        F: FnMut(&TsType),
+    {
+        f(self);
+        for_each_child_type(self, &mut |ty| ty.for_each_type(f));
+    }
+
+    /// Returns the type wrapped by any number of parentheses, e.g. `T` for
+    /// `((T))`.
+    pub fn unwrap_parens(&self) -> &TsType {
+        let mut ty = self;
+        while let TsType::TsParenthesizedType(p) = ty {
+            ty = &p.type_ann;
+        }
+        ty
+    }
+
+    /// Owned version of [TsType::unwrap_parens].
+    pub fn into_unwrapped_parens(self) -> TsType {
+        let mut ty = self;
+        while let TsType::TsParenthesizedType(p) = ty {
+            ty = *p.type_ann;
+        }
+        ty
+    }
+
+    /// Returns `true` if the type must be wrapped in parentheses when it's
+    /// nested in another type at the position described by `context`, e.g.
+    /// `A | B` as the element type of `(A | B)[]`.
+    ///
+    /// Parenthesized types never need additional parentheses.
+    pub fn needs_parens_in(&self, context: TsTypeContext) -> bool {
+        match context {
+            TsTypeContext::ArrayElement => matches!(
+                self,
+                TsType::TsUnionOrIntersectionType(..)
+                    | TsType::TsFnOrConstructorType(..)
+                    | TsType::TsConditionalType(..)
+                    | TsType::TsTypeOperator(..)
+                    | TsType::TsInferType(..)
+                    | TsType::TsTypePredicate(..)
+            ),
+            TsTypeContext::TypeOperatorOperand => matches!(
+                self,
+                TsType::TsUnionOrIntersectionType(..)
+                    | TsType::TsFnOrConstructorType(..)
+                    | TsType::TsConditionalType(..)
+                    | TsType::TsTypePredicate(..)
+            ),
+            TsTypeContext::UnionMember | TsTypeContext::ConditionalOperand => match self {
+                TsType::TsFnOrConstructorType(..)
+                | TsType::TsConditionalType(..)
+                | TsType::TsTypePredicate(..) => true,
+                // `infer U extends A | B` would include `| B` in the constraint.
+                TsType::TsInferType(TsInferType {
+                    type_param: TsTypeParam {
+                        constraint: Some(..),
+                        ..
+                    },
+                    ..
+                }) => true,
+                _ => false,
+            },
+            TsTypeContext::IntersectionMember => {
+                matches!(
+                    self,
+                    TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(..))
+                ) || self.needs_parens_in(TsTypeContext::UnionMember)
+            }
+            TsTypeContext::FunctionReturn => false,
+        }
+    }
+
+    /// Returns the kind of this type if it's a keyword type like `any` or
+    /// `string`.
+    ///
+    /// Parenthesized types are unwrapped, so `(any)` is treated like `any`.
+    /// This also applies to `is_any()` and the other keyword predicates.
+    pub fn keyword_kind(&self) -> Option<TsKeywordTypeKind> {
+        match self.unwrap_parens() {
+            TsType::TsKeywordType(k) => Some(k.kind),
+            _ => None,
+        }
+    }
+
+    pub fn is_any(&self) -> bool {
+        self.keyword_kind() == Some(TsKeywordTypeKind::TsAnyKeyword)
+    }
+
+    pub fn is_never(&self) -> bool {
+        self.keyword_kind() == Some(TsKeywordTypeKind::TsNeverKeyword)
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        self.keyword_kind() == Some(TsKeywordTypeKind::TsUnknownKeyword)
+    }
+
+    pub fn is_void(&self) -> bool {
+        self.keyword_kind() == Some(TsKeywordTypeKind::TsVoidKeyword)
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.keyword_kind() == Some(TsKeywordTypeKind::TsNullKeyword)
+    }
+
+    pub fn is_undefined(&self) -> bool {
+        self.keyword_kind() == Some(TsKeywordTypeKind::TsUndefinedKeyword)
+    }
+}
+
 #[ast_node("TsKeywordType")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -390,6 +900,32 @@ pub enum TsKeywordTypeKind {
     TsIntrinsicKeyword,
 }
 
+impl TsKeywordTypeKind {
+    /// Returns the result of the `typeof` operator for values of this type,
+    /// e.g. `"string"` for `string`.
+    ///
+    /// Returns `None` for types which don't correspond to a single result,
+    /// like `any` or `void`. `null` is also excluded, as `typeof null` is
+    /// `"object"` for historical reasons.
+    pub fn typeof_string(&self) -> Option<&'static str> {
+        match self {
+            TsKeywordTypeKind::TsStringKeyword => Some("string"),
+            TsKeywordTypeKind::TsNumberKeyword => Some("number"),
+            TsKeywordTypeKind::TsBooleanKeyword => Some("boolean"),
+            TsKeywordTypeKind::TsBigIntKeyword => Some("bigint"),
+            TsKeywordTypeKind::TsSymbolKeyword => Some("symbol"),
+            TsKeywordTypeKind::TsUndefinedKeyword => Some("undefined"),
+            TsKeywordTypeKind::TsObjectKeyword => Some("object"),
+            TsKeywordTypeKind::TsAnyKeyword
+            | TsKeywordTypeKind::TsUnknownKeyword
+            | TsKeywordTypeKind::TsVoidKeyword
+            | TsKeywordTypeKind::TsNeverKeyword
+            | TsKeywordTypeKind::TsNullKeyword
+            | TsKeywordTypeKind::TsIntrinsicKeyword => None,
+        }
+    }
+}
+
 #[ast_node("TsThisType")]
 #[derive(Copy, Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -414,6 +950,33 @@ pub enum TsFnParam {
     Object(ObjectPat),
 }
 
+impl From<TsFnParam> for Pat {
+    fn from(p: TsFnParam) -> Self {
+        match p {
+            TsFnParam::Ident(p) => Pat::Ident(p),
+            TsFnParam::Array(p) => Pat::Array(p),
+            TsFnParam::Rest(p) => Pat::Rest(p),
+            TsFnParam::Object(p) => Pat::Object(p),
+        }
+    }
+}
+
+/// Fails for patterns which are not allowed as parameters of function types,
+/// like `a = 1`, and returns the pattern as is.
+impl TryFrom<Pat> for TsFnParam {
+    type Error = Pat;
+
+    fn try_from(p: Pat) -> Result<Self, Pat> {
+        match p {
+            Pat::Ident(p) => Ok(TsFnParam::Ident(p)),
+            Pat::Array(p) => Ok(TsFnParam::Array(p)),
+            Pat::Rest(p) => Ok(TsFnParam::Rest(p)),
+            Pat::Object(p) => Ok(TsFnParam::Object(p)),
+            Pat::Assign(..) | Pat::Invalid(..) | Pat::Expr(..) => Err(p),
+        }
+    }
+}
+
 #[ast_node("TsFunctionType")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -450,6 +1013,68 @@ pub struct TsTypeRef {
     pub type_params: Option<TsTypeParamInstantiation>,
 }
 
+impl TsTypeRef {
+    /// Returns the global type this type refers to by name, e.g.
+    /// [WellKnownType::Array] for `Array<T>`.
+    ///
+    /// This is only syntactic, so `Array` is matched even if it's shadowed by
+    /// a local declaration. Qualified names like `A.Array` are never matched.
+    pub fn well_known(&self) -> Option<WellKnownType> {
+        match &self.type_name {
+            TsEntityName::Ident(i) => i.sym.parse().ok(),
+            TsEntityName::TsQualifiedName(..) => None,
+        }
+    }
+}
+
+/// Global types of the standard library which are special-cased by
+/// transforms.
+#[derive(StringEnum, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WellKnownType {
+    /// `Array`
+    Array,
+    /// `ReadonlyArray`
+    ReadonlyArray,
+    /// `Promise`
+    Promise,
+    /// `PromiseLike`
+    PromiseLike,
+    /// `Map`
+    Map,
+    /// `ReadonlyMap`
+    ReadonlyMap,
+    /// `Set`
+    Set,
+    /// `ReadonlySet`
+    ReadonlySet,
+    /// `WeakMap`
+    WeakMap,
+    /// `WeakSet`
+    WeakSet,
+    /// `Record`
+    Record,
+    /// `Partial`
+    Partial,
+    /// `Required`
+    Required,
+    /// `Readonly`
+    Readonly,
+    /// `Pick`
+    Pick,
+    /// `Omit`
+    Omit,
+    /// `Exclude`
+    Exclude,
+    /// `Extract`
+    Extract,
+    /// `NonNullable`
+    NonNullable,
+    /// `ReturnType`
+    ReturnType,
+    /// `Parameters`
+    Parameters,
+}
+
 #[ast_node("TsTypePredicate")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -493,6 +1118,19 @@ pub enum TsTypeQueryExpr {
     Import(TsImportType),
 }
 
+impl TsTypeQueryExpr {
+    /// Converts the queried entity name into the equivalent expression, e.g.
+    /// `a.b.c` for `typeof a.b.c`.
+    ///
+    /// Returns `None` for `typeof import("foo")`.
+    pub fn to_expr(&self) -> Option<Expr> {
+        match self {
+            TsTypeQueryExpr::TsEntityName(name) => Some(name.clone().into()),
+            TsTypeQueryExpr::Import(..) => None,
+        }
+    }
+}
+
 #[ast_node("TsImportType")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -513,6 +1151,107 @@ pub struct TsTypeLit {
     pub members: Vec<TsTypeElement>,
 }
 
+impl TsTypeLit {
+    /// Creates a type literal with a property signature for each
+    /// `(key, type, optional)` triple.
+    ///
+    /// ```
+    /// use swc_common::DUMMY_SP;
+    /// use swc_ecma_ast::*;
+    ///
+    /// let key = |name: &str| PropName::Ident(Ident::new(name.into(), DUMMY_SP));
+    /// let keyword = |kind| TsType::TsKeywordType(TsKeywordType { span: DUMMY_SP, kind });
+    ///
+    /// // { a: string; b?: number }
+    /// let lit = TsTypeLit::from_props(vec![
+    ///     (key("a"), keyword(TsKeywordTypeKind::TsStringKeyword), false),
+    ///     (key("b"), keyword(TsKeywordTypeKind::TsNumberKeyword), true),
+    /// ]);
+    /// assert_eq!(lit.members.len(), 2);
+    /// ```
+    pub fn from_props<I>(props: I) -> Self
+    where
+        I: IntoIterator<Item = (PropName, TsType, bool)>,
+    {
+        let mut lit = TsTypeLit {
+            span: DUMMY_SP,
+            members: vec![],
+        };
+        for (key, ty, optional) in props {
+            lit.push_property(key, ty, optional);
+        }
+        lit
+    }
+
+    /// Appends a property signature like `key?: ty`.
+    pub fn push_property(&mut self, key: PropName, ty: TsType, optional: bool) {
+        let (key, computed) = prop_name_to_key(key);
+
+        let member = TsPropertySignature {
+            span: DUMMY_SP,
+            readonly: false,
+            key,
+            computed,
+            optional,
+            init: None,
+            params: vec![],
+            type_ann: Some(type_ann(ty)),
+            type_params: None,
+        };
+        self.members.push(TsTypeElement::TsPropertySignature(member));
+    }
+
+    /// Appends an index signature like `[param: param_ty]: ty`.
+    pub fn push_index_signature(&mut self, param: Ident, param_ty: TsType, ty: TsType) {
+        let member = TsIndexSignature {
+            span: DUMMY_SP,
+            params: vec![TsFnParam::Ident(BindingIdent {
+                id: param,
+                type_ann: Some(type_ann(param_ty)),
+            })],
+            type_ann: Some(type_ann(ty)),
+            readonly: false,
+            is_static: false,
+        };
+        self.members.push(TsTypeElement::TsIndexSignature(member));
+    }
+
+    /// Appends a method signature like `key(...params): return_ty`.
+    pub fn push_method(&mut self, key: PropName, params: Vec<TsFnParam>, return_ty: TsType) {
+        let (key, computed) = prop_name_to_key(key);
+
+        let member = TsMethodSignature {
+            span: DUMMY_SP,
+            readonly: false,
+            key,
+            computed,
+            optional: false,
+            params,
+            type_ann: Some(type_ann(return_ty)),
+            type_params: None,
+        };
+        self.members.push(TsTypeElement::TsMethodSignature(member));
+    }
+}
+
+fn type_ann(ty: TsType) -> TsTypeAnn {
+    TsTypeAnn {
+        span: DUMMY_SP,
+        type_ann: Box::new(ty),
+    }
+}
+
+/// Returns the key of a type element and whether it's computed.
+fn prop_name_to_key(key: PropName) -> (Box<Expr>, bool) {
+    match key {
+        PropName::Ident(i) => (Box::new(Expr::Ident(i)), false),
+        PropName::Str(s) => (Box::new(Expr::Lit(Lit::Str(s))), false),
+        PropName::Num(n) => (Box::new(Expr::Lit(Lit::Num(n))), false),
+        PropName::BigInt(n) => (Box::new(Expr::Lit(Lit::BigInt(n))), false),
+        PropName::Computed(c) => (c.expr, true),
+    }
+}
+
 #[ast_node("TsArrayType")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -584,6 +1323,127 @@ pub struct TsIntersectionType {
     pub types: Vec<Box<TsType>>,
 }
 
+impl TsUnionType {
+    /// Hoists the members of nested unions like `A | (B | C)` into this union
+    /// and removes duplicate members, ignoring spans and parentheses.
+    ///
+    /// The first occurrence of a member is kept, so the order is preserved.
+    pub fn flatten(self) -> TsUnionType {
+        let mut types = Vec::with_capacity(self.types.len());
+        flatten_members(self.types, &mut types, |ty| {
+            matches!(
+                ty,
+                TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(..))
+            )
+        });
+
+        TsUnionType {
+            span: self.span,
+            types,
+        }
+    }
+
+    /// Sorts the members by their kind and then by their printed form, so
+    /// unions like `A | B` and `B | A` become equal.
+    ///
+    /// The sort is stable. Together with [TsUnionType::flatten], this gives a
+    /// canonical form of the union.
+    pub fn sort_members(&mut self) {
+        sort_members(&mut self.types);
+    }
+}
+
+impl TsIntersectionType {
+    /// Hoists the members of nested intersections like `A & (B & C)` into this
+    /// intersection and removes duplicate members, ignoring spans and
+    /// parentheses.
+    ///
+    /// The first occurrence of a member is kept, so the order is preserved.
+    pub fn flatten(self) -> TsIntersectionType {
+        let mut types = Vec::with_capacity(self.types.len());
+        flatten_members(self.types, &mut types, |ty| {
+            matches!(
+                ty,
+                TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+                    ..
+                ))
+            )
+        });
+
+        TsIntersectionType {
+            span: self.span,
+            types,
+        }
+    }
+
+    /// Sorts the members like [TsUnionType::sort_members] does.
+    ///
+    /// Intersections of function types are overloads, so sorting them changes
+    /// which signature is tried first.
+    pub fn sort_members(&mut self) {
+        sort_members(&mut self.types);
+    }
+}
+
+fn sort_members(types: &mut [Box<TsType>]) {
+    types.sort_by_cached_key(|ty| (kind_order(ty), ty.to_string()));
+}
+
+/// Returns the position of the kind of the type in the declaration of
+/// [TsType].
+fn kind_order(ty: &TsType) -> u8 {
+    match ty {
+        TsType::TsKeywordType(..) => 0,
+        TsType::TsThisType(..) => 1,
+        TsType::TsFnOrConstructorType(..) => 2,
+        TsType::TsTypeRef(..) => 3,
+        TsType::TsTypeQuery(..) => 4,
+        TsType::TsTypeLit(..) => 5,
+        TsType::TsArrayType(..) => 6,
+        TsType::TsTupleType(..) => 7,
+        TsType::TsOptionalType(..) => 8,
+        TsType::TsRestType(..) => 9,
+        TsType::TsUnionOrIntersectionType(..) => 10,
+        TsType::TsConditionalType(..) => 11,
+        TsType::TsInferType(..) => 12,
+        TsType::TsParenthesizedType(..) => 13,
+        TsType::TsTypeOperator(..) => 14,
+        TsType::TsIndexedAccessType(..) => 15,
+        TsType::TsMappedType(..) => 16,
+        TsType::TsLitType(..) => 17,
+        TsType::TsTypePredicate(..) => 18,
+        TsType::TsImportType(..) => 19,
+    }
+}
+
+fn flatten_members(
+    types: Vec<Box<TsType>>,
+    buf: &mut Vec<Box<TsType>>,
+    is_nested: fn(&TsType) -> bool,
+) {
+    for ty in types {
+        if is_nested(ty.unwrap_parens()) {
+            let members = match (*ty).into_unwrapped_parens() {
+                TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(
+                    TsUnionType { types, .. },
+                ))
+                | TsType::TsUnionOrIntersectionType(
+                    TsUnionOrIntersectionType::TsIntersectionType(TsIntersectionType {
+                        types, ..
+                    }),
+                ) => types,
+                _ => unreachable!(),
+            };
+            flatten_members(members, buf, is_nested);
+        } else if !buf
+            .iter()
+            .any(|prev| prev.unwrap_parens().eq_ignore_span(ty.unwrap_parens()))
+        {
+            buf.push(ty);
+        }
+    }
+}
+
 #[ast_node("TsConditionalType")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -595,6 +1455,246 @@ pub struct TsConditionalType {
     pub false_type: Box<TsType>,
 }
 
+impl TsConditionalType {
+    /// Returns true if the check type is a naked type parameter, which makes
+    /// the conditional type distribute over unions, like `T` in
+    /// `T extends string ? X : Y`.
+    ///
+    /// This is a syntactic check: any reference to a plain identifier
+    /// without type arguments is assumed to be a type parameter, because
+    /// scopes are not known here. Parentheses are looked through, while
+    /// wrapping the type like `[T] extends [string]` disables distribution.
+    pub fn is_distributive(&self) -> bool {
+        matches!(
+            self.check_type.unwrap_parens(),
+            TsType::TsTypeRef(TsTypeRef {
+                type_name: TsEntityName::Ident(..),
+                type_params: None,
+                ..
+            })
+        )
+    }
+
+    /// Returns the type parameters declared by `infer` in the extends type, in
+    /// source order.
+    ///
+    /// For `T extends [infer A, infer B] ? [A, B] : never`, this returns `A`
+    /// and `B`. `infer` declarations of nested conditional types are not
+    /// included.
+    pub fn infer_type_params(&self) -> Vec<&TsTypeParam> {
+        let mut params = vec![];
+        collect_infer_type_params(&self.extends_type, &mut params);
+        params
+    }
+
+    /// Returns the properties of the extends type which narrow the checked
+    /// type to a literal, in source order.
+    ///
+    /// For `T extends { kind: "a" } ? X : Y`, this returns `kind` and `"a"`.
+    /// Returns an empty vector if the extends type is not an object type.
+    pub fn narrowing_keys(&self) -> Vec<(JsWord, TsLit)> {
+        let lit = match self.extends_type.unwrap_parens() {
+            TsType::TsTypeLit(lit) => lit,
+            _ => return vec![],
+        };
+
+        lit.members
+            .iter()
+            .filter_map(|member| match member {
+                TsTypeElement::TsPropertySignature(prop) if !prop.computed => {
+                    let key = match &*prop.key {
+                        Expr::Ident(i) => i.sym.clone(),
+                        Expr::Lit(Lit::Str(s)) => s.value.clone(),
+                        _ => return None,
+                    };
+
+                    match prop.type_ann.as_ref()?.type_ann.unwrap_parens() {
+                        TsType::TsLitType(ty) => Some((key, ty.lit.clone())),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+fn collect_infer_type_params<'a>(ty: &'a TsType, params: &mut Vec<&'a TsTypeParam>) {
+    match ty {
+        TsType::TsInferType(t) => params.push(&t.type_param),
+        TsType::TsConditionalType(t) => {
+            // `infer` in the extends type belongs to the nested conditional type.
+            collect_infer_type_params(&t.check_type, params);
+            collect_infer_type_params(&t.true_type, params);
+            collect_infer_type_params(&t.false_type, params);
+        }
+        _ => for_each_child_type(ty, &mut |ty| collect_infer_type_params(ty, params)),
+    }
+}
+
+fn collect_type_names<'a>(ty: &'a TsType, names: &mut Vec<&'a TsEntityName>) {
+    match ty {
+        TsType::TsTypeRef(t) => names.push(&t.type_name),
+        TsType::TsTypeQuery(TsTypeQuery {
+            expr_name: TsTypeQueryExpr::TsEntityName(name),
+            ..
+        }) => names.push(name),
+        _ => {}
+    }
+
+    for_each_child_type(ty, &mut |ty| collect_type_names(ty, names));
+}
+
+/// Calls `f` with each type nested directly in `ty`, in source order.
+pub(crate) fn for_each_child_type<'a, F: FnMut(&'a TsType)>(ty: &'a TsType, f: &mut F) {
+    fn type_params<'a, F: FnMut(&'a TsType)>(decl: Option<&'a TsTypeParamDecl>, f: &mut F) {
+        if let Some(decl) = decl {
+            for p in &decl.params {
+                type_param(p, f);
+            }
+        }
+    }
+
+    fn type_param<'a, F: FnMut(&'a TsType)>(p: &'a TsTypeParam, f: &mut F) {
+        if let Some(constraint) = &p.constraint {
+            f(constraint);
+        }
+        if let Some(default) = &p.default {
+            f(default);
+        }
+    }
+
+    fn fn_params<'a, F: FnMut(&'a TsType)>(fn_params: &'a [TsFnParam], f: &mut F) {
+        for p in fn_params {
+            let type_ann = match p {
+                TsFnParam::Ident(i) => &i.type_ann,
+                TsFnParam::Array(a) => &a.type_ann,
+                TsFnParam::Rest(r) => &r.type_ann,
+                TsFnParam::Object(o) => &o.type_ann,
+            };
+            type_ann_opt(type_ann.as_ref(), f);
+        }
+    }
+
+    fn type_ann_opt<'a, F: FnMut(&'a TsType)>(type_ann: Option<&'a TsTypeAnn>, f: &mut F) {
+        if let Some(type_ann) = type_ann {
+            f(&type_ann.type_ann);
+        }
+    }
+
+    fn type_args<'a, F: FnMut(&'a TsType)>(args: Option<&'a TsTypeParamInstantiation>, f: &mut F) {
+        if let Some(args) = args {
+            for ty in &args.params {
+                f(ty);
+            }
+        }
+    }
+
+    match ty {
+        TsType::TsKeywordType(..) | TsType::TsThisType(..) => {}
+        TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(t)) => {
+            type_params(t.type_params.as_ref(), f);
+            fn_params(&t.params, f);
+            f(&t.type_ann.type_ann);
+        }
+        TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsConstructorType(t)) => {
+            type_params(t.type_params.as_ref(), f);
+            fn_params(&t.params, f);
+            f(&t.type_ann.type_ann);
+        }
+        TsType::TsTypeRef(t) => type_args(t.type_params.as_ref(), f),
+        TsType::TsTypeQuery(t) => match &t.expr_name {
+            TsTypeQueryExpr::TsEntityName(..) => {}
+            TsTypeQueryExpr::Import(i) => type_args(i.type_args.as_ref(), f),
+        },
+        TsType::TsTypeLit(t) => {
+            for member in &t.members {
+                match member {
+                    TsTypeElement::TsCallSignatureDecl(m) => {
+                        type_params(m.type_params.as_ref(), f);
+                        fn_params(&m.params, f);
+                        type_ann_opt(m.type_ann.as_ref(), f);
+                    }
+                    TsTypeElement::TsConstructSignatureDecl(m) => {
+                        type_params(m.type_params.as_ref(), f);
+                        fn_params(&m.params, f);
+                        type_ann_opt(m.type_ann.as_ref(), f);
+                    }
+                    TsTypeElement::TsPropertySignature(m) => {
+                        type_params(m.type_params.as_ref(), f);
+                        fn_params(&m.params, f);
+                        type_ann_opt(m.type_ann.as_ref(), f);
+                    }
+                    TsTypeElement::TsGetterSignature(m) => {
+                        type_ann_opt(m.type_ann.as_ref(), f);
+                    }
+                    TsTypeElement::TsSetterSignature(m) => {
+                        fn_params(std::slice::from_ref(&m.param), f);
+                    }
+                    TsTypeElement::TsMethodSignature(m) => {
+                        type_params(m.type_params.as_ref(), f);
+                        fn_params(&m.params, f);
+                        type_ann_opt(m.type_ann.as_ref(), f);
+                    }
+                    TsTypeElement::TsIndexSignature(m) => {
+                        fn_params(&m.params, f);
+                        type_ann_opt(m.type_ann.as_ref(), f);
+                    }
+                }
+            }
+        }
+        TsType::TsArrayType(t) => f(&t.elem_type),
+        TsType::TsTupleType(t) => {
+            for elem in &t.elem_types {
+                f(&elem.ty);
+            }
+        }
+        TsType::TsOptionalType(t) => f(&t.type_ann),
+        TsType::TsRestType(t) => f(&t.type_ann),
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(t)) => {
+            for ty in &t.types {
+                f(ty);
+            }
+        }
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(t)) => {
+            for ty in &t.types {
+                f(ty);
+            }
+        }
+        TsType::TsConditionalType(t) => {
+            f(&t.check_type);
+            f(&t.extends_type);
+            f(&t.true_type);
+            f(&t.false_type);
+        }
+        TsType::TsInferType(t) => type_param(&t.type_param, f),
+        TsType::TsParenthesizedType(t) => f(&t.type_ann),
+        TsType::TsTypeOperator(t) => f(&t.type_ann),
+        TsType::TsIndexedAccessType(t) => {
+            f(&t.obj_type);
+            f(&t.index_type);
+        }
+        TsType::TsMappedType(t) => {
+            type_param(&t.type_param, f);
+            if let Some(name_type) = &t.name_type {
+                f(name_type);
+            }
+            if let Some(type_ann) = &t.type_ann {
+                f(type_ann);
+            }
+        }
+        TsType::TsLitType(t) => {
+            if let TsLit::Tpl(tpl) = &t.lit {
+                for ty in &tpl.types {
+                    f(ty);
+                }
+            }
+        }
+        TsType::TsTypePredicate(t) => type_ann_opt(t.type_ann.as_ref(), f),
+        TsType::TsImportType(t) => type_args(t.type_args.as_ref(), f),
+    }
+}
+
 #[ast_node("TsInferType")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -633,6 +1733,63 @@ pub enum TsTypeOperatorOp {
     ReadOnly,
 }
 
+impl TsTypeOperator {
+    /// Checks if the operator is applied to a type it allows.
+    ///
+    /// `unique` is only valid on `symbol` and `readonly` only on array and
+    /// tuple types. `keyof` accepts any type.
+    pub fn validate(&self) -> Result<(), TsError> {
+        let kind = match (self.op, &*self.type_ann) {
+            (TsTypeOperatorOp::KeyOf, _) => return Ok(()),
+            (
+                TsTypeOperatorOp::Unique,
+                TsType::TsKeywordType(TsKeywordType {
+                    kind: TsKeywordTypeKind::TsSymbolKeyword,
+                    ..
+                }),
+            ) => return Ok(()),
+            (TsTypeOperatorOp::Unique, _) => TsErrorKind::UniqueNotOnSymbol,
+            (TsTypeOperatorOp::ReadOnly, TsType::TsArrayType(..))
+            | (TsTypeOperatorOp::ReadOnly, TsType::TsTupleType(..)) => return Ok(()),
+            (TsTypeOperatorOp::ReadOnly, _) => TsErrorKind::ReadonlyNotOnArrayOrTuple,
+        };
+
+        Err(TsError {
+            span: self.span,
+            kind,
+        })
+    }
+}
+
+/// A semantically invalid typescript node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TsError {
+    pub span: Span,
+    pub kind: TsErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TsErrorKind {
+    /// `unique string`
+    UniqueNotOnSymbol,
+    /// `readonly string`
+    ReadonlyNotOnArrayOrTuple,
+}
+
+impl fmt::Display for TsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            TsErrorKind::UniqueNotOnSymbol => {
+                write!(f, "'unique' type operator is only permitted on 'symbol'")
+            }
+            TsErrorKind::ReadonlyNotOnArrayOrTuple => write!(
+                f,
+                "'readonly' type modifier is only permitted on array and tuple literal types"
+            ),
+        }
+    }
+}
+
 #[ast_node("TsIndexedAccessType")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -779,6 +1936,96 @@ pub struct TsInterfaceDecl {
     pub body: TsInterfaceBody,
 }
 
+impl TsInterfaceDecl {
+    /// Returns true if this interface has at least one type parameter.
+    ///
+    /// `interface A<> {}` is not generic, as it declares no type parameters.
+    pub fn is_generic(&self) -> bool {
+        self.type_param_count() != 0
+    }
+
+    /// Returns the number of type parameters of this interface, which is zero
+    /// if `type_params` is `None` or empty.
+    pub fn type_param_count(&self) -> usize {
+        self.type_params.as_ref().map_or(0, |decl| decl.len())
+    }
+
+    /// Returns the members of this interface and of the interfaces it
+    /// extends, resolved with `resolve`.
+    ///
+    /// Members of an interface override the members with the same key of the
+    /// interfaces it extends, and members of a later `extends` entry override
+    /// the ones of an earlier entry. Signatures without a key, like call
+    /// signatures, are always kept.
+    ///
+    /// Interfaces which can't be resolved are skipped, and so are interfaces
+    /// which are already being flattened, so cyclic `extends` terminate.
+    pub fn all_members<'a, F>(&'a self, mut resolve: F) -> Vec<TsTypeElement>
+    where
+        F: FnMut(&TsEntityName) -> Option<&'a TsInterfaceDecl>,
+    {
+        let mut members = vec![];
+        let mut stack = vec![];
+        self.collect_members(&mut resolve, &mut stack, &mut members);
+        members
+    }
+
+    fn collect_members<'a, F>(
+        &'a self,
+        resolve: &mut F,
+        stack: &mut Vec<&'a TsInterfaceDecl>,
+        members: &mut Vec<TsTypeElement>,
+    ) where
+        F: FnMut(&TsEntityName) -> Option<&'a TsInterfaceDecl>,
+    {
+        if stack.iter().any(|decl| std::ptr::eq(*decl, self)) {
+            return;
+        }
+        stack.push(self);
+
+        for parent in &self.extends {
+            if let Some(parent) = resolve(&parent.expr) {
+                let mut parent_members = vec![];
+                parent.collect_members(resolve, stack, &mut parent_members);
+                override_members(members, parent_members);
+            }
+        }
+        override_members(members, self.body.body.clone());
+
+        stack.pop();
+    }
+}
+
+/// Appends `new` to `members`, removing the members of `members` which have
+/// the same key as a member of `new`.
+fn override_members(members: &mut Vec<TsTypeElement>, new: Vec<TsTypeElement>) {
+    let keys: Vec<_> = new.iter().filter_map(member_key).collect();
+    members.retain(|member| match member_key(member) {
+        Some(key) => !keys.contains(&key),
+        None => true,
+    });
+    members.extend(new);
+}
+
+fn member_key(member: &TsTypeElement) -> Option<JsWord> {
+    let (key, computed) = match member {
+        TsTypeElement::TsPropertySignature(TsPropertySignature { key, computed, .. })
+        | TsTypeElement::TsGetterSignature(TsGetterSignature { key, computed, .. })
+        | TsTypeElement::TsSetterSignature(TsSetterSignature { key, computed, .. })
+        | TsTypeElement::TsMethodSignature(TsMethodSignature { key, computed, .. }) => {
+            (key, *computed)
+        }
+        _ => return None,
+    };
+
+    match &**key {
+        Expr::Ident(i) if !computed => Some(i.sym.clone()),
+        Expr::Lit(Lit::Str(s)) => Some(s.value.clone()),
+        Expr::Lit(Lit::Num(n)) => Some(n.value.to_string().into()),
+        _ => None,
+    }
+}
+
 #[ast_node("TsInterfaceBody")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -811,6 +2058,21 @@ pub struct TsTypeAliasDecl {
     pub type_ann: Box<TsType>,
 }
 
+impl TsTypeAliasDecl {
+    /// Returns true if this type alias has at least one type parameter.
+    ///
+    /// `type A<> = B` is not generic, as it declares no type parameters.
+    pub fn is_generic(&self) -> bool {
+        self.type_param_count() != 0
+    }
+
+    /// Returns the number of type parameters of this type alias, which is zero
+    /// if `type_params` is `None` or empty.
+    pub fn type_param_count(&self) -> usize {
+        self.type_params.as_ref().map_or(0, |decl| decl.len())
+    }
+}
+
 #[ast_node("TsEnumDeclaration")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -822,6 +2084,18 @@ pub struct TsEnumDecl {
     pub members: Vec<TsEnumMember>,
 }
 
+impl TsEnumDecl {
+    /// Always returns `false`, as enums can't have type parameters.
+    pub fn is_generic(&self) -> bool {
+        false
+    }
+
+    /// Always returns zero, as enums can't have type parameters.
+    pub fn type_param_count(&self) -> usize {
+        0
+    }
+}
+
 #[ast_node("TsEnumMember")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -845,6 +2119,33 @@ pub enum TsEnumMemberId {
     Str(Str),
 }
 
+impl TsEnumMemberId {
+    /// Returns the name of the member, which is the symbol of the identifier
+    /// for `A` and the value of the string for `"a-b"`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            TsEnumMemberId::Ident(i) => &i.sym,
+            TsEnumMemberId::Str(s) => &s.value,
+        }
+    }
+
+    /// Returns `true` if the name can be used as an identifier, so the member
+    /// can be accessed like `E.A` instead of `E["a-b"]`.
+    ///
+    /// String members like `"A"` are valid identifiers, while the empty
+    /// identifier created by error recovery is not.
+    pub fn is_valid_identifier(&self) -> bool {
+        let mut chars = self.as_str().chars();
+
+        match chars.next() {
+            Some(c) if c == '$' || c == '_' || c.is_xid_start() => {}
+            _ => return false,
+        }
+
+        chars.all(|c| c == '$' || c == '\u{200c}' || c == '\u{200d}' || c.is_xid_continue())
+    }
+}
+
 #[ast_node("TsModuleDeclaration")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -902,6 +2203,24 @@ pub enum TsModuleName {
     Str(Str),
 }
 
+impl TsModuleName {
+    /// Returns the name of the module, which is the value of the string for
+    /// `declare module "foo"` and the symbol of the identifier for `declare
+    /// module foo`.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            TsModuleName::Ident(i) => Some(&i.sym),
+            TsModuleName::Str(s) => Some(&s.value),
+        }
+    }
+
+    /// Returns `true` for `declare module "foo"`.
+    #[inline]
+    pub fn is_string_literal(&self) -> bool {
+        self.is_str()
+    }
+}
+
 #[ast_node("TsImportEqualsDeclaration")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -925,6 +2244,24 @@ pub enum TsModuleRef {
     TsExternalModuleRef(TsExternalModuleRef),
 }
 
+impl TsModuleRef {
+    /// Returns the module specifier of `import x = require("foo")`.
+    ///
+    /// Returns [None] for entity names like `import x = foo.bar`.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            TsModuleRef::TsEntityName(..) => None,
+            TsModuleRef::TsExternalModuleRef(r) => Some(&r.expr.value),
+        }
+    }
+
+    /// Returns `true` for `import x = require("foo")`.
+    #[inline]
+    pub fn is_string_literal(&self) -> bool {
+        self.is_ts_external_module_ref()
+    }
+}
+
 #[ast_node("TsExternalModuleReference")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -989,17 +2326,78 @@ pub struct TsNonNullExpr {
     pub expr: Box<Expr>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Eq, Hash, EqIgnoreSpan)]
+/// An accessibility modifier.
+///
+/// A member without a modifier is public, so `None` in an
+/// `Option<Accessibility>` can be treated like [Accessibility::Public], which
+/// is also the default.
+#[derive(StringEnum, Clone, Copy, PartialEq, Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Accessibility {
-    #[serde(rename = "public")]
+    /// `public`
     Public,
-    #[serde(rename = "protected")]
+    /// `protected`
     Protected,
-    #[serde(rename = "private")]
+    /// `private`
     Private,
 }
 
+impl Default for Accessibility {
+    fn default() -> Self {
+        Accessibility::Public
+    }
+}
+
+/// The modifiers of a class member, a parameter property or an index
+/// signature.
+///
+/// Typescript requires the modifiers to be written in a specific order, e.g.
+/// `static public` is an error. [Modifiers::keywords] and the [fmt::Display]
+/// implementation produce that order, which is
+/// `public static abstract override readonly`, so printers don't need to
+/// hardcode it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Modifiers {
+    pub accessibility: Option<Accessibility>,
+    pub is_static: bool,
+    pub is_abstract: bool,
+    pub is_override: bool,
+    pub readonly: bool,
+}
+
+impl Modifiers {
+    /// Returns the keywords of the modifiers which are set, in canonical
+    /// order.
+    pub fn keywords(self) -> impl Iterator<Item = &'static str> {
+        let flags = [
+            (self.is_static, "static"),
+            (self.is_abstract, "abstract"),
+            (self.is_override, "override"),
+            (self.readonly, "readonly"),
+        ];
+
+        self.accessibility.map(|a| a.as_str()).into_iter().chain(
+            std::array::IntoIter::new(flags)
+                .filter(|(set, _)| *set)
+                .map(|(_, keyword)| keyword),
+        )
+    }
+}
+
+impl fmt::Display for Modifiers {
+    /// Prints the keywords separated by spaces, e.g. `private static readonly`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, keyword) in self.keywords().enumerate() {
+            if i != 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(keyword)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[ast_node("TsConstAssertion")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -1007,3 +2405,545 @@ pub struct TsConstAssertion {
     pub span: Span,
     pub expr: Box<Expr>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_common::BytePos;
+
+    fn tref(name: &str, lo: u32) -> Box<TsType> {
+        let span = Span::new(BytePos(lo), BytePos(lo + 1), Default::default());
+        Box::new(TsType::TsTypeRef(TsTypeRef {
+            span,
+            type_name: TsEntityName::Ident(Ident::new(name.into(), span)),
+            type_params: None,
+        }))
+    }
+
+    fn parens(ty: TsType) -> Box<TsType> {
+        Box::new(TsType::TsParenthesizedType(TsParenthesizedType {
+            span: DUMMY_SP,
+            type_ann: Box::new(ty),
+        }))
+    }
+
+    fn names(types: &[Box<TsType>]) -> Vec<&str> {
+        types
+            .iter()
+            .map(|ty| match &**ty {
+                TsType::TsTypeRef(TsTypeRef {
+                    type_name: TsEntityName::Ident(i),
+                    ..
+                }) => &*i.sym,
+                ty => unreachable!("{:?}", ty),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn flatten_union() {
+        // A | (B | (A | C)) | B
+        let inner: TsType = TsUnionType {
+            span: DUMMY_SP,
+            types: vec![tref("A", 10), tref("C", 11)],
+        }
+        .into();
+        let nested: TsType = TsUnionType {
+            span: DUMMY_SP,
+            types: vec![tref("B", 5), parens(inner)],
+        }
+        .into();
+        let union = TsUnionType {
+            span: DUMMY_SP,
+            types: vec![tref("A", 1), parens(nested), tref("B", 20)],
+        }
+        .flatten();
+
+        assert_eq!(names(&union.types), vec!["A", "B", "C"]);
+        assert_eq!(union.types[0].span().lo(), BytePos(1));
+    }
+
+    #[test]
+    fn flatten_intersection_keeps_unions() {
+        // A & (B | C) & (A & D)
+        let union: TsType = TsUnionType {
+            span: DUMMY_SP,
+            types: vec![tref("B", 2), tref("C", 3)],
+        }
+        .into();
+        let nested: TsType = TsIntersectionType {
+            span: DUMMY_SP,
+            types: vec![tref("A", 4), tref("D", 5)],
+        }
+        .into();
+        let intersection = TsIntersectionType {
+            span: DUMMY_SP,
+            types: vec![tref("A", 1), parens(union), parens(nested)],
+        }
+        .flatten();
+
+        assert_eq!(intersection.types.len(), 3);
+        assert!(intersection.types[1]
+            .unwrap_parens()
+            .is_ts_union_or_intersection_type());
+        assert_eq!(names(&intersection.types[2..]), vec!["D"]);
+    }
+    #[test]
+    fn conditional_type_is_distributive() {
+        fn conditional(check_type: Box<TsType>) -> TsConditionalType {
+            TsConditionalType {
+                span: DUMMY_SP,
+                check_type,
+                extends_type: tref("U", 2),
+                true_type: tref("X", 3),
+                false_type: tref("Y", 4),
+            }
+        }
+
+        assert!(conditional(tref("T", 1)).is_distributive());
+        assert!(conditional(parens(*tref("T", 1))).is_distributive());
+
+        let tuple = Box::new(TsType::TsTupleType(TsTupleType {
+            span: DUMMY_SP,
+            elem_types: vec![TsTupleElement {
+                span: DUMMY_SP,
+                label: None,
+                ty: *tref("T", 1),
+            }],
+        }));
+        assert!(!conditional(tuple).is_distributive());
+
+        let generic = Box::new(TsType::TsTypeRef(TsTypeRef {
+            span: DUMMY_SP,
+            type_name: TsEntityName::Ident(Ident::new("Array".into(), DUMMY_SP)),
+            type_params: Some(TsTypeParamInstantiation::from_types(vec![tref("T", 1)])),
+        }));
+        assert!(!conditional(generic).is_distributive());
+    }
+
+    /// `this[][]...`, whose objects are nested `depth` levels deep, including
+    /// the span of `this`.
+    fn nested_array_json(depth: usize) -> String {
+        const ARRAY: &str =
+            r#"{"type":"TsArrayType","span":{"start":0,"end":0,"ctxt":0},"elemType":"#;
+        const THIS: &str = r#"{"type":"TsThisType","span":{"start":0,"end":0,"ctxt":0}}"#;
+
+        format!(
+            "{}{}{}",
+            ARRAY.repeat(depth - 2),
+            THIS,
+            "}".repeat(depth - 2)
+        )
+    }
+
+    #[test]
+    fn deserialize_depth_limit() {
+        let nested_array = |depth| serde_json::from_str::<TsType>(&nested_array_json(depth));
+
+        assert!(nested_array(20).is_ok());
+
+        with_max_ts_type_depth(8, || {
+            assert!(nested_array(8).is_ok());
+
+            let err = nested_array(9).unwrap_err();
+            assert!(err
+                .to_string()
+                .starts_with("TsType is nested deeper than 8 levels"));
+
+            // The depth is reset after an error.
+            assert!(nested_array(8).is_ok());
+        });
+
+        assert!(nested_array(9).is_ok());
+    }
+
+    /// The limit is checked while the input is buffered, so it applies before
+    /// the deserializer overflows the stack.
+    #[test]
+    fn deserialize_depth_limit_without_recursion_limit() {
+        let json = nested_array_json(100_000);
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        deserializer.disable_recursion_limit();
+
+        let err = TsType::deserialize(&mut deserializer).unwrap_err();
+        assert!(err.to_string().starts_with(&format!(
+            "TsType is nested deeper than {} levels",
+            DEFAULT_MAX_TS_TYPE_DEPTH
+        )));
+    }
+
+    #[test]
+    fn entity_name_segments() {
+        fn ident(name: &str) -> Ident {
+            Ident::new(name.into(), DUMMY_SP)
+        }
+
+        // A.B.C
+        let name = ["B", "C"]
+            .iter()
+            .fold(TsEntityName::Ident(ident("A")), |left, right| {
+                TsEntityName::TsQualifiedName(Box::new(TsQualifiedName {
+                    left,
+                    right: ident(right),
+                }))
+            });
+
+        let segments: Vec<&str> = name.segments().map(|i| &*i.sym).collect();
+        assert_eq!(segments, vec!["A", "B", "C"]);
+        assert_eq!(&*name.leftmost().sym, "A");
+
+        let name = TsEntityName::Ident(ident("A"));
+        assert_eq!(name.segments().count(), 1);
+        assert_eq!(&*name.leftmost().sym, "A");
+    }
+
+    #[test]
+    fn node_type() {
+        use swc_common::NodeType;
+
+        let ty = tref("T", 0);
+        assert_eq!(ty.node_type(), "TsTypeReference");
+
+        // `TsUnionOrIntersectionType` has two tags.
+        let union: TsType = TsUnionType {
+            span: DUMMY_SP,
+            types: vec![ty.clone()],
+        }
+        .into();
+        assert_eq!(union.node_type(), "TsUnionType");
+
+        let name = TsEntityName::Ident(Ident::new("T".into(), DUMMY_SP));
+        assert_eq!(name.node_type(), "Identifier");
+        assert_eq!(
+            TsTypeQueryExpr::TsEntityName(name).node_type(),
+            "Identifier"
+        );
+    }
+
+    #[test]
+    fn node_type_wildcard_variant() {
+        use crate::{EmptyStmt, Expr, ModuleItem, Pat, Stmt};
+        use swc_common::NodeType;
+
+        // `Pat::Expr` and `ModuleItem::Stmt` are tagged with `*`.
+        let pat = Pat::Expr(Box::new(Expr::Ident(Ident::new("a".into(), DUMMY_SP))));
+        assert_eq!(pat.node_type(), "Identifier");
+
+        let item = ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span: DUMMY_SP }));
+        assert_eq!(item.node_type(), "EmptyStatement");
+    }
+
+    #[test]
+    fn well_known_type_ref() {
+        fn type_ref(type_name: TsEntityName) -> TsTypeRef {
+            TsTypeRef {
+                span: DUMMY_SP,
+                type_name,
+                type_params: Some(TsTypeParamInstantiation::from_types(vec![tref("T", 1)])),
+            }
+        }
+        let ident = |name: &str| Ident::new(name.into(), DUMMY_SP);
+
+        assert_eq!(
+            type_ref(TsEntityName::Ident(ident("Array"))).well_known(),
+            Some(WellKnownType::Array)
+        );
+        assert_eq!(
+            type_ref(TsEntityName::Ident(ident("ReadonlyArray"))).well_known(),
+            Some(WellKnownType::ReadonlyArray)
+        );
+        assert_eq!(
+            type_ref(TsEntityName::Ident(ident("array"))).well_known(),
+            None
+        );
+
+        // A.Array
+        let qualified = TsEntityName::TsQualifiedName(Box::new(TsQualifiedName {
+            left: TsEntityName::Ident(ident("A")),
+            right: ident("Array"),
+        }));
+        assert_eq!(type_ref(qualified).well_known(), None);
+    }
+
+    #[test]
+    fn enum_member_id() {
+        let ident = |name: &str| TsEnumMemberId::Ident(Ident::new(name.into(), DUMMY_SP));
+        let str = |value: &str| {
+            TsEnumMemberId::Str(Str {
+                span: DUMMY_SP,
+                value: value.into(),
+                has_escape: false,
+                kind: Default::default(),
+            })
+        };
+
+        assert_eq!(ident("A").as_str(), "A");
+        assert_eq!(str("a-b").as_str(), "a-b");
+
+        assert!(ident("A").is_valid_identifier());
+        assert!(str("$_a1").is_valid_identifier());
+        assert!(str("\u{e9}t\u{e9}").is_valid_identifier());
+        assert!(!str("a-b").is_valid_identifier());
+        assert!(!str("1a").is_valid_identifier());
+        assert!(!str("").is_valid_identifier());
+        assert!(!ident("").is_valid_identifier());
+    }
+
+    #[test]
+    fn needs_parens_in() {
+        let union = TsType::from(TsUnionType {
+            span: DUMMY_SP,
+            types: vec![tref("A", 0), tref("B", 4)],
+        });
+        let intersection = TsType::from(TsIntersectionType {
+            span: DUMMY_SP,
+            types: vec![tref("A", 0), tref("B", 4)],
+        });
+        let keyof = TsType::TsTypeOperator(TsTypeOperator {
+            span: DUMMY_SP,
+            op: TsTypeOperatorOp::KeyOf,
+            type_ann: tref("T", 6),
+        });
+
+        // (A | B)[]
+        assert!(union.needs_parens_in(TsTypeContext::ArrayElement));
+        // (keyof T)[]
+        assert!(keyof.needs_parens_in(TsTypeContext::ArrayElement));
+        // keyof (A & B)
+        assert!(intersection.needs_parens_in(TsTypeContext::TypeOperatorOperand));
+        // (A | B) & C
+        assert!(union.needs_parens_in(TsTypeContext::IntersectionMember));
+        // A & B | C
+        assert!(!intersection.needs_parens_in(TsTypeContext::UnionMember));
+        // () => A | B
+        assert!(!union.needs_parens_in(TsTypeContext::FunctionReturn));
+
+        assert!(!tref("A", 0).needs_parens_in(TsTypeContext::ArrayElement));
+        assert!(!parens(union).needs_parens_in(TsTypeContext::ArrayElement));
+    }
+
+    #[test]
+    fn param_prop_expand() {
+        let x = Ident::new("x".into(), DUMMY_SP);
+        let renamed = Ident::new("_x".into(), DUMMY_SP);
+        let one = Box::new(Expr::Lit(Lit::Num(Number {
+            span: DUMMY_SP,
+            value: 1.0,
+        })));
+        let decorator = Decorator {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Ident(Ident::new("dec".into(), DUMMY_SP))),
+        };
+
+        // constructor(@dec private readonly x = 1)
+        let param_prop = TsParamProp {
+            span: DUMMY_SP,
+            decorators: vec![decorator.clone()],
+            accessibility: Some(Accessibility::Private),
+            readonly: true,
+            is_override: false,
+            param: TsParamPropParam::Assign(AssignPat {
+                span: DUMMY_SP,
+                left: Box::new(Pat::Ident(x.clone().into())),
+                right: one.clone(),
+                type_ann: None,
+            }),
+        };
+
+        let (prop, assign) = param_prop.expand(renamed.clone());
+        assert_eq!(*prop.key, Expr::Ident(x.clone()));
+        assert_eq!(prop.value, None);
+        assert_eq!(prop.accessibility, Some(Accessibility::Private));
+        assert!(prop.readonly);
+        assert_eq!(prop.decorators, vec![decorator]);
+
+        match assign {
+            Stmt::Expr(ExprStmt { expr, .. }) => match *expr {
+                Expr::Assign(AssignExpr {
+                    left: PatOrExpr::Expr(left),
+                    right,
+                    ..
+                }) => {
+                    match *left {
+                        Expr::Member(MemberExpr { prop, .. }) => {
+                            assert_eq!(*prop, Expr::Ident(x))
+                        }
+                        left => panic!("expected this.x, got {:?}", left),
+                    }
+                    assert_eq!(*right, Expr::Ident(renamed.clone()));
+                }
+                expr => panic!("expected an assignment, got {:?}", expr),
+            },
+            stmt => panic!("expected an expression statement, got {:?}", stmt),
+        }
+
+        assert_eq!(
+            param_prop.to_param(renamed.clone()).pat,
+            Pat::Assign(AssignPat {
+                span: DUMMY_SP,
+                left: Box::new(Pat::Ident(renamed.into())),
+                right: one,
+                type_ann: None,
+            })
+        );
+    }
+
+    #[test]
+    fn type_lit_builder() {
+        let computed = PropName::Computed(crate::ComputedPropName {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Ident(Ident::new("key".into(), DUMMY_SP))),
+        });
+
+        // { a: A; [key]?: B; [k: K]: C; m(): D }
+        let mut lit = TsTypeLit::from_props(vec![
+            (
+                PropName::Ident(Ident::new("a".into(), DUMMY_SP)),
+                *tref("A", 0),
+                false,
+            ),
+            (computed, *tref("B", 0), true),
+        ]);
+        lit.push_index_signature(
+            Ident::new("k".into(), DUMMY_SP),
+            *tref("K", 0),
+            *tref("C", 0),
+        );
+        lit.push_method(
+            PropName::Ident(Ident::new("m".into(), DUMMY_SP)),
+            vec![],
+            *tref("D", 0),
+        );
+
+        let props: Vec<_> = lit
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                TsTypeElement::TsPropertySignature(p) => Some((p.computed, p.optional)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(props, vec![(false, false), (true, true)]);
+        assert!(lit.members[2].is_ts_index_signature());
+        assert!(lit.members[3].is_ts_method_signature());
+    }
+
+    #[test]
+    fn return_type() {
+        let ret = TsTypeAnn {
+            span: DUMMY_SP,
+            type_ann: tref("R", 0),
+        };
+
+        let fn_type = TsFnType {
+            span: DUMMY_SP,
+            params: vec![],
+            type_params: None,
+            type_ann: ret.clone(),
+        };
+        assert_eq!(fn_type.return_type(), Some(&ret));
+
+        let mut call = TsCallSignatureDecl {
+            span: DUMMY_SP,
+            params: vec![],
+            type_ann: None,
+            type_params: None,
+        };
+        assert_eq!(call.return_type(), None);
+        call.type_ann = Some(ret.clone());
+        assert_eq!(call.return_type(), Some(&ret));
+    }
+
+    #[test]
+    fn index_signature_is_static_defaults_to_false() {
+        // Index signatures of interfaces are never static, so the field may be
+        // omitted.
+        let sig: TsIndexSignature = serde_json::from_value(serde_json::json!({
+            "type": "TsIndexSignature",
+            "span": { "start": 0, "end": 0, "ctxt": 0 },
+            "params": [],
+            "typeAnnotation": null,
+            "readonly": false,
+        }))
+        .unwrap();
+        assert!(!sig.is_static);
+    }
+
+    #[test]
+    fn for_each_type() {
+        // (A | B)[]
+        let ty = TsType::TsArrayType(TsArrayType {
+            span: DUMMY_SP,
+            elem_type: parens(TsType::from(TsUnionType {
+                span: DUMMY_SP,
+                types: vec![tref("A", 1), tref("B", 5)],
+            })),
+        });
+
+        let mut kinds = vec![];
+        ty.for_each_type(&mut |ty| {
+            kinds.push(match ty {
+                TsType::TsArrayType(..) => "array",
+                TsType::TsParenthesizedType(..) => "parens",
+                TsType::TsUnionOrIntersectionType(..) => "union",
+                TsType::TsTypeRef(..) => "ref",
+                _ => "other",
+            })
+        });
+        assert_eq!(kinds, vec!["array", "parens", "union", "ref", "ref"]);
+    }
+
+    #[test]
+    fn sort_members() {
+        let keyword = Box::new(TsType::TsKeywordType(TsKeywordType {
+            span: DUMMY_SP,
+            kind: TsKeywordTypeKind::TsStringKeyword,
+        }));
+
+        // B | string | A | B
+        let mut union = TsUnionType {
+            span: DUMMY_SP,
+            types: vec![tref("B", 0), keyword.clone(), tref("A", 4), tref("B", 8)],
+        };
+        union.sort_members();
+        assert!(union.types[0].eq_ignore_span(&keyword));
+        assert_eq!(names(&union.types[1..]), vec!["A", "B", "B"]);
+        // The sort is stable.
+        assert_eq!(union.types[2].span().lo(), BytePos(0));
+        assert_eq!(union.types[3].span().lo(), BytePos(8));
+
+        // A & B and B & A
+        let mut a = TsIntersectionType {
+            span: DUMMY_SP,
+            types: vec![tref("A", 0), tref("B", 4)],
+        };
+        let mut b = TsIntersectionType {
+            span: DUMMY_SP,
+            types: vec![tref("B", 0), tref("A", 4)],
+        };
+        a.sort_members();
+        b.sort_members();
+        assert!(a.eq_ignore_span(&b));
+    }
+
+    #[test]
+    fn modifiers_order() {
+        let all = Modifiers {
+            accessibility: Some(Accessibility::Private),
+            is_static: true,
+            is_abstract: true,
+            is_override: true,
+            readonly: true,
+        };
+        assert_eq!(all.to_string(), "private static abstract override readonly");
+
+        let modifiers = Modifiers {
+            readonly: true,
+            is_static: true,
+            ..Default::default()
+        };
+        assert_eq!(modifiers.keywords().collect::<Vec<_>>(), vec!["static", "readonly"]);
+
+        assert_eq!(Modifiers::default().to_string(), "");
+    }
+}
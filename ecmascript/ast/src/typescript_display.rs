@@ -0,0 +1,687 @@
+//! Lightweight [Display] implementation for typescript types.
+//!
+//! This does not depend on the code generator and is meant for diagnostics.
+//! Comments and original formatting are not preserved, but parentheses are
+//! inserted where they are required to keep the structure of the type.
+
+use crate::{
+    expr::{Expr, ExprOrSuper, MemberExpr},
+    ident::BindingIdent,
+    lit::{Lit, Str},
+    pat::{ArrayPat, ObjectPat, ObjectPatProp, Pat, RestPat},
+    prop::PropName,
+    typescript::*,
+};
+use std::fmt::{self, Display, Formatter};
+
+/// Prints `ty`, wrapping it in parentheses if it's required at the position
+/// described by `context`.
+fn write_ty(f: &mut Formatter<'_>, ty: &TsType, context: TsTypeContext) -> fmt::Result {
+    if ty.needs_parens_in(context) {
+        write!(f, "({})", ty)
+    } else {
+        write!(f, "{}", ty)
+    }
+}
+
+fn write_joined<T>(
+    f: &mut Formatter<'_>,
+    items: &[T],
+    sep: &str,
+    mut op: impl FnMut(&mut Formatter<'_>, &T) -> fmt::Result,
+) -> fmt::Result {
+    for (i, item) in items.iter().enumerate() {
+        if i != 0 {
+            f.write_str(sep)?;
+        }
+        op(f, item)?;
+    }
+    Ok(())
+}
+
+fn write_str_lit(f: &mut Formatter<'_>, s: &Str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in s.value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            _ => write!(f, "{}", c)?,
+        }
+    }
+    f.write_str("\"")
+}
+
+/// Prints simple expressions used as keys. Other expressions are printed as
+/// `...`.
+fn write_expr(f: &mut Formatter<'_>, e: &Expr) -> fmt::Result {
+    match e {
+        Expr::Ident(i) => write!(f, "{}", i.sym),
+        Expr::Lit(Lit::Str(s)) => write_str_lit(f, s),
+        Expr::Lit(Lit::Num(n)) => write!(f, "{}", n),
+        Expr::Lit(Lit::BigInt(n)) => write!(f, "{}n", n.value),
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            prop,
+            computed: false,
+            ..
+        }) => {
+            write_expr(f, obj)?;
+            f.write_str(".")?;
+            write_expr(f, prop)
+        }
+        _ => f.write_str("..."),
+    }
+}
+
+fn write_key(f: &mut Formatter<'_>, key: &Expr, computed: bool, optional: bool) -> fmt::Result {
+    if computed {
+        f.write_str("[")?;
+        write_expr(f, key)?;
+        f.write_str("]")?;
+    } else {
+        write_expr(f, key)?;
+    }
+    if optional {
+        f.write_str("?")?;
+    }
+    Ok(())
+}
+
+fn write_prop_name(f: &mut Formatter<'_>, key: &PropName) -> fmt::Result {
+    match key {
+        PropName::Ident(i) => write!(f, "{}", i.sym),
+        PropName::Str(s) => write_str_lit(f, s),
+        PropName::Num(n) => write!(f, "{}", n),
+        PropName::BigInt(n) => write!(f, "{}n", n.value),
+        PropName::Computed(c) => {
+            f.write_str("[")?;
+            write_expr(f, &c.expr)?;
+            f.write_str("]")
+        }
+    }
+}
+
+fn write_binding_ident(f: &mut Formatter<'_>, i: &BindingIdent) -> fmt::Result {
+    write!(f, "{}", i.id.sym)?;
+    if i.id.optional {
+        f.write_str("?")?;
+    }
+    Ok(())
+}
+
+fn write_array_pat(f: &mut Formatter<'_>, a: &ArrayPat) -> fmt::Result {
+    f.write_str("[")?;
+    write_joined(f, &a.elems, ", ", |f, elem| match elem {
+        Some(elem) => write_pat(f, elem),
+        None => Ok(()),
+    })?;
+    f.write_str("]")
+}
+
+fn write_rest_pat(f: &mut Formatter<'_>, r: &RestPat) -> fmt::Result {
+    f.write_str("...")?;
+    write_pat(f, &r.arg)
+}
+
+fn write_object_pat(f: &mut Formatter<'_>, o: &ObjectPat) -> fmt::Result {
+    if o.props.is_empty() {
+        return f.write_str("{}");
+    }
+    f.write_str("{ ")?;
+    write_joined(f, &o.props, ", ", |f, prop| match prop {
+        ObjectPatProp::KeyValue(p) => {
+            write_prop_name(f, &p.key)?;
+            f.write_str(": ")?;
+            write_pat(f, &p.value)
+        }
+        ObjectPatProp::Assign(p) => write!(f, "{}", p.key.sym),
+        ObjectPatProp::Rest(r) => write_rest_pat(f, r),
+    })?;
+    f.write_str(" }")
+}
+
+fn write_pat(f: &mut Formatter<'_>, pat: &Pat) -> fmt::Result {
+    match pat {
+        Pat::Ident(i) => write_binding_ident(f, i),
+        Pat::Array(a) => write_array_pat(f, a),
+        Pat::Rest(r) => write_rest_pat(f, r),
+        Pat::Object(o) => write_object_pat(f, o),
+        Pat::Assign(a) => write_pat(f, &a.left),
+        Pat::Invalid(..) | Pat::Expr(..) => f.write_str("_"),
+    }
+}
+
+fn write_type_ann(f: &mut Formatter<'_>, type_ann: Option<&TsTypeAnn>) -> fmt::Result {
+    match type_ann {
+        Some(ann) => write!(f, ": {}", ann.type_ann),
+        None => Ok(()),
+    }
+}
+
+fn write_fn_param(f: &mut Formatter<'_>, param: &TsFnParam) -> fmt::Result {
+    match param {
+        TsFnParam::Ident(i) => {
+            write_binding_ident(f, i)?;
+            write_type_ann(f, i.type_ann.as_ref())
+        }
+        TsFnParam::Array(a) => {
+            write_array_pat(f, a)?;
+            write_type_ann(f, a.type_ann.as_ref())
+        }
+        TsFnParam::Rest(r) => {
+            write_rest_pat(f, r)?;
+            write_type_ann(f, r.type_ann.as_ref())
+        }
+        TsFnParam::Object(o) => {
+            write_object_pat(f, o)?;
+            write_type_ann(f, o.type_ann.as_ref())
+        }
+    }
+}
+
+fn write_params(f: &mut Formatter<'_>, params: &[TsFnParam]) -> fmt::Result {
+    f.write_str("(")?;
+    write_joined(f, params, ", ", write_fn_param)?;
+    f.write_str(")")
+}
+
+fn write_type_param(f: &mut Formatter<'_>, param: &TsTypeParam) -> fmt::Result {
+    if param.is_const {
+        f.write_str("const ")?;
+    }
+    if param.is_in {
+        f.write_str("in ")?;
+    }
+    if param.is_out {
+        f.write_str("out ")?;
+    }
+    write!(f, "{}", param.name.sym)?;
+    if let Some(constraint) = &param.constraint {
+        write!(f, " extends {}", constraint)?;
+    }
+    if let Some(default) = &param.default {
+        write!(f, " = {}", default)?;
+    }
+    Ok(())
+}
+
+fn write_type_params(f: &mut Formatter<'_>, params: Option<&TsTypeParamDecl>) -> fmt::Result {
+    if let Some(params) = params {
+        f.write_str("<")?;
+        write_joined(f, &params.params, ", ", write_type_param)?;
+        f.write_str(">")?;
+    }
+    Ok(())
+}
+
+fn write_type_args(f: &mut Formatter<'_>, args: Option<&TsTypeParamInstantiation>) -> fmt::Result {
+    if let Some(args) = args {
+        f.write_str("<")?;
+        write_joined(f, &args.params, ", ", |f, ty| write!(f, "{}", ty))?;
+        f.write_str(">")?;
+    }
+    Ok(())
+}
+
+fn write_plus_minus(f: &mut Formatter<'_>, v: TruePlusMinus) -> fmt::Result {
+    match v {
+        TruePlusMinus::True => Ok(()),
+        TruePlusMinus::Plus => f.write_str("+"),
+        TruePlusMinus::Minus => f.write_str("-"),
+    }
+}
+
+fn write_type_element(f: &mut Formatter<'_>, elem: &TsTypeElement) -> fmt::Result {
+    match elem {
+        TsTypeElement::TsCallSignatureDecl(s) => {
+            write_type_params(f, s.type_params.as_ref())?;
+            write_params(f, &s.params)?;
+            write_type_ann(f, s.type_ann.as_ref())
+        }
+        TsTypeElement::TsConstructSignatureDecl(s) => {
+            f.write_str("new ")?;
+            write_type_params(f, s.type_params.as_ref())?;
+            write_params(f, &s.params)?;
+            write_type_ann(f, s.type_ann.as_ref())
+        }
+        TsTypeElement::TsPropertySignature(s) => {
+            if s.readonly {
+                f.write_str("readonly ")?;
+            }
+            write_key(f, &s.key, s.computed, s.optional)?;
+            write_type_ann(f, s.type_ann.as_ref())
+        }
+        TsTypeElement::TsGetterSignature(s) => {
+            f.write_str("get ")?;
+            write_key(f, &s.key, s.computed, s.optional)?;
+            f.write_str("()")?;
+            write_type_ann(f, s.type_ann.as_ref())
+        }
+        TsTypeElement::TsSetterSignature(s) => {
+            f.write_str("set ")?;
+            write_key(f, &s.key, s.computed, s.optional)?;
+            f.write_str("(")?;
+            write_fn_param(f, &s.param)?;
+            f.write_str(")")
+        }
+        TsTypeElement::TsMethodSignature(s) => {
+            if s.readonly {
+                f.write_str("readonly ")?;
+            }
+            write_key(f, &s.key, s.computed, s.optional)?;
+            write_type_params(f, s.type_params.as_ref())?;
+            write_params(f, &s.params)?;
+            write_type_ann(f, s.type_ann.as_ref())
+        }
+        TsTypeElement::TsIndexSignature(s) => {
+            if s.is_static {
+                f.write_str("static ")?;
+            }
+            if s.readonly {
+                f.write_str("readonly ")?;
+            }
+            f.write_str("[")?;
+            write_joined(f, &s.params, ", ", write_fn_param)?;
+            f.write_str("]")?;
+            write_type_ann(f, s.type_ann.as_ref())
+        }
+    }
+}
+
+impl Display for TsEntityName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TsEntityName::TsQualifiedName(q) => write!(f, "{}.{}", q.left, q.right.sym),
+            TsEntityName::Ident(i) => write!(f, "{}", i.sym),
+        }
+    }
+}
+
+impl Display for TsKeywordTypeKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TsKeywordTypeKind::TsAnyKeyword => "any",
+            TsKeywordTypeKind::TsUnknownKeyword => "unknown",
+            TsKeywordTypeKind::TsNumberKeyword => "number",
+            TsKeywordTypeKind::TsObjectKeyword => "object",
+            TsKeywordTypeKind::TsBooleanKeyword => "boolean",
+            TsKeywordTypeKind::TsBigIntKeyword => "bigint",
+            TsKeywordTypeKind::TsStringKeyword => "string",
+            TsKeywordTypeKind::TsSymbolKeyword => "symbol",
+            TsKeywordTypeKind::TsVoidKeyword => "void",
+            TsKeywordTypeKind::TsUndefinedKeyword => "undefined",
+            TsKeywordTypeKind::TsNullKeyword => "null",
+            TsKeywordTypeKind::TsNeverKeyword => "never",
+            TsKeywordTypeKind::TsIntrinsicKeyword => "intrinsic",
+        })
+    }
+}
+
+impl Display for TsImportType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("import(")?;
+        write_str_lit(f, &self.arg)?;
+        f.write_str(")")?;
+        if let Some(qualifier) = &self.qualifier {
+            write!(f, ".{}", qualifier)?;
+        }
+        write_type_args(f, self.type_args.as_ref())
+    }
+}
+
+impl Display for TsType {
+    /// Prints the type as typescript source code, e.g. `A<B> | C[]`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TsType::TsKeywordType(k) => write!(f, "{}", k.kind),
+            TsType::TsThisType(..) => f.write_str("this"),
+            TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(t)) => {
+                write_type_params(f, t.type_params.as_ref())?;
+                write_params(f, &t.params)?;
+                write!(f, " => {}", t.type_ann.type_ann)
+            }
+            TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsConstructorType(t)) => {
+                if t.is_abstract {
+                    f.write_str("abstract ")?;
+                }
+                f.write_str("new ")?;
+                write_type_params(f, t.type_params.as_ref())?;
+                write_params(f, &t.params)?;
+                write!(f, " => {}", t.type_ann.type_ann)
+            }
+            TsType::TsTypeRef(t) => {
+                write!(f, "{}", t.type_name)?;
+                write_type_args(f, t.type_params.as_ref())
+            }
+            TsType::TsTypeQuery(q) => match &q.expr_name {
+                TsTypeQueryExpr::TsEntityName(n) => write!(f, "typeof {}", n),
+                TsTypeQueryExpr::Import(i) => write!(f, "typeof {}", i),
+            },
+            TsType::TsTypeLit(t) => {
+                if t.members.is_empty() {
+                    return f.write_str("{}");
+                }
+                f.write_str("{ ")?;
+                write_joined(f, &t.members, "; ", write_type_element)?;
+                f.write_str(" }")
+            }
+            TsType::TsArrayType(t) => {
+                write_ty(f, &t.elem_type, TsTypeContext::ArrayElement)?;
+                f.write_str("[]")
+            }
+            TsType::TsTupleType(t) => {
+                f.write_str("[")?;
+                write_joined(f, &t.elem_types, ", ", |f, elem| {
+                    if let Some(label) = &elem.label {
+                        write_pat(f, label)?;
+                        f.write_str(": ")?;
+                    }
+                    write!(f, "{}", elem.ty)
+                })?;
+                f.write_str("]")
+            }
+            TsType::TsOptionalType(t) => {
+                write_ty(f, &t.type_ann, TsTypeContext::ArrayElement)?;
+                f.write_str("?")
+            }
+            TsType::TsRestType(t) => {
+                f.write_str("...")?;
+                write_ty(f, &t.type_ann, TsTypeContext::TypeOperatorOperand)
+            }
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(t)) => {
+                write_joined(f, &t.types, " | ", |f, ty| {
+                    write_ty(f, ty, TsTypeContext::UnionMember)
+                })
+            }
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(t)) => {
+                write_joined(f, &t.types, " & ", |f, ty| {
+                    write_ty(f, ty, TsTypeContext::IntersectionMember)
+                })
+            }
+            TsType::TsConditionalType(t) => {
+                write_ty(f, &t.check_type, TsTypeContext::ConditionalOperand)?;
+                f.write_str(" extends ")?;
+                write_ty(f, &t.extends_type, TsTypeContext::ConditionalOperand)?;
+                write!(f, " ? {} : {}", t.true_type, t.false_type)
+            }
+            TsType::TsInferType(t) => {
+                f.write_str("infer ")?;
+                write_type_param(f, &t.type_param)
+            }
+            TsType::TsParenthesizedType(t) => write!(f, "({})", t.type_ann),
+            TsType::TsTypeOperator(t) => {
+                write!(f, "{} ", t.op)?;
+                write_ty(f, &t.type_ann, TsTypeContext::TypeOperatorOperand)
+            }
+            TsType::TsIndexedAccessType(t) => {
+                write_ty(f, &t.obj_type, TsTypeContext::ArrayElement)?;
+                write!(f, "[{}]", t.index_type)
+            }
+            TsType::TsMappedType(t) => {
+                f.write_str("{ ")?;
+                if let Some(readonly) = t.readonly {
+                    write_plus_minus(f, readonly)?;
+                    f.write_str("readonly ")?;
+                }
+                write!(f, "[{} in ", t.type_param.name.sym)?;
+                if let Some(constraint) = &t.type_param.constraint {
+                    write!(f, "{}", constraint)?;
+                }
+                if let Some(name_type) = &t.name_type {
+                    write!(f, " as {}", name_type)?;
+                }
+                f.write_str("]")?;
+                if let Some(optional) = t.optional {
+                    write_plus_minus(f, optional)?;
+                    f.write_str("?")?;
+                }
+                if let Some(type_ann) = &t.type_ann {
+                    write!(f, ": {}", type_ann)?;
+                }
+                f.write_str(" }")
+            }
+            TsType::TsLitType(t) => match &t.lit {
+                TsLit::Number(n) => write!(f, "{}", n),
+                TsLit::Str(s) => write_str_lit(f, s),
+                TsLit::Bool(b) => write!(f, "{}", b.value),
+                TsLit::BigInt(n) => write!(f, "{}n", n.value),
+                TsLit::Tpl(tpl) => {
+                    f.write_str("`")?;
+                    for (i, quasi) in tpl.quasis.iter().enumerate() {
+                        f.write_str(&quasi.raw.value)?;
+                        if let Some(ty) = tpl.types.get(i) {
+                            write!(f, "${{{}}}", ty)?;
+                        }
+                    }
+                    f.write_str("`")
+                }
+            },
+            TsType::TsTypePredicate(t) => {
+                if t.asserts {
+                    f.write_str("asserts ")?;
+                }
+                match &t.param_name {
+                    TsThisTypeOrIdent::TsThisType(..) => f.write_str("this")?,
+                    TsThisTypeOrIdent::Ident(i) => write!(f, "{}", i.sym)?,
+                }
+                if let Some(type_ann) = &t.type_ann {
+                    write!(f, " is {}", type_ann.type_ann)?;
+                }
+                Ok(())
+            }
+            TsType::TsImportType(t) => write!(f, "{}", t),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use swc_common::DUMMY_SP;
+
+    fn kw(kind: TsKeywordTypeKind) -> TsType {
+        TsType::TsKeywordType(TsKeywordType {
+            span: DUMMY_SP,
+            kind,
+        })
+    }
+
+    fn ident(sym: &str) -> Ident {
+        Ident::new(sym.into(), DUMMY_SP)
+    }
+
+    fn tref(name: &str, args: Vec<TsType>) -> TsType {
+        TsType::TsTypeRef(TsTypeRef {
+            span: DUMMY_SP,
+            type_name: TsEntityName::Ident(ident(name)),
+            type_params: if args.is_empty() {
+                None
+            } else {
+                Some(TsTypeParamInstantiation {
+                    span: DUMMY_SP,
+                    params: args.into_iter().map(Box::new).collect(),
+                })
+            },
+        })
+    }
+
+    fn union(types: Vec<TsType>) -> TsType {
+        TsUnionType {
+            span: DUMMY_SP,
+            types: types.into_iter().map(Box::new).collect(),
+        }
+        .into()
+    }
+
+    fn intersection(types: Vec<TsType>) -> TsType {
+        TsIntersectionType {
+            span: DUMMY_SP,
+            types: types.into_iter().map(Box::new).collect(),
+        }
+        .into()
+    }
+
+    fn array(elem_type: TsType) -> TsType {
+        TsType::TsArrayType(TsArrayType {
+            span: DUMMY_SP,
+            elem_type: Box::new(elem_type),
+        })
+    }
+
+    fn func(params: Vec<TsFnParam>, ret: TsType) -> TsType {
+        TsFnType {
+            span: DUMMY_SP,
+            params,
+            type_params: None,
+            type_ann: TsTypeAnn {
+                span: DUMMY_SP,
+                type_ann: Box::new(ret),
+            },
+        }
+        .into()
+    }
+
+    fn param(name: &str, ty: TsType) -> TsFnParam {
+        TsFnParam::Ident(BindingIdent {
+            id: ident(name),
+            type_ann: Some(TsTypeAnn {
+                span: DUMMY_SP,
+                type_ann: Box::new(ty),
+            }),
+        })
+    }
+
+    fn type_param(name: &str, constraint: Option<TsType>) -> TsTypeParam {
+        TsTypeParam {
+            span: DUMMY_SP,
+            name: ident(name),
+            is_in: false,
+            is_out: false,
+            is_const: false,
+            constraint: constraint.map(Box::new),
+            default: None,
+        }
+    }
+
+    #[test]
+    fn union_of_ref_and_array() {
+        let ty = union(vec![
+            tref("A", vec![tref("B", vec![])]),
+            array(tref("C", vec![])),
+        ]);
+        assert_eq!(ty.to_string(), "A<B> | C[]");
+    }
+
+    #[test]
+    fn union_in_intersection() {
+        let ty = intersection(vec![
+            union(vec![tref("A", vec![]), tref("B", vec![])]),
+            tref("C", vec![]),
+        ]);
+        assert_eq!(ty.to_string(), "(A | B) & C");
+
+        let ty = union(vec![
+            intersection(vec![tref("A", vec![]), tref("B", vec![])]),
+            tref("C", vec![]),
+        ]);
+        assert_eq!(ty.to_string(), "A & B | C");
+    }
+
+    #[test]
+    fn fn_type_precedence() {
+        let f = func(
+            vec![param("a", kw(TsKeywordTypeKind::TsStringKeyword))],
+            kw(TsKeywordTypeKind::TsVoidKeyword),
+        );
+        assert_eq!(f.to_string(), "(a: string) => void");
+        assert_eq!(
+            union(vec![f.clone(), kw(TsKeywordTypeKind::TsNullKeyword)]).to_string(),
+            "((a: string) => void) | null"
+        );
+        assert_eq!(array(f).to_string(), "((a: string) => void)[]");
+
+        let ret = union(vec![
+            kw(TsKeywordTypeKind::TsStringKeyword),
+            kw(TsKeywordTypeKind::TsNumberKeyword),
+        ]);
+        assert_eq!(func(vec![], ret).to_string(), "() => string | number");
+    }
+
+    #[test]
+    fn keyof_array() {
+        let keyof = TsType::TsTypeOperator(TsTypeOperator {
+            span: DUMMY_SP,
+            op: TsTypeOperatorOp::KeyOf,
+            type_ann: Box::new(tref("T", vec![])),
+        });
+        assert_eq!(array(keyof).to_string(), "(keyof T)[]");
+    }
+
+    #[test]
+    fn conditional() {
+        let infer = TsType::TsInferType(TsInferType {
+            span: DUMMY_SP,
+            type_param: type_param("U", None),
+        });
+        let ty = TsType::TsConditionalType(TsConditionalType {
+            span: DUMMY_SP,
+            check_type: Box::new(tref("T", vec![])),
+            extends_type: Box::new(array(infer)),
+            true_type: Box::new(tref("U", vec![])),
+            false_type: Box::new(kw(TsKeywordTypeKind::TsNeverKeyword)),
+        });
+        assert_eq!(ty.to_string(), "T extends (infer U)[] ? U : never");
+    }
+
+    #[test]
+    fn mapped() {
+        let ty = TsType::TsMappedType(TsMappedType {
+            span: DUMMY_SP,
+            readonly: Some(TruePlusMinus::Minus),
+            type_param: type_param(
+                "K",
+                Some(TsType::TsTypeOperator(TsTypeOperator {
+                    span: DUMMY_SP,
+                    op: TsTypeOperatorOp::KeyOf,
+                    type_ann: Box::new(tref("T", vec![])),
+                })),
+            ),
+            name_type: None,
+            optional: Some(TruePlusMinus::True),
+            type_ann: Some(Box::new(TsType::TsIndexedAccessType(TsIndexedAccessType {
+                span: DUMMY_SP,
+                readonly: false,
+                obj_type: Box::new(tref("T", vec![])),
+                index_type: Box::new(tref("K", vec![])),
+            }))),
+        });
+        assert_eq!(ty.to_string(), "{ -readonly [K in keyof T]?: T[K] }");
+    }
+
+    #[test]
+    fn template_literal() {
+        let quasi = |raw: &str| TplElement {
+            span: DUMMY_SP,
+            tail: false,
+            cooked: None,
+            raw: Str {
+                span: DUMMY_SP,
+                value: raw.into(),
+                has_escape: false,
+                kind: Default::default(),
+            },
+        };
+        let ty = TsType::TsLitType(TsLitType {
+            span: DUMMY_SP,
+            lit: TsLit::Tpl(TsTplLitType {
+                span: DUMMY_SP,
+                types: vec![Box::new(tref("T", vec![]))],
+                quasis: vec![quasi("on"), quasi("Change")],
+            }),
+        });
+        assert_eq!(ty.to_string(), "`on${T}Change`");
+    }
+}
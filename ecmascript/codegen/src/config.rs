@@ -1,4 +1,21 @@
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Config {
     pub minify: bool,
+
+    /// The separator emitted between the members of a type literal.
+    pub type_lit_separator: TypeLitSeparator,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeLitSeparator {
+    /// `{ x: number; y: string }`
+    Semicolon,
+    /// `{ x: number, y: string }`
+    Comma,
+}
+
+impl Default for TypeLitSeparator {
+    fn default() -> Self {
+        TypeLitSeparator::Semicolon
+    }
 }
@@ -92,7 +92,19 @@ impl<'a> Emitter<'a> {
     fn emit_var_declarator(&mut self, node: &VarDeclarator) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo(), false)?;
 
-        emit!(node.name);
+        match &node.name {
+            // `!` goes between the binding and its type annotation.
+            Pat::Ident(BindingIdent { id, type_ann }) if node.definite => {
+                emit!(id);
+                punct!("!");
+                if let Some(ty) = type_ann {
+                    punct!(":");
+                    formatting_space!();
+                    emit!(ty);
+                }
+            }
+            _ => emit!(node.name),
+        }
 
         if let Some(ref init) = node.init {
             formatting_space!();
@@ -23,6 +23,10 @@ impl<'a> Emitter<'a> {
         punct!("<");
         emit!(node.name);
 
+        if let Some(type_args) = &node.type_args {
+            emit!(type_args);
+        }
+
         space!();
 
         self.emit_list(
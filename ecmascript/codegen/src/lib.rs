@@ -1,7 +1,7 @@
 #![recursion_limit = "1024"]
 #![allow(unused_variables)]
 
-pub use self::config::Config;
+pub use self::config::{Config, TypeLitSeparator};
 use self::{
     list::ListFormat,
     text_writer::WriteJs,
@@ -215,6 +215,11 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     fn emit_import_specific(&mut self, node: &ImportNamedSpecifier) -> Result {
+        if node.import_kind.is_type() {
+            keyword!("type");
+            space!();
+        }
+
         if let Some(ref imported) = node.imported {
             emit!(imported);
             space!();
@@ -251,6 +256,11 @@ impl<'a> Emitter<'a> {
     fn emit_named_export_specifier(&mut self, node: &ExportNamedSpecifier) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo(), false)?;
 
+        if node.export_kind.is_type() {
+            keyword!("type");
+            space!();
+        }
+
         if let Some(ref exported) = node.exported {
             emit!(node.orig);
             space!();
@@ -667,8 +677,10 @@ impl<'a> Emitter<'a> {
         }
 
         let parens = !self.cfg.minify
+            || node.type_params.is_some()
+            || node.return_type.is_some()
             || match node.params.as_slice() {
-                [Pat::Ident(_)] => false,
+                [Pat::Ident(i)] => i.type_ann.is_some(),
                 _ => true,
             };
 
@@ -683,6 +695,12 @@ impl<'a> Emitter<'a> {
             punct!(")");
         }
 
+        if let Some(ty) = &node.return_type {
+            punct!(":");
+            formatting_space!();
+            emit!(ty);
+        }
+
         punct!("=>");
         emit!(node.body);
     }
@@ -859,10 +877,7 @@ impl<'a> Emitter<'a> {
     fn emit_private_method(&mut self, n: &PrivateMethod) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
-        if n.is_static {
-            keyword!("static");
-            space!();
-        }
+        self.emit_modifiers(n.modifiers())?;
         match n.kind {
             MethodKind::Method => {
                 if n.function.is_async {
@@ -907,12 +922,8 @@ impl<'a> Emitter<'a> {
     fn emit_class_method(&mut self, n: &ClassMethod) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
-        self.emit_accesibility(n.accessibility)?;
+        self.emit_modifiers(n.modifiers())?;
 
-        if n.is_static {
-            keyword!("static");
-            space!();
-        }
         match n.kind {
             MethodKind::Method => {
                 if n.function.is_async {
@@ -971,14 +982,12 @@ impl<'a> Emitter<'a> {
 
         self.emit_list(n.span, Some(&n.decorators), ListFormat::Decorators)?;
 
-        self.emit_accesibility(n.accessibility)?;
-
-        if n.readonly {
-            keyword!("readonly");
-            space!();
-        }
+        self.emit_modifiers(n.modifiers())?;
 
         emit!(n.key);
+        if n.definite {
+            punct!("!");
+        }
         if let Some(type_ann) = &n.type_ann {
             punct!(":");
             space!();
@@ -1006,19 +1015,11 @@ impl<'a> Emitter<'a> {
     fn emit_class_prop(&mut self, n: &ClassProp) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
-        if n.accessibility != Some(Accessibility::Public) {
-            self.emit_accesibility(n.accessibility)?;
-        }
-
-        if n.readonly {
-            keyword!("readonly");
-            space!()
-        }
-
-        if n.is_static {
-            keyword!("static");
-            space!();
+        let mut modifiers = n.modifiers();
+        if modifiers.accessibility == Some(Accessibility::Public) {
+            modifiers.accessibility = None;
         }
+        self.emit_modifiers(modifiers)?;
 
         if n.computed {
             punct!("[");
@@ -1028,6 +1029,10 @@ impl<'a> Emitter<'a> {
             emit!(n.key);
         }
 
+        if n.definite {
+            punct!("!");
+        }
+
         if let Some(ty) = &n.type_ann {
             punct!(":");
             space!();
@@ -1053,11 +1058,18 @@ impl<'a> Emitter<'a> {
 
     fn emit_accesibility(&mut self, n: Option<Accessibility>) -> Result {
         if let Some(a) = n {
-            match a {
-                Accessibility::Public => keyword!(self, "public"),
-                Accessibility::Protected => keyword!(self, "protected"),
-                Accessibility::Private => keyword!(self, "private"),
-            }
+            keyword!(self, a.as_str());
+            space!(self);
+        }
+
+        Ok(())
+    }
+
+    /// Emits the modifiers in the order typescript requires, each followed by
+    /// a space.
+    fn emit_modifiers(&mut self, n: Modifiers) -> Result {
+        for k in n.keywords() {
+            keyword!(self, k);
             space!(self);
         }
 
@@ -1314,11 +1326,12 @@ impl<'a> Emitter<'a> {
         self.emit_leading_comments_of_pos(node.span().lo(), false)?;
 
         punct!("[");
-        self.emit_list(
-            node.span(),
-            Some(&node.elems),
-            ListFormat::ArrayLiteralExpressionElements,
-        )?;
+        let mut format = ListFormat::ArrayLiteralExpressionElements;
+        if let Some(None) = node.elems.last() {
+            // `[a,,]` has two elements, but `[a,]` has only one.
+            format |= ListFormat::ForceTrailingComma;
+        }
+        self.emit_list(node.span(), Some(&node.elems), format)?;
         punct!("]");
     }
 
@@ -1644,11 +1657,17 @@ impl<'a> Emitter<'a> {
                 }
             };
 
-            if has_trailing_comma && format.contains(ListFormat::CommaDelimited) {
+            if (has_trailing_comma || format.contains(ListFormat::ForceTrailingComma))
+                && format.contains(ListFormat::CommaDelimited)
+            {
                 self.wr.write_punct(",")?;
                 formatting_space!(self);
             }
 
+            if format.contains(ListFormat::SemicolonDelimited) {
+                formatting_semi!(self);
+            }
+
             {
                 // Emit any trailing comment of the last element in the list
                 // i.e
@@ -2240,6 +2259,9 @@ impl<'a> Emitter<'a> {
         match f & ListFormat::DelimitersMask {
             ListFormat::None => {}
             ListFormat::CommaDelimited => self.wr.write_punct(",")?,
+            ListFormat::SemicolonDelimited => {
+                formatting_semi!(self);
+            }
             ListFormat::BarDelimited => {
                 if !self.cfg.minify {
                     self.wr.write_space()?;
@@ -32,11 +32,16 @@ add_bitflags!(
         AmpersandDelimited: 1 << 3,
         /// Each list item is comma (",") delimited.
         CommaDelimited: 1 << 4,
-        DelimitersMask: BarDelimited | AmpersandDelimited | CommaDelimited,
+        /// Each list item is semicolon (";") terminated.
+        SemicolonDelimited: 1 << 21,
+        DelimitersMask: BarDelimited | AmpersandDelimited | CommaDelimited | SemicolonDelimited,
     },
     Values {
         /// Write a trailing comma (",") if present.
         AllowTrailingComma: 1 << 5,
+        /// Always write a trailing comma (","). Required if the last element of
+        /// an array literal is a hole.
+        ForceTrailingComma: 1 << 20,
     },
     /// Whitespace
     Values {
@@ -137,7 +142,7 @@ add_bitflags!(
         MultiLineFunctionBodyStatements: MultiLine,
         ClassHeritageClauses: SingleLine | SpaceBetweenSiblings,
         ClassMembers: Indented | MultiLine,
-        InterfaceMembers: Indented | MultiLine,
+        InterfaceMembers: Indented | MultiLine | SemicolonDelimited,
         EnumMembers: CommaDelimited | Indented | MultiLine,
         CaseBlockClauses: Indented | MultiLine,
         NamedImportsOrExportsElements: CommaDelimited
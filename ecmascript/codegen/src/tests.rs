@@ -1,4 +1,4 @@
-use self::swc_ecma_parser::{EsConfig, Parser, StringInput, Syntax};
+use self::swc_ecma_parser::{EsConfig, Parser, StringInput, Syntax, TsConfig};
 use super::*;
 use crate::config::Config;
 use crate::text_writer::omit_trailing_semi;
@@ -91,7 +91,10 @@ fn parse_then_emit(from: &str, cfg: Config, syntax: Syntax, target: EsVersion) -
 pub(crate) fn assert_min(from: &str, to: &str) {
     let out = parse_then_emit(
         from,
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Syntax::default(),
         EsVersion::latest(),
     );
@@ -100,7 +103,15 @@ pub(crate) fn assert_min(from: &str, to: &str) {
 }
 
 pub(crate) fn assert_min_target(from: &str, to: &str, target: EsVersion) {
-    let out = parse_then_emit(from, Config { minify: true }, Syntax::default(), target);
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::default(),
+        target,
+    );
 
     assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to),);
 }
@@ -109,7 +120,23 @@ pub(crate) fn assert_min_target(from: &str, to: &str, target: EsVersion) {
 pub(crate) fn assert_min_typescript(from: &str, to: &str) {
     let out = parse_then_emit(
         from,
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::Typescript(Default::default()),
+        EsVersion::latest(),
+    );
+
+    assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to),);
+}
+
+/// Clone of the regular `assert_min_typescript` function but with a custom
+/// config.
+pub(crate) fn assert_typescript_with_config(from: &str, to: &str, cfg: Config) {
+    let out = parse_then_emit(
+        from,
+        cfg,
         Syntax::Typescript(Default::default()),
         EsVersion::latest(),
     );
@@ -120,7 +147,10 @@ pub(crate) fn assert_min_typescript(from: &str, to: &str) {
 pub(crate) fn assert_pretty(from: &str, to: &str) {
     let out = parse_then_emit(
         from,
-        Config { minify: false },
+        Config {
+            minify: false,
+            ..Default::default()
+        },
         Syntax::default(),
         EsVersion::latest(),
     );
@@ -235,7 +265,10 @@ fn empty_named_export_min() {
     test_from_to_custom_config(
         "export { }",
         "export{};",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -250,7 +283,10 @@ fn empty_named_export_from_min() {
     test_from_to_custom_config(
         "export { } from 'foo';",
         "export{}from'foo';",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -265,7 +301,10 @@ fn named_export_from_min() {
     test_from_to_custom_config(
         "export { bar } from 'foo';",
         "export{bar}from'foo';",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -288,7 +327,10 @@ fn export_namespace_from_min() {
     test_from_to_custom_config(
         "export * as Foo from 'foo';",
         "export*as Foo from'foo';",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Syntax::Es(EsConfig {
             export_namespace_from: true,
             ..EsConfig::default()
@@ -314,7 +356,10 @@ fn named_and_namespace_export_from_min() {
     test_from_to_custom_config(
         "export * as Foo, { bar } from 'foo';",
         "export*as Foo,{bar}from'foo';",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Syntax::Es(EsConfig {
             export_namespace_from: true,
             ..EsConfig::default()
@@ -484,6 +529,19 @@ fn jsx_1() {
     );
 }
 
+#[test]
+fn jsx_type_args() {
+    test_from_to_custom_config(
+        "<Foo<string> prop={x} />;",
+        "<Foo<string> prop={x}/>;",
+        Default::default(),
+        Syntax::Typescript(TsConfig {
+            tsx: true,
+            ..Default::default()
+        }),
+    );
+}
+
 #[test]
 fn deno_8162() {
     test_from_to(
@@ -592,6 +650,30 @@ fn issue_1452_1() {
     assert_min("async foo => 0", "async foo=>0");
 }
 
+#[test]
+fn using_decl() {
+    let assert_min_using = |from: &str, to: &str| {
+        let out = parse_then_emit(
+            from,
+            Config {
+                minify: true,
+                ..Default::default()
+            },
+            Syntax::Es(EsConfig {
+                top_level_await: true,
+                explicit_resource_management: true,
+                ..Default::default()
+            }),
+            EsVersion::latest(),
+        );
+
+        assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to),);
+    };
+
+    assert_min_using("using x = a;", "using x=a");
+    assert_min_using("await using x = a;", "await using x=a");
+}
+
 #[test]
 fn issue_1619_1() {
     assert_min_target(
@@ -1,5 +1,5 @@
 use super::{Emitter, Result};
-use crate::{list::ListFormat, text_writer::WriteJs};
+use crate::{config::TypeLitSeparator, list::ListFormat, text_writer::WriteJs};
 use swc_common::Spanned;
 use swc_ecma_ast::*;
 use swc_ecma_codegen_macros::emitter;
@@ -46,9 +46,8 @@ impl<'a> Emitter<'a> {
         punct!(")");
 
         if let Some(type_ann) = &n.type_ann {
-            space!();
             punct!(":");
-            space!();
+            formatting_space!();
 
             emit!(type_ann);
         }
@@ -79,14 +78,24 @@ impl<'a> Emitter<'a> {
     }
 
     #[emitter]
-    fn emit_ts_constructor_signature_decl(&mut self, n: &TsConstructSignatureDecl) -> Result {
+    fn emit_ts_construct_signature_decl(&mut self, n: &TsConstructSignatureDecl) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
-        keyword!("constructor");
+        keyword!("new");
+        formatting_space!();
+
+        emit!(n.type_params);
 
         punct!("(");
         self.emit_list(n.span, Some(&n.params), ListFormat::Parameters)?;
         punct!(")");
+
+        if let Some(type_ann) = &n.type_ann {
+            punct!(":");
+            formatting_space!();
+
+            emit!(type_ann);
+        }
     }
 
     #[emitter]
@@ -261,6 +270,8 @@ impl<'a> Emitter<'a> {
     fn emit_ts_index_signature(&mut self, n: &TsIndexSignature) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
+        self.emit_modifiers(n.modifiers())?;
+
         punct!("[");
         self.emit_list(n.span, Some(&n.params), ListFormat::Parameters)?;
         punct!("]");
@@ -454,6 +465,13 @@ impl<'a> Emitter<'a> {
 
         emit!(n.type_param.constraint);
 
+        if let Some(name_type) = &n.name_type {
+            space!();
+            keyword!("as");
+            space!();
+            emit!(name_type);
+        }
+
         punct!("]");
 
         match n.optional {
@@ -610,12 +628,7 @@ impl<'a> Emitter<'a> {
     fn emit_ts_param_prop(&mut self, n: &TsParamProp) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
-        self.emit_accesibility(n.accessibility)?;
-
-        if n.readonly {
-            keyword!("readonly");
-            space!();
-        }
+        self.emit_modifiers(n.modifiers())?;
 
         emit!(n.param);
     }
@@ -855,7 +868,6 @@ impl<'a> Emitter<'a> {
                 emit!(n)
             }
         }
-        formatting_semi!();
     }
 
     #[emitter]
@@ -893,11 +905,17 @@ impl<'a> Emitter<'a> {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
         punct!("{");
-        self.emit_list(
-            n.span,
-            Some(&n.members),
-            ListFormat::MultiLineTypeLiteralMembers,
-        )?;
+        if !n.members.is_empty() {
+            let separator = match self.cfg.type_lit_separator {
+                TypeLitSeparator::Semicolon => ListFormat::SemicolonDelimited,
+                TypeLitSeparator::Comma => ListFormat::CommaDelimited,
+            };
+            self.emit_list(
+                n.span,
+                Some(&n.members),
+                ListFormat::MultiLineTypeLiteralMembers | separator,
+            )?;
+        }
         punct!("}");
     }
 
@@ -918,6 +936,21 @@ impl<'a> Emitter<'a> {
     fn emit_ts_type_param(&mut self, n: &TsTypeParam) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo(), false)?;
 
+        if n.is_const {
+            keyword!("const");
+            space!();
+        }
+
+        if n.is_in {
+            keyword!("in");
+            space!();
+        }
+
+        if n.is_out {
+            keyword!("out");
+            space!();
+        }
+
         emit!(n.name);
 
         if let Some(constraints) = &n.constraint {
@@ -1023,7 +1056,10 @@ impl<'a> Emitter<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::tests::assert_min_typescript;
+    use crate::{
+        config::{Config, TypeLitSeparator},
+        tests::{assert_min_typescript, assert_typescript_with_config},
+    };
 
     #[test]
     fn qualified_type() {
@@ -1032,4 +1068,294 @@ mod tests {
             "var memory:WebAssembly.Memory",
         );
     }
+
+    #[test]
+    fn conditional_type_multiple_infer() {
+        assert_min_typescript(
+            "type X<T> = T extends [infer A, infer B] ? [A, B] : never;",
+            "type X<T>=T extends [infer A,infer B] ? [A,B] : never",
+        );
+    }
+
+    #[test]
+    fn definite_assignment() {
+        assert_min_typescript("let x!: number;", "let x!:number");
+        assert_min_typescript(
+            "class A { x!: string; static y!: boolean }",
+            "class A{x!: string;static y!: boolean}",
+        );
+        assert_min_typescript("class A { #x!: number }", "class A{#x!: number}");
+    }
+
+    #[test]
+    fn type_param_modifiers() {
+        assert_min_typescript(
+            "interface A<in out T> {} class B<const T> {}",
+            "interface A<in out T>{}class B<const T>{}",
+        );
+    }
+
+    #[test]
+    fn constructor_type() {
+        assert_min_typescript("type A = new () => X;", "type A=new ()=>X");
+        assert_min_typescript("type A = new <T>(x: T) => T;", "type A=new <T>(x:T)=>T");
+    }
+
+    #[test]
+    fn type_only_specifiers() {
+        assert_min_typescript(
+            "import { type A, type B as C, D } from 'a';",
+            "import {type A,type B as C,D}from'a';",
+        );
+        assert_min_typescript(
+            "export { type A, type B as C };",
+            "export{type A,type B as C};",
+        );
+    }
+
+    #[test]
+    fn optional_method_signature() {
+        assert_min_typescript(
+            "interface I { f?(): void; g(): void }",
+            "interface I{f?():void;g():void}",
+        );
+    }
+
+    #[test]
+    fn type_predicate_return_type() {
+        assert_min_typescript(
+            "function f(x: unknown): x is Foo { return true; }",
+            "function f(x:unknown):x is Foo{return true}",
+        );
+        assert_min_typescript(
+            "const f = (x): x is Foo => true;",
+            "const f=(x):x is Foo=>true",
+        );
+        assert_min_typescript("const f = (x: number) => x;", "const f=(x:number)=>x");
+        assert_min_typescript(
+            "function f(x): asserts x is Foo {}",
+            "function f(x):asserts x is Foo{}",
+        );
+    }
+
+    #[test]
+    fn this_type_predicate_on_methods() {
+        assert_min_typescript(
+            "interface I { isFoo(): this is Foo; }",
+            "interface I{isFoo():this is Foo}",
+        );
+        assert_min_typescript(
+            "interface I { assertIsFoo(): asserts this is Foo; }",
+            "interface I{assertIsFoo():asserts this is Foo}",
+        );
+        assert_min_typescript(
+            "interface I { assertThis(): asserts this; }",
+            "interface I{assertThis():asserts this}",
+        );
+        assert_min_typescript(
+            "class A { assertIsFoo(): asserts this is Foo {} }",
+            "class A{assertIsFoo():asserts this is Foo{}}",
+        );
+        assert_min_typescript(
+            "class A { assertThis(): asserts this {} }",
+            "class A{assertThis():asserts this{}}",
+        );
+    }
+
+    #[test]
+    fn mapped_type_key_remapping_with_infer() {
+        let src = "type OnEvents<T> = {\n    [K in keyof T as K extends `on${infer E}` ? E : never]: \
+                   T[K];\n};";
+        assert_typescript_with_config(src, src, Config::default());
+    }
+
+    #[test]
+    fn boolean_literal_types() {
+        assert_min_typescript("type T = true;", "type T=true");
+        assert_min_typescript("type F = false;", "type F=false");
+        assert_min_typescript("type B = true | false;", "type B=true|false");
+        assert_min_typescript("type B = boolean;", "type B=boolean");
+
+        let pretty = Config::default();
+        assert_typescript_with_config("type B = true | false;", "type B = true | false;", pretty);
+    }
+
+    #[test]
+    fn indexed_access_by_number() {
+        assert_min_typescript(
+            "type E<T extends any[]> = T[number];",
+            "type E<T extends any[]>=T[number]",
+        );
+        assert_min_typescript(
+            "type E = (typeof list)[number];",
+            "type E=(typeof list)[number]",
+        );
+        assert_min_typescript("type E = T[number][];", "type E=T[number][]");
+    }
+
+    #[test]
+    fn tuple_rest_positions() {
+        assert_min_typescript(
+            "type T = [...string[], number];",
+            "type T=[...string[],number]",
+        );
+        assert_min_typescript(
+            "type T = [string, ...number[], boolean];",
+            "type T=[string,...number[],boolean]",
+        );
+        assert_min_typescript(
+            "type T = [string, ...number[]];",
+            "type T=[string,...number[]]",
+        );
+    }
+
+    #[test]
+    fn trailing_comma_in_type_lists() {
+        assert_min_typescript("type T = [string, number,];", "type T=[string,number]");
+        assert_min_typescript(
+            "type U = Map<string, number,>;",
+            "type U=Map<string,number>",
+        );
+    }
+
+    #[test]
+    fn bigint_literal_type() {
+        assert_min_typescript("type X = 1n | 2n;", "type X=1n|2n");
+        assert_min_typescript("type X = -1n;", "type X=-1n");
+    }
+
+    #[test]
+    fn unique_symbol_const() {
+        assert_min_typescript(
+            "const x: unique symbol = Symbol();",
+            "const x:unique symbol=Symbol()",
+        );
+    }
+
+    #[test]
+    fn type_param_default_referencing_param() {
+        assert_min_typescript(
+            "type P<T, U = Array<T>> = [T, U];",
+            "type P<T,U=Array<T>>=[T,U]",
+        );
+    }
+
+    #[test]
+    fn type_lit_members() {
+        assert_min_typescript("type A = {};", "type A={}");
+        assert_min_typescript("type A = { x: number };", "type A={x:number}");
+        assert_min_typescript(
+            "type A = { x: number; y: string };",
+            "type A={x:number;y:string}",
+        );
+
+        let pretty = Config::default();
+        assert_typescript_with_config("type A = {};", "type A = {};", pretty);
+        assert_typescript_with_config(
+            "type A = { x: number };",
+            "type A = {\n    x: number;\n};",
+            pretty,
+        );
+        assert_typescript_with_config(
+            "type A = { x: number; y: string };",
+            "type A = {\n    x: number;\n    y: string;\n};",
+            pretty,
+        );
+    }
+
+    #[test]
+    fn type_lit_comma_separator() {
+        let min = Config {
+            minify: true,
+            type_lit_separator: TypeLitSeparator::Comma,
+        };
+        assert_typescript_with_config("type A = {};", "type A={}", min);
+        assert_typescript_with_config("type A = { x: number };", "type A={x:number}", min);
+        assert_typescript_with_config(
+            "type A = { x: number; y: string };",
+            "type A={x:number,y:string}",
+            min,
+        );
+        assert_typescript_with_config(
+            "interface A { x: number; y: string }",
+            "interface A{x:number;y:string}",
+            min,
+        );
+
+        let pretty = Config {
+            type_lit_separator: TypeLitSeparator::Comma,
+            ..Default::default()
+        };
+        assert_typescript_with_config(
+            "type A = { x: number; y: string };",
+            "type A = {\n    x: number,\n    y: string\n};",
+            pretty,
+        );
+    }
+
+    #[test]
+    fn infer_type_param_constraint() {
+        assert_min_typescript(
+            "type First<T> = T extends [infer U extends string, ...any] ? U : never;",
+            "type First<T>=T extends [infer U extends string,...any] ? U : never",
+        );
+        assert_min_typescript(
+            "type X<T> = T extends infer U extends string ? U : never;",
+            "type X<T>=T extends infer U extends string ? U : never",
+        );
+    }
+
+    #[test]
+    fn signatures() {
+        assert_min_typescript(
+            "interface I { (x: number): void; }",
+            "interface I{(x:number):void}",
+        );
+        assert_min_typescript(
+            "interface I<T> { new (x: number): T; }",
+            "interface I<T>{new(x:number):T}",
+        );
+        assert_min_typescript("interface I { m<T>(x: T): T; }", "interface I{m<T>(x:T):T}");
+        assert_min_typescript("type F = <T>(x: T) => void;", "type F=<T>(x:T)=>void");
+        assert_min_typescript(
+            "type C<T> = new (x: number) => T;",
+            "type C<T>=new (x:number)=>T",
+        );
+        assert_min_typescript(
+            "type C<T> = abstract new () => T;",
+            "type C<T>=abstract new ()=>T",
+        );
+    }
+
+    #[test]
+    fn override_modifier() {
+        assert_min_typescript(
+            "class A extends B { constructor(public override readonly x) {} override m() {} }",
+            "class A extends B{constructor(public override readonly x){}override m(){}}",
+        );
+        assert_min_typescript(
+            "class A extends B { static override readonly p = 1 }",
+            "class A extends B{static override readonly p=1}",
+        );
+    }
+
+    #[test]
+    fn index_signature_modifiers() {
+        assert_min_typescript(
+            "interface I { readonly [key: string]: number; }",
+            "interface I{readonly [key:string]:number}",
+        );
+        assert_min_typescript(
+            "class A { static [key: string]: number }",
+            "class A{static [key:string]:number}",
+        );
+    }
+
+    #[test]
+    fn class_member_modifiers() {
+        assert_min_typescript(
+            "class A { static #x = 1; private static readonly y = 1; }",
+            "class A{static #x=1;private static readonly y=1}",
+        );
+    }
 }
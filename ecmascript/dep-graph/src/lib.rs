@@ -79,7 +79,7 @@ impl<'a> Visit for DependencyCollector<'a> {
         let location = self.get_location(span);
         let leading_comments = self.get_leading_comments(span);
         let specifier_location = self.get_location(node.src.span);
-        let kind = if node.type_only {
+        let kind = if node.import_kind.is_type() {
             DependencyKind::ImportType
         } else {
             DependencyKind::Import
@@ -105,7 +105,7 @@ impl<'a> Visit for DependencyCollector<'a> {
             let location = self.get_location(span);
             let leading_comments = self.get_leading_comments(span);
             let specifier_location = self.get_location(src.span);
-            let kind = if node.type_only {
+            let kind = if node.export_kind.is_type() {
                 DependencyKind::ExportType
             } else {
                 DependencyKind::Export
@@ -132,6 +132,7 @@ pub enum SyntaxError {
     ReturnNotAllowed,
     TooManyVarInForInHead,
     VarInitializerInForInHead,
+    UsingDeclInForIn,
     LabelledGenerator,
     YieldParamInGen,
 
@@ -148,6 +149,7 @@ pub enum SyntaxError {
     DecoratorOnExport,
 
     TsRequiredAfterOptional,
+    TsRestAfterRest,
     TsInvalidParamPropPat,
 
     SpaceBetweenHashAndIdent,
@@ -345,6 +347,9 @@ impl SyntaxError {
             SyntaxError::VarInitializerInForInHead => {
                 "Unexpected initializer in for in/of loop".into()
             }
+            SyntaxError::UsingDeclInForIn => {
+                "`using` declarations are not allowed in for-in loops".into()
+            }
             SyntaxError::LabelledGenerator => "Generator cannot be labelled".into(),
             SyntaxError::YieldParamInGen => {
                 "'yield' cannot be used as a parameter within generator".into()
@@ -376,6 +381,9 @@ impl SyntaxError {
             SyntaxError::TsRequiredAfterOptional => {
                 "A required element cannot follow an optional element.".into()
             }
+            SyntaxError::TsRestAfterRest => {
+                "A rest element cannot follow another rest element.".into()
+            }
             SyntaxError::TsInvalidParamPropPat => {
                 "Typescript parameter property must be identifer or assignment pattern".into()
             }
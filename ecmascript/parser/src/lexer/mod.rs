@@ -887,7 +887,7 @@ impl<'a, I: Input> Lexer<'a, I> {
                         }
                         has_escape = true
                     }
-                    c if c.is_line_break() => l.error(start, SyntaxError::UnterminatedStrLit)?,
+                    c if c.is_line_break() => break,
                     _ => {
                         out.push(c);
                         l.bump();
@@ -895,6 +895,16 @@ impl<'a, I: Input> Lexer<'a, I> {
                 }
             }
 
+            // In editors, a string literal type is unterminated while it's being
+            // typed, so we close it at the end of the line.
+            if l.syntax.error_recovery() && l.ctx.in_type {
+                l.emit_error(start, SyntaxError::UnterminatedStrLit);
+                return Ok(Token::Str {
+                    value: (&**out).into(),
+                    has_escape,
+                });
+            }
+
             l.error(start, SyntaxError::UnterminatedStrLit)?
         })
     }
@@ -266,6 +266,19 @@ impl Syntax {
         }
     }
 
+    pub fn explicit_resource_management(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                explicit_resource_management,
+                ..
+            })
+            | Syntax::Typescript(TsConfig {
+                explicit_resource_management,
+                ..
+            }) => explicit_resource_management,
+        }
+    }
+
     pub fn dts(self) -> bool {
         match self {
             Syntax::Typescript(t) => t.dts,
@@ -273,6 +286,14 @@ impl Syntax {
         }
     }
 
+    /// Should we recover from errors which would abort parsing otherwise?
+    pub(crate) fn error_recovery(self) -> bool {
+        match self {
+            Syntax::Typescript(t) => t.error_recovery,
+            Syntax::Es(..) => false,
+        }
+    }
+
     pub(crate) fn early_errors(self) -> bool {
         match self {
             Syntax::Typescript(t) => !t.no_early_errors,
@@ -300,9 +321,23 @@ pub struct TsConfig {
     #[serde(skip, default)]
     pub no_early_errors: bool,
 
+    /// Recover from some errors which abort parsing otherwise, like an
+    /// unterminated string literal type. This is useful for editors, which
+    /// need an ast for incomplete code.
+    ///
+    /// Recovered errors are returned by `Parser::take_errors`.
+    #[serde(skip, default)]
+    pub error_recovery: bool,
+
     /// Stage 3.
     #[serde(default)]
     pub import_assertions: bool,
+
+    /// Parse `using` and `await using` declarations.
+    ///
+    /// Stage 3.
+    #[serde(default)]
+    pub explicit_resource_management: bool,
 }
 
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
@@ -371,6 +406,12 @@ pub struct EsConfig {
     /// Stage 3.
     #[serde(default)]
     pub import_assertions: bool,
+
+    /// Parse `using` and `await using` declarations.
+    ///
+    /// Stage 3.
+    #[serde(default)]
+    pub explicit_resource_management: bool,
 }
 
 /// Syntactic context.
@@ -412,6 +453,11 @@ pub struct Context {
 
     /// If true, `:` should not be treated as a type annotation.
     in_case_cond: bool,
+
+    /// If true, we are in the extends clause of a conditional type, where the
+    /// constraint of `infer T extends U` is parsed even if it's followed by
+    /// `?`.
+    in_cond_type_extends: bool,
 }
 
 #[cfg(test)]
@@ -353,6 +353,9 @@ macro_rules! tok {
     ("override") => {
         crate::token::Token::Word(crate::token::Word::Ident(swc_atoms::js_word!("override")))
     };
+    ("using") => {
+        crate::token::Token::Word(crate::token::Word::Ident(swc_atoms::js_word!("using")))
+    };
     ("undefined") => {
         crate::token::Token::Word(crate::token::Word::Ident(swc_atoms::js_word!("undefined")))
     };
@@ -80,6 +80,11 @@ impl<'a, I: Tokens> Parser<I> {
         T: OutputType,
         Self: MaybeOptionalIdentParser<T::Ident>,
     {
+        if self.input.syntax().typescript() {
+            // `strip` rewrites class members even if they are plain javascript.
+            self.ts_syntax.record(start);
+        }
+
         self.strict_mode().parse_with(|p| {
             expect!(p, "class");
 
@@ -294,12 +299,7 @@ impl<'a, I: Tokens> Parser<I> {
     pub(super) fn parse_access_modifier(&mut self) -> PResult<Option<Accessibility>> {
         Ok(self
             .parse_ts_modifier(&["public", "protected", "private"])?
-            .map(|s| match s {
-                "public" => Accessibility::Public,
-                "protected" => Accessibility::Protected,
-                "private" => Accessibility::Private,
-                _ => unreachable!(),
-            }))
+            .map(|s| s.parse().unwrap()))
     }
 
     fn parse_class_member(&mut self) -> PResult<ClassMember> {
@@ -1336,7 +1336,9 @@ impl<I: Tokens> FnBodyParser<BlockStmtOrExpr> for Parser<I> {
 impl<I: Tokens> FnBodyParser<Option<BlockStmt>> for Parser<I> {
     fn parse_fn_body_inner(&mut self) -> PResult<Option<BlockStmt>> {
         // allow omitting body and allow placing `{` on next line
+        let start = cur_pos!(self);
         if self.input.syntax().typescript() && !is!(self, '{') && eat!(self, ';') {
+            self.ts_syntax.record(start);
             return Ok(None);
         }
         self.include_in_expr(true).parse_block(true).map(Some)
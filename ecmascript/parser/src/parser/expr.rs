@@ -2,7 +2,7 @@ use super::{pat::PatType, util::ExprExt, *};
 use crate::{lexer::TokenContext, token::AssignOpToken};
 use either::Either;
 use swc_atoms::js_word;
-use swc_common::{ast_node, Spanned};
+use swc_common::{FromVariant, Spanned};
 
 mod ops;
 #[cfg(test)]
@@ -890,6 +890,7 @@ impl<'a, I: Tokens> Parser<I> {
         if self.input.syntax().typescript() {
             if !self.input.had_line_break_before_cur() && is!(self, '!') {
                 self.input.set_expr_allowed(false);
+                self.ts_syntax.record(cur_pos!(self));
                 assert_and_bump!(self, '!');
 
                 let expr = match obj {
@@ -1332,6 +1333,7 @@ impl<'a, I: Tokens> Parser<I> {
 
                 let mut pat = self.reparse_expr_as_pat(PatType::BindingPat, arg.expr)?;
                 if optional {
+                    self.ts_syntax.record(start);
                     match pat {
                         Pat::Ident(ref mut i) => i.id.optional = true,
                         _ => unreachable!(),
@@ -1466,11 +1468,9 @@ impl<'a, I: Tokens> Parser<I> {
     }
 }
 
-#[ast_node]
+#[derive(Debug, Clone, PartialEq, Spanned, FromVariant)]
 pub(in crate::parser) enum PatOrExprOrSpread {
-    #[tag("*")]
     Pat(Pat),
-    #[tag("*")]
     ExprOrSpread(ExprOrSpread),
 }
 
@@ -97,6 +97,7 @@ impl<'a, I: Tokens> Parser<I> {
         {
             let start = left.span().lo();
             let expr = left;
+            self.ts_syntax.record(cur_pos!(self));
             let node = if peeked_is!(self, "const") {
                 bump!(self); // as
                 let _ = cur!(self, false);
@@ -233,6 +234,7 @@ impl<'a, I: Tokens> Parser<I> {
 
         if !self.input.syntax().jsx() && self.input.syntax().typescript() && eat!(self, '<') {
             if eat!(self, "const") {
+                self.ts_syntax.record(start);
                 expect!(self, '>');
                 let expr = self.parse_unary_expr()?;
                 return Ok(Box::new(Expr::TsConstAssertion(TsConstAssertion {
@@ -324,7 +326,7 @@ impl<'a, I: Tokens> Parser<I> {
         }
 
         if (self.ctx().in_async || self.syntax().top_level_await()) && is!(self, "await") {
-            return self.parse_await_expr();
+            return self.parse_await_expr(None);
         }
 
         // UpdateExpression
@@ -356,10 +358,20 @@ impl<'a, I: Tokens> Parser<I> {
         Ok(expr)
     }
 
-    pub(crate) fn parse_await_expr(&mut self) -> PResult<Box<Expr>> {
-        let start = cur_pos!(self);
-
-        assert_and_bump!(self, "await");
+    /// `start_of_await` is the start of the `await` keyword if it is already
+    /// eaten.
+    pub(crate) fn parse_await_expr(
+        &mut self,
+        start_of_await: Option<BytePos>,
+    ) -> PResult<Box<Expr>> {
+        let start = match start_of_await {
+            Some(start) => start,
+            None => {
+                let start = cur_pos!(self);
+                assert_and_bump!(self, "await");
+                start
+            }
+        };
 
         if is!(self, '*') {
             syntax_error!(self, SyntaxError::AwaitStar);
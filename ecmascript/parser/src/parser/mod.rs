@@ -8,7 +8,10 @@ use crate::{
     token::{Token, Word},
     Context, JscTarget, Syntax,
 };
-use std::ops::{Deref, DerefMut};
+use std::{
+    mem::take,
+    ops::{Deref, DerefMut},
+};
 use swc_atoms::JsWord;
 use swc_common::{comments::Comments, input::Input, BytePos, Span};
 use swc_ecma_ast::*;
@@ -43,6 +46,7 @@ pub struct Parser<I: Tokens> {
     emit_err: bool,
     state: State,
     input: Buffer<I>,
+    ts_syntax: TsSyntaxPositions,
 }
 
 #[derive(Clone, Default)]
@@ -52,6 +56,35 @@ struct State {
     potential_arrow_start: Option<BytePos>,
 }
 
+/// Start positions of typescript-only syntax seen by a [Parser].
+///
+/// Obtained from [Parser::take_ts_syntax] after parsing. Positions recorded
+/// while backtracking are dropped with the backtracked parser.
+#[derive(Debug, Clone, Default)]
+pub struct TsSyntaxPositions {
+    /// Sorted once the positions are taken from the parser.
+    positions: Vec<BytePos>,
+}
+
+impl TsSyntaxPositions {
+    pub(crate) fn record(&mut self, pos: BytePos) {
+        self.positions.push(pos);
+    }
+
+    /// Returns true if any typescript-only syntax was parsed.
+    pub fn has_ts(&self) -> bool {
+        !self.positions.is_empty()
+    }
+
+    /// Returns true if typescript-only syntax starts within `span`.
+    pub fn contains_ts(&self, span: Span) -> bool {
+        match self.positions.binary_search(&span.lo) {
+            Ok(..) => true,
+            Err(idx) => self.positions.get(idx).map_or(false, |&pos| pos < span.hi),
+        }
+    }
+}
+
 impl<'a, I: Input> Parser<Lexer<'a, I>> {
     pub fn new(syntax: Syntax, input: I, comments: Option<&'a dyn Comments>) -> Self {
         Self::new_from(Lexer::new(syntax, Default::default(), input, comments))
@@ -64,9 +97,19 @@ impl<I: Tokens> Parser<I> {
             emit_err: true,
             state: Default::default(),
             input: Buffer::new(input),
+            ts_syntax: Default::default(),
         }
     }
 
+    /// Takes the positions of typescript-only syntax parsed by this parser,
+    /// which is used by `strip` to skip plain javascript.
+    pub fn take_ts_syntax(&mut self) -> TsSyntaxPositions {
+        let mut ts_syntax = take(&mut self.ts_syntax);
+        ts_syntax.positions.sort_unstable();
+        ts_syntax.positions.dedup();
+        ts_syntax
+    }
+
     pub fn take_errors(&mut self) -> Vec<Error> {
         self.input().take_errors()
     }
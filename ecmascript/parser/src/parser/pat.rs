@@ -177,6 +177,7 @@ impl<'a, I: Tokens> Parser<I> {
                     }) => {
                         *optional = true;
                         opt = true;
+                        self.ts_syntax.record(self.input.prev_span().lo());
                     }
                     _ if self.input.syntax().dts() || self.ctx().in_declare => {}
                     _ => {
@@ -315,16 +316,21 @@ impl<'a, I: Tokens> Parser<I> {
         param_start: BytePos,
         decorators: Vec<Decorator>,
     ) -> PResult<ParamOrTsParamProp> {
-        let (accessibility, readonly) = if self.input.syntax().typescript() {
+        let (accessibility, is_override, readonly) = if self.input.syntax().typescript() {
             let accessibility = self.parse_access_modifier()?;
+            let is_override = self.parse_ts_modifier(&["override"])?.is_some();
+            if is_override && !self.ctx().has_super_class {
+                self.emit_err(self.input.prev_span(), SyntaxError::TS4112);
+            }
             (
                 accessibility,
+                is_override,
                 self.parse_ts_modifier(&["readonly"])?.is_some(),
             )
         } else {
-            (None, false)
+            (None, false, false)
         };
-        if accessibility == None && !readonly {
+        if accessibility == None && !is_override && !readonly {
             let pat = self.parse_formal_param_pat()?;
             Ok(ParamOrTsParamProp::Param(Param {
                 span: span!(self, param_start),
@@ -341,6 +347,7 @@ impl<'a, I: Tokens> Parser<I> {
                 span: span!(self, param_start),
                 accessibility,
                 readonly,
+                is_override,
                 decorators,
                 param,
             }))
@@ -102,8 +102,45 @@ impl<'a, I: Tokens> Parser<I> {
             if !valid {
                 self.emit_err(self.input.cur_span(), SyntaxError::TopLevelAwait);
             }
+        }
+
+        if include_decl && self.syntax().explicit_resource_management() {
+            if self.is_using_decl() {
+                let v = self.parse_var_stmt(false)?;
+                return Ok(Stmt::Decl(Decl::Var(v)));
+            }
+
+            if is!(self, "await")
+                && peeked_is!(self, "using")
+                && !self.input.has_linebreak_between_cur_and_peeked()
+            {
+                let await_start = cur_pos!(self);
+                assert_and_bump!(self, "await");
+
+                if self.is_using_decl() {
+                    assert_and_bump!(self, "using");
+                    let v = self.parse_var_decls(
+                        await_start,
+                        VarDeclKind::Using { is_await: true },
+                        false,
+                    )?;
+                    return Ok(Stmt::Decl(Decl::Var(v)));
+                }
 
-            let expr = self.parse_await_expr()?;
+                // `await using;`, `await using.dispose()`, ...
+                let expr = self.parse_await_expr(Some(await_start))?;
+                let expr = self
+                    .include_in_expr(true)
+                    .parse_bin_op_recursively(expr, 0)?;
+                eat!(self, ';');
+
+                let span = span!(self, start);
+                return Ok(Stmt::Expr(ExprStmt { span, expr }));
+            }
+        }
+
+        if top_level && is!(self, "await") {
+            let expr = self.parse_await_expr(None)?;
             eat!(self, ';');
 
             let span = span!(self, start);
@@ -625,14 +662,52 @@ impl<'a, I: Tokens> Parser<I> {
         }
     }
 
+    /// Returns `true` if the current token is a `using` which starts a
+    /// declaration, i.e. it is followed by a binding identifier on the same
+    /// line.
+    fn is_using_decl(&mut self) -> bool {
+        is!(self, "using")
+            && peeked_is!(self, BindingIdent)
+            && !self.input.has_linebreak_between_cur_and_peeked()
+    }
+
+    /// Returns `true` if the current token is an `await` which starts an
+    /// `await using` declaration.
+    fn is_await_using_decl(&mut self) -> bool {
+        if !is!(self, "await")
+            || !peeked_is!(self, "using")
+            || self.input.has_linebreak_between_cur_and_peeked()
+        {
+            return false;
+        }
+
+        let mut cloned = self.clone();
+        cloned.emit_err = false;
+        bump!(cloned);
+        cloned.is_using_decl()
+    }
+
     pub(super) fn parse_var_stmt(&mut self, for_loop: bool) -> PResult<VarDecl> {
         let start = cur_pos!(self);
         let kind = match bump!(self) {
             tok!("const") => VarDeclKind::Const,
             tok!("let") => VarDeclKind::Let,
             tok!("var") => VarDeclKind::Var,
+            tok!("using") => VarDeclKind::Using { is_await: false },
             _ => unreachable!(),
         };
+
+        self.parse_var_decls(start, kind, for_loop)
+    }
+
+    /// Parses the declarators of a variable declaration whose keywords are
+    /// already eaten.
+    fn parse_var_decls(
+        &mut self,
+        start: BytePos,
+        kind: VarDeclKind,
+        for_loop: bool,
+    ) -> PResult<VarDecl> {
         let var_span = span!(self, start);
         let should_include_in = kind != VarDeclKind::Var || !for_loop;
 
@@ -732,7 +807,11 @@ impl<'a, I: Tokens> Parser<I> {
 
         let definite = if self.input.syntax().typescript() {
             match name {
-                Pat::Ident(..) => eat!(self, '!'),
+                Pat::Ident(..) if is!(self, '!') => {
+                    self.ts_syntax.record(cur_pos!(self));
+                    bump!(self);
+                    true
+                }
                 _ => false,
             }
         } else {
@@ -993,14 +1072,29 @@ impl<'a, I: Tokens> Parser<I> {
         let start = cur_pos!(self);
         let strict = self.ctx().strict;
 
-        if is_one_of!(self, "const", "var")
+        let allow_using = self.syntax().explicit_resource_management();
+        let decl = if is_one_of!(self, "const", "var")
             || (is!(self, "let") && peek!(self)?.follows_keyword_let(strict))
+            // `for (using of items)` iterates over `using`.
+            || (allow_using && self.is_using_decl() && !peeked_is!(self, "of"))
         {
-            let decl = self.parse_var_stmt(true)?;
+            Some(self.parse_var_stmt(true)?)
+        } else if allow_using && self.is_await_using_decl() {
+            assert_and_bump!(self, "await");
+            assert_and_bump!(self, "using");
+            Some(self.parse_var_decls(start, VarDeclKind::Using { is_await: true }, true)?)
+        } else {
+            None
+        };
 
+        if let Some(decl) = decl {
             if is_one_of!(self, "of", "in") {
                 let is_in = is!(self, "in");
 
+                if is_in && decl.kind.is_using() {
+                    self.emit_err(decl.span, SyntaxError::UsingDeclInForIn);
+                }
+
                 if decl.decls.len() != 1 {
                     for d in decl.decls.iter().skip(1) {
                         self.emit_err(d.name.span(), SyntaxError::TooManyVarInForInHead);
@@ -1790,4 +1884,85 @@ export default function waitUntil(callback, options = {}) {
             _ => assert!(false),
         }
     }
+
+    fn var_decl_kind(s: &'static str, explicit_resource_management: bool) -> Option<VarDeclKind> {
+        let syntax = Syntax::Es(EsConfig {
+            top_level_await: true,
+            explicit_resource_management,
+            ..Default::default()
+        });
+
+        match test_parser(s, syntax, |p| p.parse_stmt_list_item(true)) {
+            Stmt::Decl(Decl::Var(v)) => Some(v.kind),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn using_decl() {
+        assert_eq!(
+            var_decl_kind("using x = a;", true),
+            Some(VarDeclKind::Using { is_await: false })
+        );
+        assert_eq!(
+            var_decl_kind("await using x = a;", true),
+            Some(VarDeclKind::Using { is_await: true })
+        );
+
+        assert_eq!(var_decl_kind("using\nx = a;", true), None);
+        assert_eq!(var_decl_kind("using = a;", true), None);
+        assert_eq!(var_decl_kind("using[0] = a;", true), None);
+        assert_eq!(var_decl_kind("await using;", true), None);
+        assert_eq!(var_decl_kind("await using.dispose();", true), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to parse")]
+    fn using_decl_without_syntax_flag() {
+        var_decl_kind("using x = a;", false);
+    }
+
+    fn for_head_var_decl_kind(s: &'static str) -> Option<VarDeclKind> {
+        let syntax = Syntax::Es(EsConfig {
+            explicit_resource_management: true,
+            ..Default::default()
+        });
+
+        match test_parser(s, syntax, |p| p.parse_for_head()) {
+            ForHead::For {
+                init: Some(VarDeclOrExpr::VarDecl(v)),
+                ..
+            }
+            | ForHead::ForOf {
+                left: VarDeclOrPat::VarDecl(v),
+                ..
+            } => Some(v.kind),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn using_decl_in_for_head() {
+        assert_eq!(
+            for_head_var_decl_kind("using x of items"),
+            Some(VarDeclKind::Using { is_await: false })
+        );
+        assert_eq!(
+            for_head_var_decl_kind("await using x of items"),
+            Some(VarDeclKind::Using { is_await: true })
+        );
+        assert_eq!(
+            for_head_var_decl_kind("using x = a; x.ok; x.next()"),
+            Some(VarDeclKind::Using { is_await: false })
+        );
+
+        assert_eq!(for_head_var_decl_kind("using of items"), None);
+        assert_eq!(for_head_var_decl_kind("using.x of items"), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to parse")]
+    fn using_decl_in_for_in_head() {
+        for_head_var_decl_kind("using x in items");
+    }
 }
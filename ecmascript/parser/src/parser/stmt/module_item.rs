@@ -69,7 +69,7 @@ impl<'a, I: Tokens> Parser<I> {
                 span: span!(self, start),
                 src,
                 specifiers: vec![],
-                type_only: false,
+                import_kind: ImportKind::Value,
                 asserts: None,
             }))
             .map(ModuleItem::from);
@@ -79,9 +79,12 @@ impl<'a, I: Tokens> Parser<I> {
             && is!(self, "type")
             && (peeked_is!(self, '{') || !peeked_is!(self, "from") && !peeked_is!(self, ','));
 
-        if type_only {
+        let import_kind = if type_only {
             assert_and_bump!(self, "type");
-        }
+            ImportKind::Type
+        } else {
+            ImportKind::Value
+        };
 
         let mut specifiers = vec![];
 
@@ -160,7 +163,7 @@ impl<'a, I: Tokens> Parser<I> {
             span: span!(self, start),
             specifiers,
             src,
-            type_only,
+            import_kind,
             asserts,
         }))
         .map(ModuleItem::from)
@@ -173,12 +176,38 @@ impl<'a, I: Tokens> Parser<I> {
             Ok(&Word(..)) => {
                 let orig_name = self.parse_ident_name()?;
 
+                if self.input.syntax().typescript()
+                    && orig_name.sym == js_word!("type")
+                    && is!(self, IdentName)
+                {
+                    let (is_type_only, orig_name, alias) =
+                        self.parse_ts_type_only_specifier(orig_name)?;
+                    let (local, imported) = match alias {
+                        Some(alias) => (alias, Some(orig_name)),
+                        None => (orig_name, None),
+                    };
+                    if self.ctx().is_reserved_word(&local.sym) {
+                        syntax_error!(self, local.span, SyntaxError::ReservedWordInImport)
+                    }
+                    return Ok(ImportSpecifier::Named(ImportNamedSpecifier {
+                        span: span!(self, start),
+                        local,
+                        imported,
+                        import_kind: if is_type_only {
+                            ImportKind::Type
+                        } else {
+                            ImportKind::Value
+                        },
+                    }));
+                }
+
                 if eat!(self, "as") {
                     let local = self.parse_binding_ident()?.id;
                     return Ok(ImportSpecifier::Named(ImportNamedSpecifier {
                         span: Span::new(start, local.span.hi(), Default::default()),
                         local,
                         imported: Some(orig_name),
+                        import_kind: ImportKind::Value,
                     }));
                 }
 
@@ -195,12 +224,60 @@ impl<'a, I: Tokens> Parser<I> {
                     span: span!(self, start),
                     local,
                     imported: None,
+                    import_kind: ImportKind::Value,
                 }))
             }
             _ => unexpected!(self, "an identifier"),
         }
     }
 
+    /// Parses the rest of a typescript specifier which starts with `type`,
+    /// like `type foo` or `type foo as bar`.
+    ///
+    /// `type` is a modifier unless it's the name being imported or exported,
+    /// as in `type as foo` or `type as as`.
+    ///
+    /// Returns `(is_type_only, orig, alias)`.
+    fn parse_ts_type_only_specifier(
+        &mut self,
+        type_ident: Ident,
+    ) -> PResult<(bool, Ident, Option<Ident>)> {
+        debug_assert!(self.input.syntax().typescript());
+
+        if !is!(self, "as") {
+            // `type foo`, `type foo as bar`
+            let orig = self.parse_ident_name()?;
+            let alias = if eat!(self, "as") {
+                Some(self.parse_ident_name()?)
+            } else {
+                None
+            };
+            return Ok((true, orig, alias));
+        }
+
+        let as_ident = self.parse_ident_name()?;
+        if is!(self, "as") {
+            let second_as = self.parse_ident_name()?;
+            if is!(self, IdentName) {
+                // `type as as foo`
+                let alias = self.parse_ident_name()?;
+                return Ok((true, as_ident, Some(alias)));
+            }
+
+            // `type as as`
+            return Ok((false, type_ident, Some(second_as)));
+        }
+
+        if is!(self, IdentName) {
+            // `type as foo`
+            let alias = self.parse_ident_name()?;
+            return Ok((false, type_ident, Some(alias)));
+        }
+
+        // `type as`
+        Ok((true, as_ident, None))
+    }
+
     fn parse_imported_default_binding(&mut self) -> PResult<Ident> {
         self.parse_imported_binding()
     }
@@ -296,6 +373,11 @@ impl<'a, I: Tokens> Parser<I> {
         let ns_export_specifier_start = cur_pos!(self);
 
         let type_only = self.input.syntax().typescript() && eat!(self, "type");
+        let export_kind = if type_only {
+            ExportKind::Type
+        } else {
+            ExportKind::Value
+        };
 
         if eat!(self, '*') {
             has_star = true;
@@ -439,7 +521,7 @@ impl<'a, I: Tokens> Parser<I> {
                         span: Span::new(start, src.span.hi(), Default::default()),
                         specifiers: vec![s],
                         src: Some(src),
-                        type_only,
+                        export_kind,
                         asserts,
                     }));
                 }
@@ -465,7 +547,7 @@ impl<'a, I: Tokens> Parser<I> {
                             exported: default,
                         })],
                         src: Some(src),
-                        type_only,
+                        export_kind,
                         asserts,
                     }));
                 }
@@ -533,7 +615,7 @@ impl<'a, I: Tokens> Parser<I> {
                 span: span!(self, start),
                 specifiers,
                 src,
-                type_only,
+                export_kind,
                 asserts,
             }));
         };
@@ -549,6 +631,21 @@ impl<'a, I: Tokens> Parser<I> {
 
         let orig = self.parse_ident_name()?;
 
+        if self.input.syntax().typescript() && orig.sym == js_word!("type") && is!(self, IdentName)
+        {
+            let (is_type_only, orig, exported) = self.parse_ts_type_only_specifier(orig)?;
+            return Ok(ExportNamedSpecifier {
+                span: span!(self, start),
+                orig,
+                exported,
+                export_kind: if is_type_only {
+                    ExportKind::Type
+                } else {
+                    ExportKind::Value
+                },
+            });
+        }
+
         let exported = if eat!(self, "as") {
             Some(self.parse_ident_name()?)
         } else {
@@ -559,6 +656,7 @@ impl<'a, I: Tokens> Parser<I> {
             span: span!(self, start),
             orig,
             exported,
+            export_kind: ExportKind::Value,
         })
     }
 
@@ -635,7 +733,8 @@ impl<'a, I: Tokens> StmtLikeParser<'a, ModuleItem> for Parser<I> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{EsConfig, Syntax};
+    use crate::{EsConfig, Syntax, TsConfig};
+    use swc_ecma_ast::*;
 
     #[test]
     fn test_legacy_decorator() {
@@ -654,4 +753,54 @@ export default class Foo {
             |p| p.parse_module(),
         );
     }
+
+    #[test]
+    fn type_only_import_specifier() {
+        let module = crate::test_parser(
+            "import { type A, B } from \"m\";",
+            Syntax::Typescript(TsConfig::default()),
+            |p| p.parse_module(),
+        );
+
+        let import = match &module.body[0] {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => import,
+            _ => unreachable!(),
+        };
+        assert_eq!(import.import_kind, ImportKind::Value);
+
+        let specifiers: Vec<_> = import
+            .specifiers
+            .iter()
+            .map(|s| match s {
+                ImportSpecifier::Named(s) => (&*s.local.sym, s.import_kind.is_type()),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(specifiers, vec![("A", true), ("B", false)]);
+    }
+
+    #[test]
+    fn type_only_export_specifier() {
+        let module = crate::test_parser(
+            "export { type A, B } from \"m\";",
+            Syntax::Typescript(TsConfig::default()),
+            |p| p.parse_module(),
+        );
+
+        let export = match &module.body[0] {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) => export,
+            _ => unreachable!(),
+        };
+        assert_eq!(export.export_kind, ExportKind::Value);
+
+        let specifiers: Vec<_> = export
+            .specifiers
+            .iter()
+            .map(|s| match s {
+                ExportSpecifier::Named(s) => (&*s.orig.sym, s.export_kind.is_type()),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(specifiers, vec![("A", true), ("B", false)]);
+    }
 }
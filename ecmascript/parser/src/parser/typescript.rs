@@ -1,6 +1,7 @@
 use super::*;
 use crate::lexer::TokenContexts;
 use either::Either;
+use std::convert::TryFrom;
 use swc_atoms::js_word;
 use swc_common::{Spanned, SyntaxContext};
 
@@ -44,7 +45,9 @@ impl<I: Tokens> Parser<I> {
         };
 
         if let Some(pos) = pos {
+            let start = cur_pos!(self);
             if self.try_parse_ts_bool(|p| p.ts_next_token_can_follow_modifier().map(Some))? {
+                self.ts_syntax.record(start);
                 return Ok(Some(allowed_modifiers[pos]));
             }
         }
@@ -363,6 +366,44 @@ impl<I: Tokens> Parser<I> {
 
         let start = cur_pos!(self);
 
+        let mut is_in = false;
+        let mut is_out = false;
+        let mut is_const = false;
+        while let Ok(&Token::Word(ref w)) = cur!(self, false) {
+            let modifier: JsWord = w.clone().into();
+            // A modifier is always followed by the name of the type parameter,
+            // so `out` is the name in `<out extends T>` and `<out = T>`.
+            if !matches!(&*modifier, "in" | "out" | "const")
+                || !peeked_is!(self, IdentName)
+                || peeked_is!(self, "extends")
+            {
+                break;
+            }
+            bump!(self);
+
+            let span = self.input.prev_span();
+            let seen = match &*modifier {
+                "in" => {
+                    if is_out {
+                        self.emit_err(span, SyntaxError::TS1029(js_word!("in"), "out".into()));
+                    }
+                    &mut is_in
+                }
+                "out" => &mut is_out,
+                _ => {
+                    if is_in || is_out {
+                        let variance = if is_in { js_word!("in") } else { "out".into() };
+                        self.emit_err(span, SyntaxError::TS1029(js_word!("const"), variance));
+                    }
+                    &mut is_const
+                }
+            };
+            if *seen {
+                self.emit_err(span, SyntaxError::TS1030(modifier));
+            }
+            *seen = true;
+        }
+
         let name = self.parse_ident_name()?;
         let constraint = self.eat_then_parse_ts_type(&tok!("extends"))?;
         let default = self.eat_then_parse_ts_type(&tok!('='))?;
@@ -370,6 +411,9 @@ impl<I: Tokens> Parser<I> {
         Ok(TsTypeParam {
             span: span!(self, start),
             name,
+            is_in,
+            is_out,
+            is_const,
             constraint,
             default,
         })
@@ -380,6 +424,7 @@ impl<I: Tokens> Parser<I> {
         self.in_type().parse_with(|p| {
             p.ts_in_no_context(|p| {
                 let start = cur_pos!(p);
+                p.ts_syntax.record(start);
 
                 if !is!(p, '<') && !is!(p, JSXTagStart) {
                     unexpected!(p, "< (jsx tag start)")
@@ -411,6 +456,7 @@ impl<I: Tokens> Parser<I> {
 
         self.in_type().parse_with(|p| {
             let return_token_start = cur_pos!(p);
+            p.ts_syntax.record(return_token_start);
             if !p.input.eat(return_token) {
                 let cur = format!("{:?}", cur!(p, false).ok());
                 let span = p.input.cur_span();
@@ -661,6 +707,7 @@ impl<I: Tokens> Parser<I> {
         is_const: bool,
     ) -> PResult<TsEnumDecl> {
         debug_assert!(self.input.syntax().typescript());
+        self.ts_syntax.record(start);
 
         let id = self.parse_ident_name()?;
         expect!(self, '{');
@@ -785,30 +832,43 @@ impl<I: Tokens> Parser<I> {
         debug_assert!(self.ctx().in_type);
 
         let start = cur_pos!(self);
+        self.ts_syntax.record(start);
 
-        let ty = self.parse_ts_non_conditional_type()?;
-        if self.input.had_line_break_before_cur() || !eat!(self, "extends") {
-            return Ok(ty);
-        }
+        let ctx = Context {
+            in_cond_type_extends: false,
+            ..self.ctx()
+        };
+        self.with_ctx(ctx).parse_with(|p| {
+            let ty = p.parse_ts_non_conditional_type()?;
+            if p.input.had_line_break_before_cur() || !eat!(p, "extends") {
+                return Ok(ty);
+            }
 
-        let check_type = ty;
-        let extends_type = self.parse_ts_non_conditional_type()?;
+            let check_type = ty;
+            let extends_type = {
+                let ctx = Context {
+                    in_cond_type_extends: true,
+                    ..p.ctx()
+                };
+                p.with_ctx(ctx).parse_ts_non_conditional_type()?
+            };
 
-        expect!(self, '?');
+            expect!(p, '?');
 
-        let true_type = self.parse_ts_type()?;
+            let true_type = p.parse_ts_type()?;
 
-        expect!(self, ':');
+            expect!(p, ':');
 
-        let false_type = self.parse_ts_type()?;
+            let false_type = p.parse_ts_type()?;
 
-        Ok(Box::new(TsType::TsConditionalType(TsConditionalType {
-            span: span!(self, start),
-            check_type,
-            extends_type,
-            true_type,
-            false_type,
-        })))
+            Ok(Box::new(TsType::TsConditionalType(TsConditionalType {
+                span: span!(p, start),
+                check_type,
+                extends_type,
+                true_type,
+                false_type,
+            })))
+        })
     }
 
     /// `tsParseNonConditionalType`
@@ -894,6 +954,7 @@ impl<I: Tokens> Parser<I> {
     /// `tsParseInterfaceDeclaration`
     pub(super) fn parse_ts_interface_decl(&mut self, start: BytePos) -> PResult<TsInterfaceDecl> {
         debug_assert!(self.input.syntax().typescript());
+        self.ts_syntax.record(start);
 
         let id = self.parse_ident_name()?;
         match id.sym {
@@ -954,6 +1015,7 @@ impl<I: Tokens> Parser<I> {
     /// `tsParseTypeAliasDeclaration`
     fn parse_ts_type_alias_decl(&mut self, start: BytePos) -> PResult<TsTypeAliasDecl> {
         debug_assert!(self.input.syntax().typescript());
+        self.ts_syntax.record(start);
 
         let id = self.parse_ident_name()?;
         let type_params = self.try_parse_ts_type_params()?;
@@ -975,6 +1037,7 @@ impl<I: Tokens> Parser<I> {
         is_export: bool,
     ) -> PResult<TsImportEqualsDecl> {
         debug_assert!(self.input.syntax().typescript());
+        self.ts_syntax.record(start);
 
         let id = self.parse_ident_name()?;
         expect!(self, '=');
@@ -1471,6 +1534,9 @@ impl<I: Tokens> Parser<I> {
         Ok(TsTypeParam {
             span: span!(self, start),
             name,
+            is_in: false,
+            is_out: false,
+            is_const: false,
             constraint,
             default: None,
         })
@@ -1546,13 +1612,18 @@ impl<I: Tokens> Parser<I> {
 
         // Validate the elementTypes to ensure:
         //   No mandatory elements may follow optional elements
-        //   If there's a rest element, it must be at the end of the tuple
+        //   There's at most one rest element, which may appear anywhere
 
         let mut seen_optional_element = false;
-        let len = elem_types.len();
-        for (i, elem_type) in elem_types.iter().enumerate() {
+        let mut seen_rest_element = false;
+        for elem_type in elem_types.iter() {
             match elem_type.ty {
-                TsType::TsRestType(..) => {}
+                TsType::TsRestType(..) => {
+                    if seen_rest_element {
+                        syntax_error!(self, elem_type.span, SyntaxError::TsRestAfterRest)
+                    }
+                    seen_rest_element = true;
+                }
                 TsType::TsOptionalType(..) => {
                     seen_optional_element = true;
                 }
@@ -1653,6 +1724,14 @@ impl<I: Tokens> Parser<I> {
         expect!(self, '(');
         let type_ann = self.parse_ts_type()?;
         expect!(self, ')');
+
+        // Redundant parens like `((A))` are collapsed into a single
+        // `TsParenthesizedType`.
+        let type_ann = match *type_ann {
+            TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. }) => type_ann,
+            type_ann => Box::new(type_ann),
+        };
+
         Ok(TsParenthesizedType {
             span: span!(self, start),
             type_ann,
@@ -1780,12 +1859,9 @@ impl<I: Tokens> Parser<I> {
         let mut list = vec![];
 
         for param in params {
-            let item = match param.pat {
-                Pat::Ident(pat) => TsFnParam::Ident(pat),
-                Pat::Array(pat) => TsFnParam::Array(pat),
-                Pat::Object(pat) => TsFnParam::Object(pat),
-                Pat::Rest(pat) => TsFnParam::Rest(pat),
-                _ => unexpected!(
+            let item = match TsFnParam::try_from(param.pat) {
+                Ok(item) => item,
+                Err(..) => unexpected!(
                     self,
                     "an identifier, [ for an array pattern, { for an object patter or ... for a \
                      rest pattern"
@@ -1916,7 +1992,7 @@ impl<I: Tokens> Parser<I> {
                 let start = cur_pos!(self);
                 bump!(self);
                 if match *cur!(self, true)? {
-                    Token::Num(..) => false,
+                    Token::Num(..) | Token::BigInt(..) => false,
                     _ => true,
                 } {
                     unexpected!(self, "a numeric literal")
@@ -1927,6 +2003,10 @@ impl<I: Tokens> Parser<I> {
                         span: num.span,
                         value: -num.value,
                     }),
+                    Lit::BigInt(n) => TsLit::BigInt(BigInt {
+                        span: n.span,
+                        value: -n.value,
+                    }),
                     _ => unreachable!(),
                 };
 
@@ -2037,11 +2117,16 @@ impl<I: Tokens> Parser<I> {
 
         let start = cur_pos!(self);
         expect!(self, "infer");
+        let type_param_start = cur_pos!(self);
         let type_param_name = self.parse_ident_name()?;
+        let constraint = self.try_parse_ts(|p| p.parse_ts_infer_constraint());
         let type_param = TsTypeParam {
-            span: type_param_name.span(),
+            span: span!(self, type_param_start),
             name: type_param_name,
-            constraint: None,
+            is_in: false,
+            is_out: false,
+            is_const: false,
+            constraint,
             default: None,
         };
         Ok(TsInferType {
@@ -2050,6 +2135,31 @@ impl<I: Tokens> Parser<I> {
         })
     }
 
+    /// `tsParseConstraintForInferType`
+    ///
+    /// Outside of the extends clause of a conditional type, `infer T extends U
+    /// ? X : Y` is a conditional type checking `infer T`, so the constraint is
+    /// not parsed if it's followed by `?`.
+    fn parse_ts_infer_constraint(&mut self) -> PResult<Option<Box<TsType>>> {
+        debug_assert!(self.input.syntax().typescript());
+
+        if !eat!(self, "extends") {
+            return Ok(None);
+        }
+
+        let in_cond_type_extends = self.ctx().in_cond_type_extends;
+        let ctx = Context {
+            in_cond_type_extends: true,
+            ..self.ctx()
+        };
+        let constraint = self.with_ctx(ctx).parse_ts_non_conditional_type()?;
+        if in_cond_type_extends || !is!(self, '?') {
+            Ok(Some(constraint))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// `tsParseTypeOperatorOrHigher`
     fn parse_ts_type_operator_or_higher(&mut self) -> PResult<Box<TsType>> {
         trace_cur!(self, parse_ts_type_operator_or_higher);
@@ -2091,7 +2201,7 @@ impl<I: Tokens> Parser<I> {
     ) -> PResult<Option<Decl>> {
         let start = expr.span().lo();
 
-        match &*expr.sym {
+        let decl = match &*expr.sym {
             "declare" => {
                 let decl = self.try_parse_ts_declare(start, decorators)?;
                 if let Some(mut decl) = decl {
@@ -2150,7 +2260,13 @@ impl<I: Tokens> Parser<I> {
                 }
             }
             _ => self.parse_ts_decl(start, decorators, expr.sym, /* next */ false),
+        }?;
+
+        if decl.is_some() {
+            self.ts_syntax.record(start);
         }
+
+        Ok(decl)
     }
 
     /// `tsTryParseDeclare`
@@ -2456,6 +2572,7 @@ impl<I: Tokens> Parser<I> {
         debug_assert!(self.input.syntax().typescript());
 
         let start = cur_pos!(self);
+        self.ts_syntax.record(start);
         let params = self.in_type().parse_with(|p| {
             // Temporarily remove a JSX parsing context, which makes us scan different
             // tokens.
@@ -2609,9 +2726,11 @@ fn make_decl_declare(mut decl: Decl) -> Decl {
 #[cfg(test)]
 mod tests {
     use crate::{
-        lexer::Lexer, test_parser, token::*, Capturing, JscTarget, Parser, Syntax, TsConfig,
+        error::SyntaxError, lexer::Lexer, test_parser, test_parser_comment, token::*, Capturing,
+        JscTarget, Parser, Syntax, TsConfig,
     };
-    use swc_common::DUMMY_SP;
+    use swc_atoms::JsWord;
+    use swc_common::{comments::SingleThreadedComments, EqIgnoreSpan, Spanned, DUMMY_SP};
     use swc_ecma_ast::*;
     use swc_ecma_visit::assert_eq_ignore_span;
 
@@ -2742,4 +2861,698 @@ mod tests {
         })
         .unwrap();
     }
+
+    #[test]
+    fn infer_type_params_multiple() {
+        let module = test_parser(
+            "type X<T> = T extends [infer A, infer B] ? [A, B] : never;",
+            Syntax::Typescript(Default::default()),
+            |p| p.parse_module(),
+        );
+
+        let alias = match &module.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias))) => alias,
+            item => unreachable!("{:?}", item),
+        };
+        let cond = match &*alias.type_ann {
+            TsType::TsConditionalType(cond) => cond,
+            ty => unreachable!("{:?}", ty),
+        };
+        let names = cond
+            .infer_type_params()
+            .into_iter()
+            .map(|p| &*p.name.sym)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["A", "B"]);
+    }
+
+    #[test]
+    fn infer_type_param_constraint() {
+        fn constraints(src: &'static str) -> Vec<Option<TsType>> {
+            let module = test_parser(src, Syntax::Typescript(Default::default()), |p| {
+                p.parse_module()
+            });
+
+            let alias = match &module.body[0] {
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias))) => alias,
+                item => unreachable!("{:?}", item),
+            };
+            let cond = match &*alias.type_ann {
+                TsType::TsConditionalType(cond) => cond,
+                ty => unreachable!("{:?}", ty),
+            };
+            cond.infer_type_params()
+                .into_iter()
+                .map(|p| p.constraint.as_ref().map(|ty| (**ty).clone()))
+                .collect()
+        }
+
+        let string = TsType::TsKeywordType(TsKeywordType {
+            span: DUMMY_SP,
+            kind: TsKeywordTypeKind::TsStringKeyword,
+        });
+        let is_string = |ty: &Option<TsType>| match ty {
+            Some(ty) => ty.eq_ignore_span(&string),
+            None => false,
+        };
+
+        let tuple =
+            constraints("type First<T> = T extends [infer U extends string, ...any] ? U : never;");
+        assert_eq!(tuple.len(), 1);
+        assert!(is_string(&tuple[0]));
+
+        let direct = constraints("type X<T> = T extends infer U extends string ? U : never;");
+        assert_eq!(direct.len(), 1);
+        assert!(is_string(&direct[0]));
+
+        let nested_cond =
+            constraints("type X<T> = T extends [infer U extends string ? 1 : 2] ? U : never;");
+        assert_eq!(nested_cond, vec![None]);
+
+        let unconstrained = constraints("type X<T> = T extends [infer U] ? U : never;");
+        assert_eq!(unconstrained, vec![None]);
+    }
+
+    #[test]
+    fn narrowing_keys() {
+        fn keys(src: &'static str) -> Vec<(JsWord, TsLit)> {
+            let module = test_parser(src, Syntax::Typescript(Default::default()), |p| {
+                p.parse_module()
+            });
+
+            let alias = match &module.body[0] {
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias))) => alias,
+                item => unreachable!("{:?}", item),
+            };
+            match &*alias.type_ann {
+                TsType::TsConditionalType(cond) => cond.narrowing_keys(),
+                ty => unreachable!("{:?}", ty),
+            }
+        }
+
+        let found = keys(r#"type X<T> = T extends { kind: "a" } ? 1 : 2;"#);
+        assert_eq!(found.len(), 1);
+        assert_eq!(&*found[0].0, "kind");
+        match &found[0].1 {
+            TsLit::Str(s) => assert_eq!(&*s.value, "a"),
+            lit => unreachable!("{:?}", lit),
+        }
+
+        assert_eq!(keys("type X<T> = T extends string ? 1 : 2;"), vec![]);
+    }
+
+    #[test]
+    fn referenced_type_names() {
+        let module = test_parser(
+            "type X = A | B<C.D> | (E extends F ? G[H] : { [K in keyof I]: J }) | typeof k;",
+            Syntax::Typescript(Default::default()),
+            |p| p.parse_module(),
+        );
+
+        let alias = match &module.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias))) => alias,
+            item => unreachable!("{:?}", item),
+        };
+        let names = alias
+            .type_ann
+            .referenced_type_names()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["A", "B", "C.D", "E", "F", "G", "H", "I", "J", "k"]
+        );
+    }
+
+    #[test]
+    fn type_query_to_expr() {
+        let module = test_parser(
+            "type X = typeof a.b.c;",
+            Syntax::Typescript(Default::default()),
+            |p| p.parse_module(),
+        );
+
+        let alias = match &module.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias))) => alias,
+            item => unreachable!("{:?}", item),
+        };
+        let expr = match &*alias.type_ann {
+            TsType::TsTypeQuery(query) => query.expr_name.to_expr().unwrap(),
+            ty => unreachable!("{:?}", ty),
+        };
+        let expected = test_parser("a.b.c", Syntax::Typescript(Default::default()), |p| {
+            p.parse_expr()
+        });
+        assert_eq_ignore_span!(expr, *expected);
+    }
+
+    #[test]
+    fn keyword_kind() {
+        fn ty(src: &'static str) -> TsType {
+            let module = test_parser(src, Syntax::Typescript(Default::default()), |p| {
+                p.parse_module()
+            });
+
+            match module.body.into_iter().next() {
+                Some(ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias)))) => *alias.type_ann,
+                item => unreachable!("{:?}", item),
+            }
+        }
+
+        assert_eq!(
+            ty("type X = string;").keyword_kind(),
+            Some(TsKeywordTypeKind::TsStringKeyword)
+        );
+        assert_eq!(ty("type X = Foo;").keyword_kind(), None);
+
+        assert!(ty("type X = any;").is_any());
+        assert!(ty("type X = ((any));").is_any());
+        assert!(ty("type X = never;").is_never());
+        assert!(ty("type X = unknown;").is_unknown());
+        assert!(ty("type X = void;").is_void());
+        assert!(ty("type X = null;").is_null());
+        assert!(ty("type X = undefined;").is_undefined());
+        assert!(!ty("type X = any[];").is_any());
+    }
+
+    #[test]
+    fn type_eq_ignore_span() {
+        fn types(src: &'static str) -> Vec<TsType> {
+            let module = test_parser(src, Syntax::Typescript(Default::default()), |p| {
+                p.parse_module()
+            });
+
+            module
+                .body
+                .into_iter()
+                .map(|item| match item {
+                    ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias))) => *alias.type_ann,
+                    item => unreachable!("{:?}", item),
+                })
+                .collect()
+        }
+
+        let tys = types(
+            "type A = Foo<string>[] | { a?: number };
+            type B = Foo<string>[] | { a?: number };
+            type C = Foo<number>[] | { a?: number };",
+        );
+
+        assert_ne!(tys[0], tys[1]);
+        assert!(tys[0].eq_ignore_span(&tys[1]));
+        assert!(!tys[0].eq_ignore_span(&tys[2]));
+    }
+
+    #[test]
+    fn type_args_from_types() {
+        let module = test_parser(
+            "type A = Foo<string, number>;",
+            Syntax::Typescript(Default::default()),
+            |p| p.parse_module(),
+        );
+        let type_args = match module.body.into_iter().next() {
+            Some(ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias)))) => match *alias.type_ann {
+                TsType::TsTypeRef(TsTypeRef {
+                    type_params: Some(type_args),
+                    ..
+                }) => type_args,
+                ty => unreachable!("{:?}", ty),
+            },
+            item => unreachable!("{:?}", item),
+        };
+
+        assert_eq!(type_args.len(), 2);
+        assert_eq!(
+            type_args[1].keyword_kind(),
+            Some(TsKeywordTypeKind::TsNumberKeyword)
+        );
+        assert!(type_args.get(2).is_none());
+
+        let collected: TsTypeParamInstantiation = type_args.params.iter().cloned().collect();
+        assert_eq!(collected.span, DUMMY_SP);
+        assert!(collected.eq_ignore_span(&type_args));
+    }
+
+    #[test]
+    fn type_operator_validate() {
+        fn validate(src: &'static str) -> Result<(), TsErrorKind> {
+            let module = test_parser(src, Syntax::Typescript(Default::default()), |p| {
+                p.parse_module()
+            });
+
+            match module.body.into_iter().next() {
+                Some(ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias)))) => {
+                    match *alias.type_ann {
+                        TsType::TsTypeOperator(op) => op.validate().map_err(|err| err.kind),
+                        ty => unreachable!("{:?}", ty),
+                    }
+                }
+                item => unreachable!("{:?}", item),
+            }
+        }
+
+        assert_eq!(validate("type A = unique symbol;"), Ok(()));
+        assert_eq!(validate("type A = keyof string;"), Ok(()));
+        assert_eq!(validate("type A = readonly string[];"), Ok(()));
+        assert_eq!(validate("type A = readonly [string];"), Ok(()));
+
+        assert_eq!(
+            validate("type A = unique string;"),
+            Err(TsErrorKind::UniqueNotOnSymbol)
+        );
+        assert_eq!(
+            validate("type A = readonly Foo;"),
+            Err(TsErrorKind::ReadonlyNotOnArrayOrTuple)
+        );
+    }
+
+    #[test]
+    fn type_param_default_references_earlier_param() {
+        let module = test_parser(
+            "type P<T, U = Array<T>> = [T, U];",
+            Syntax::Typescript(Default::default()),
+            |p| p.parse_module(),
+        );
+        let params = match module.body.into_iter().next() {
+            Some(ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias)))) => {
+                alias.type_params.unwrap()
+            }
+            item => unreachable!("{:?}", item),
+        };
+
+        assert_eq!(params.len(), 2);
+        assert!(params[0].default.is_none());
+
+        let names: Vec<_> = params[1]
+            .default
+            .as_ref()
+            .unwrap()
+            .referenced_type_names()
+            .map(|name| match name {
+                TsEntityName::Ident(i) => i.sym.clone(),
+                name => unreachable!("{:?}", name),
+            })
+            .collect();
+        assert_eq!(names, vec![JsWord::from("Array"), JsWord::from("T")]);
+    }
+
+    #[test]
+    fn interface_all_members() {
+        let module = test_parser(
+            "interface A { a: string; b(): void; (): A; }
+            interface B { b(): number; c: number; }
+            interface C extends A, B { a: number; d: boolean; }
+            interface D extends E { d: D; }
+            interface E extends D { e: E; }",
+            Syntax::Typescript(Default::default()),
+            |p| p.parse_module(),
+        );
+        let decls: Vec<_> = module
+            .body
+            .into_iter()
+            .map(|item| match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsInterface(decl))) => decl,
+                item => unreachable!("{:?}", item),
+            })
+            .collect();
+
+        fn keys(members: &[TsTypeElement]) -> Vec<Option<JsWord>> {
+            members
+                .iter()
+                .map(|member| match member {
+                    TsTypeElement::TsPropertySignature(TsPropertySignature { key, .. })
+                    | TsTypeElement::TsMethodSignature(TsMethodSignature { key, .. }) => {
+                        match &**key {
+                            Expr::Ident(i) => Some(i.sym.clone()),
+                            key => unreachable!("{:?}", key),
+                        }
+                    }
+                    _ => None,
+                })
+                .collect()
+        }
+
+        let resolve = |name: &TsEntityName| match name {
+            TsEntityName::Ident(name) => decls.iter().find(|decl| decl.id.sym == name.sym),
+            _ => None,
+        };
+
+        assert_eq!(
+            keys(&decls[2].all_members(resolve)),
+            vec![
+                None,
+                Some("b".into()),
+                Some("c".into()),
+                Some("a".into()),
+                Some("d".into()),
+            ]
+        );
+        assert_eq!(
+            keys(&decls[3].all_members(resolve)),
+            vec![Some("e".into()), Some("d".into())]
+        );
+        assert_eq!(keys(&decls[0].all_members(|_| None)).len(), 3);
+    }
+
+    #[test]
+    fn param_prop_effective_accessibility() {
+        let module = test_parser(
+            "class A { constructor(readonly a, private b, protected c, public d) {} }",
+            Syntax::Typescript(Default::default()),
+            |p| p.parse_module(),
+        );
+        let class = match module.body.into_iter().next() {
+            Some(ModuleItem::Stmt(Stmt::Decl(Decl::Class(decl)))) => decl.class,
+            item => unreachable!("{:?}", item),
+        };
+        let params = match class.body.into_iter().next() {
+            Some(ClassMember::Constructor(c)) => c.params,
+            member => unreachable!("{:?}", member),
+        };
+        let accessibilities: Vec<_> = params
+            .into_iter()
+            .map(|param| match param {
+                ParamOrTsParamProp::TsParamProp(prop) => prop.effective_accessibility(),
+                param => unreachable!("{:?}", param),
+            })
+            .collect();
+
+        assert_eq!(
+            accessibilities,
+            vec![
+                Accessibility::Public,
+                Accessibility::Private,
+                Accessibility::Protected,
+                Accessibility::Public,
+            ]
+        );
+        assert_eq!(
+            accessibilities
+                .iter()
+                .map(|a| a.to_string())
+                .collect::<Vec<_>>(),
+            vec!["public", "private", "protected", "public"]
+        );
+        assert_eq!("protected".parse(), Ok(Accessibility::Protected));
+        assert_eq!("internal".parse::<Accessibility>(), Err(()));
+    }
+
+    #[test]
+    fn recover_unterminated_str_lit_type() {
+        crate::with_test_sess(r#"type T = "abc"#, |handler, input| {
+            let lexer = Lexer::new(
+                Syntax::Typescript(TsConfig {
+                    error_recovery: true,
+                    ..Default::default()
+                }),
+                JscTarget::Es2019,
+                input,
+                None,
+            );
+            let mut parser = Parser::new_from(lexer);
+            let module = parser
+                .parse_typescript_module()
+                .map_err(|e| e.into_diagnostic(handler).emit())?;
+
+            let errors = parser.take_errors();
+            assert_eq!(errors.len(), 1);
+            assert!(matches!(errors[0].kind(), SyntaxError::UnterminatedStrLit));
+
+            let alias = match &module.body[0] {
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias))) => alias,
+                item => unreachable!("{:?}", item),
+            };
+            match &*alias.type_ann {
+                TsType::TsLitType(TsLitType {
+                    lit: TsLit::Str(s), ..
+                }) => assert_eq!(&*s.value, "abc"),
+                ty => unreachable!("{:?}", ty),
+            }
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn unterminated_str_lit_type_without_recovery() {
+        let res = crate::with_test_sess(r#"type T = "abc"#, |handler, input| {
+            let lexer = Lexer::new(
+                Syntax::Typescript(Default::default()),
+                JscTarget::Es2019,
+                input,
+                None,
+            );
+            Parser::new_from(lexer)
+                .parse_typescript_module()
+                .map_err(|e| e.into_diagnostic(handler).emit())
+        });
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn doc_comments_of_declarations() {
+        let c = SingleThreadedComments::default();
+        let s = "/** Interface */
+interface I {}
+/** Alias */
+type T = string;
+/** Declared */
+declare type D = number;";
+
+        let module = test_parser_comment(
+            &c,
+            s,
+            Syntax::Typescript(Default::default()),
+            |p| p.parse_typescript_module(),
+        );
+
+        let docs: Vec<Vec<String>> = module
+            .body
+            .iter()
+            .map(|item| match item {
+                ModuleItem::Stmt(Stmt::Decl(decl)) => c
+                    .leading_of(decl)
+                    .into_iter()
+                    .map(|cmt| cmt.text)
+                    .collect(),
+                item => unreachable!("{:?}", item),
+            })
+            .collect();
+        assert_eq!(
+            docs,
+            vec![
+                vec!["* Interface ".to_string()],
+                vec!["* Alias ".to_string()],
+                vec!["* Declared ".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn tuple_rest_positions() {
+        fn rest_index(src: &'static str) -> Option<usize> {
+            let module = test_parser(src, Syntax::Typescript(Default::default()), |p| {
+                p.parse_typescript_module()
+            });
+            let alias = match &module.body[0] {
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias))) => alias,
+                item => unreachable!("{:?}", item),
+            };
+            match &*alias.type_ann {
+                TsType::TsTupleType(tuple) => tuple
+                    .elem_types
+                    .iter()
+                    .position(|elem| elem.ty.is_ts_rest_type()),
+                ty => unreachable!("{:?}", ty),
+            }
+        }
+
+        assert_eq!(rest_index("type T = [...string[], number];"), Some(0));
+        assert_eq!(
+            rest_index("type T = [string, ...number[], boolean];"),
+            Some(1)
+        );
+        assert_eq!(rest_index("type T = [string, ...number[]];"), Some(1));
+    }
+
+    #[test]
+    fn tuple_rest_after_rest() {
+        let res = crate::with_test_sess("type T = [...string[], ...number[]];", |handler, input| {
+            let lexer = Lexer::new(
+                Syntax::Typescript(Default::default()),
+                JscTarget::Es2019,
+                input,
+                None,
+            );
+            Parser::new_from(lexer)
+                .parse_typescript_module()
+                .map_err(|e| {
+                    assert!(matches!(e.kind(), SyntaxError::TsRestAfterRest));
+                    e.into_diagnostic(handler).emit()
+                })
+        });
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn trailing_comma_in_type_lists() {
+        let module = test_parser(
+            "type T = [string, number,]; type U = Map<string, number,>;",
+            Syntax::Typescript(Default::default()),
+            |p| p.parse_typescript_module(),
+        );
+
+        let types: Vec<&TsType> = module
+            .body
+            .iter()
+            .map(|item| match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias))) => &*alias.type_ann,
+                item => unreachable!("{:?}", item),
+            })
+            .collect();
+        match types[0] {
+            TsType::TsTupleType(tuple) => assert_eq!(tuple.elem_types.len(), 2),
+            ty => unreachable!("{:?}", ty),
+        }
+        match types[1] {
+            TsType::TsTypeRef(TsTypeRef {
+                type_params: Some(args),
+                ..
+            }) => assert_eq!(args.params.len(), 2),
+            ty => unreachable!("{:?}", ty),
+        }
+    }
+
+    #[test]
+    fn this_type_predicates() {
+        let module = test_parser(
+            "class A {
+                isB(): this is B { return true; }
+                assertB(): asserts this is B {}
+                assertThis(): asserts this {}
+            }",
+            Syntax::Typescript(Default::default()),
+            |p| p.parse_typescript_module(),
+        );
+        let class = match &module.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Class(c))) => &c.class,
+            item => unreachable!("{:?}", item),
+        };
+        let predicates: Vec<&TsTypePredicate> = class
+            .body
+            .iter()
+            .map(|member| match member {
+                ClassMember::Method(ClassMethod { function, .. }) => {
+                    match &*function.return_type.as_ref().unwrap().type_ann {
+                        TsType::TsTypePredicate(pred) => pred,
+                        ty => unreachable!("{:?}", ty),
+                    }
+                }
+                member => unreachable!("{:?}", member),
+            })
+            .collect();
+
+        for pred in &predicates {
+            assert!(pred.param_name.is_ts_this_type(), "{:?}", pred);
+        }
+        assert_eq!(
+            predicates
+                .iter()
+                .map(|pred| (pred.asserts, pred.type_ann.is_some()))
+                .collect::<Vec<_>>(),
+            vec![(false, true), (true, true), (true, false)]
+        );
+        match &*predicates[1].type_ann.as_ref().unwrap().type_ann {
+            TsType::TsTypeRef(TsTypeRef {
+                type_name: TsEntityName::Ident(i),
+                ..
+            }) => assert_eq!(&*i.sym, "B"),
+            ty => unreachable!("{:?}", ty),
+        }
+    }
+
+    #[test]
+    fn mapped_type_as_clause() {
+        let module = test_parser(
+            "type Getters<T> = { [K in keyof T as `get${Capitalize<K>}`]: () => T[K] };",
+            Syntax::Typescript(Default::default()),
+            |p| p.parse_typescript_module(),
+        );
+        let alias = match &module.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias))) => alias,
+            item => unreachable!("{:?}", item),
+        };
+        let mapped = match &*alias.type_ann {
+            TsType::TsMappedType(mapped) => mapped,
+            ty => unreachable!("{:?}", ty),
+        };
+
+        assert_eq!(&*mapped.type_param.name.sym, "K");
+        assert!(mapped.type_param.constraint.is_some());
+        match mapped.name_type.as_deref() {
+            Some(TsType::TsLitType(TsLitType {
+                lit: TsLit::Tpl(tpl),
+                ..
+            })) => assert_eq!(tpl.types.len(), 1),
+            ty => unreachable!("{:?}", ty),
+        }
+        assert!(mapped.type_ann.is_some());
+    }
+
+    #[test]
+    fn override_param_prop() {
+        let module = test_parser(
+            "class A extends B { constructor(override x, public override readonly y, readonly z) {} }",
+            Syntax::Typescript(Default::default()),
+            |p| p.parse_typescript_module(),
+        );
+        let class = match &module.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Class(decl))) => &decl.class,
+            item => unreachable!("{:?}", item),
+        };
+        let params = match &class.body[0] {
+            ClassMember::Constructor(c) => &c.params,
+            member => unreachable!("{:?}", member),
+        };
+
+        assert_eq!(
+            params
+                .iter()
+                .map(|param| match param {
+                    ParamOrTsParamProp::TsParamProp(prop) => (prop.is_override, prop.readonly),
+                    param => unreachable!("{:?}", param),
+                })
+                .collect::<Vec<_>>(),
+            vec![(true, false), (true, true), (false, true)]
+        );
+    }
+
+    #[test]
+    fn ts_syntax_positions() {
+        let (module, ts) = test_parser(
+            "const a = 1;\nfunction f(b) { return b; }\nlet c: number = a as any;\nf(c!);",
+            Syntax::Typescript(Default::default()),
+            |p| {
+                p.parse_typescript_module()
+                    .map(|module| (module, p.take_ts_syntax()))
+            },
+        );
+
+        assert!(ts.has_ts());
+        assert_eq!(
+            module
+                .body
+                .iter()
+                .map(|item| ts.contains_ts(item.span()))
+                .collect::<Vec<_>>(),
+            vec![false, false, true, true]
+        );
+
+        let ts = test_parser(
+            "const a = 1;\nfunction f(b) { return b; }",
+            Syntax::Typescript(Default::default()),
+            |p| p.parse_typescript_module().map(|_| p.take_ts_syntax()),
+        );
+        assert!(!ts.has_ts());
+    }
 }
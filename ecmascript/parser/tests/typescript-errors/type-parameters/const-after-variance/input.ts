@@ -0,0 +1 @@
+interface Processor<in const T> {}
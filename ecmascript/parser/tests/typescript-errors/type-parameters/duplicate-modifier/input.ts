@@ -0,0 +1 @@
+interface Processor<in in T> {}
@@ -0,0 +1 @@
+interface Processor<out in T> {}
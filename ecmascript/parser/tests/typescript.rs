@@ -9,9 +9,10 @@ use std::{
     io::Read,
     path::{Path, PathBuf},
 };
+use swc_common::{EqIgnoreSpan, FileName};
 use swc_ecma_ast::*;
 use swc_ecma_parser::{lexer::Lexer, PResult, Parser, StringInput, Syntax, TsConfig};
-use swc_ecma_visit::FoldWith;
+use swc_ecma_visit::{Fold, FoldWith};
 use testing::StdErr;
 
 #[path = "common/mod.rs"]
@@ -219,6 +220,147 @@ fn errors(file: PathBuf) {
     }
 }
 
+/// Every declaration which can be nested in a namespace should be serialized
+/// with its own tag, and deserialized back to the same node.
+#[test]
+fn namespace_declarations_round_trip() {
+    let src = r#"
+namespace N {
+    interface I { a: string }
+    type T = I | string;
+    enum E { A, B = "b" }
+    const enum C { A }
+    namespace Inner { type U = T; }
+    module M {}
+    import J = Inner;
+    export interface EI {}
+    export type ET = T;
+    export enum EE { A }
+    export namespace EN {}
+    export import EJ = Inner;
+    abstract class K {}
+    function f(): void {}
+}
+"#;
+
+    let module = ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let lexer = Lexer::new(
+            Syntax::Typescript(Default::default()),
+            EsVersion::Es2015,
+            (&*fm).into(),
+            None,
+        );
+        let mut p = Parser::new_from(lexer);
+
+        p.parse_typescript_module()
+            .map_err(|e| e.into_diagnostic(&handler).emit())
+    })
+    .unwrap();
+
+    let json = serde_json::to_string_pretty(&module).unwrap();
+    let deser: Module = serde_json::from_str(&json).unwrap();
+    assert_eq!(module, deser);
+    assert_eq!(json, serde_json::to_string_pretty(&deser).unwrap());
+
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let items = value["body"][0]["body"]["body"].as_array().unwrap();
+    assert_eq!(
+        items
+            .iter()
+            .map(|item| item["type"].as_str().unwrap())
+            .collect::<Vec<_>>(),
+        vec![
+            "TsInterfaceDeclaration",
+            "TsTypeAliasDeclaration",
+            "TsEnumDeclaration",
+            "TsEnumDeclaration",
+            "TsModuleDeclaration",
+            "TsModuleDeclaration",
+            "TsImportEqualsDeclaration",
+            "ExportDeclaration",
+            "ExportDeclaration",
+            "ExportDeclaration",
+            "ExportDeclaration",
+            "TsImportEqualsDeclaration",
+            "ClassDeclaration",
+            "FunctionDeclaration",
+        ]
+    );
+    assert_eq!(
+        items[7..11]
+            .iter()
+            .map(|item| item["declaration"]["type"].as_str().unwrap())
+            .collect::<Vec<_>>(),
+        vec![
+            "TsInterfaceDeclaration",
+            "TsTypeAliasDeclaration",
+            "TsEnumDeclaration",
+            "TsModuleDeclaration",
+        ]
+    );
+}
+
+/// Parses `ty` as the type of a type alias, without parentheses.
+fn parse_ts_type_without_parens(ty: &str) -> TsType {
+    struct DropParens;
+
+    impl Fold for DropParens {
+        fn fold_ts_type(&mut self, ty: TsType) -> TsType {
+            ty.fold_children_with(self).into_unwrapped_parens()
+        }
+    }
+
+    let src = format!("type X = {};", ty);
+    let module = ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(FileName::Anon, src);
+        let lexer = Lexer::new(
+            Syntax::Typescript(Default::default()),
+            EsVersion::Es2015,
+            (&*fm).into(),
+            None,
+        );
+        let mut p = Parser::new_from(lexer);
+
+        p.parse_typescript_module()
+            .map_err(|e| e.into_diagnostic(&handler).emit())
+    })
+    .unwrap();
+
+    match module.body.into_iter().next() {
+        Some(ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias)))) => {
+            alias.type_ann.fold_with(&mut DropParens)
+        }
+        item => unreachable!("expected a type alias, got {:?}", item),
+    }
+}
+
+/// The `Display` impl of `TsType` should add the parentheses required to parse
+/// the printed type back to the same type.
+#[test]
+fn ts_type_display_round_trip() {
+    for src in &[
+        "(A | B)[]",
+        "keyof (A & B)",
+        "T extends (infer U extends string) ? U : never",
+        "T extends (infer U extends string) | number ? U : never",
+        "T extends [(infer U extends string) | number] ? U : never",
+        "T extends [(infer U extends string) & number] ? U : never",
+    ] {
+        let ty = parse_ts_type_without_parens(src);
+        let printed = ty.to_string();
+        let reparsed = parse_ts_type_without_parens(&printed);
+
+        assert!(
+            ty.eq_ignore_span(&reparsed),
+            "`{}` was printed as `{}`, which is parsed as `{}`",
+            src,
+            printed,
+            reparsed
+        );
+    }
+}
+
 fn is_backtrace_enabled() -> bool {
     match ::std::env::var("RUST_BACKTRACE") {
         Ok(val) => val == "1" || val == "full",
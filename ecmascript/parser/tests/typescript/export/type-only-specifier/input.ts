@@ -0,0 +1,6 @@
+export { type A, type B as C, D };
+export { type };
+export { type as };
+export { type as E };
+export { type as as };
+export { type as as F };
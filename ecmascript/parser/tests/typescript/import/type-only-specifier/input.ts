@@ -0,0 +1,6 @@
+import { type A, type B as C, D } from "a";
+import { type } from "b";
+import { type as } from "c";
+import { type as E } from "d";
+import { type as as } from "e";
+import { type as as F } from "f";
@@ -0,0 +1,2 @@
+type A<out extends string> = out;
+type B<in = string> = B;
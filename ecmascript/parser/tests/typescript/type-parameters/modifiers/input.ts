@@ -0,0 +1,6 @@
+interface Producer<out T> {}
+interface Consumer<in T> {}
+interface Processor<in out T> {}
+class Box<const in out T> {}
+function f<const T>(x: T) {}
+type Foo<out> = out;
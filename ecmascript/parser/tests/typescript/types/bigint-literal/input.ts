@@ -0,0 +1,2 @@
+type X = 1n | 2n;
+type Y = -1n;
@@ -0,0 +1,2 @@
+type A = ((B));
+type C = (((D | E)));
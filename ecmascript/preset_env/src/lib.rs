@@ -101,7 +101,10 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
     let pass = add!(
         pass,
         Spread,
-        es2015::spread(es2015::spread::Config { loose }),
+        es2015::spread(es2015::spread::Config {
+            loose,
+            ..Default::default()
+        }),
         true
     );
     let pass = add!(pass, FunctionName, es2015::function_name());
@@ -300,7 +303,7 @@ impl Fold for Polyfills {
                             has_escape: false,
                             kind: Default::default(),
                         },
-                        type_only: false,
+                        import_kind: ImportKind::Value,
                         asserts: None,
                     }))
                 }),
@@ -318,7 +321,7 @@ impl Fold for Polyfills {
                             has_escape: false,
                             kind: Default::default(),
                         },
-                        type_only: false,
+                        import_kind: ImportKind::Value,
                         asserts: None,
                     }))
                 }),
@@ -256,7 +256,7 @@ impl InjectHelpers {
                         local: quote_ident!(DUMMY_SP.apply_mark(mark), "swcHelpers"),
                     })],
                     src: quote_str!("@swc/helpers"),
-                    type_only: false,
+                    import_kind: ImportKind::Value,
                     asserts: None,
                 }))]
             } else {
@@ -30,9 +30,10 @@ impl<'a> VisitMut for Operator<'a> {
                                 span: DUMMY_SP,
                                 orig: $ident,
                                 exported: Some($orig),
+                                export_kind: ExportKind::Value,
                             })],
                             src: None,
-                            type_only: false,
+                            export_kind: ExportKind::Value,
                             asserts: None,
                         },
                     )));
@@ -135,7 +136,7 @@ impl<'a> VisitMut for Operator<'a> {
                             span,
                             specifiers: renamed,
                             src: None,
-                            type_only: false,
+                            export_kind: ExportKind::Value,
                             asserts: None,
                         },
                     )));
@@ -298,6 +299,7 @@ impl VisitMut for VarFolder<'_, '_> {
                         span: i.span,
                         exported: Some(orig),
                         orig: i.clone(),
+                        export_kind: ExportKind::Value,
                     }));
             }
             Err(..) => {}
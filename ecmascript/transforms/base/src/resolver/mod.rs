@@ -278,7 +278,9 @@ impl<'a> Resolver<'a> {
                         mark = mark.parent();
                     }
                 }
-                Some(VarDeclKind::Let) | Some(VarDeclKind::Const) => {
+                Some(VarDeclKind::Let)
+                | Some(VarDeclKind::Const)
+                | Some(VarDeclKind::Using { .. }) => {
                     self.current
                         .hoisted_symbols
                         .borrow_mut()
@@ -1239,7 +1241,7 @@ impl VisitMut for Hoister<'_, '_> {
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl) {
         if self.in_block {
             match node.kind {
-                VarDeclKind::Const | VarDeclKind::Let => return,
+                VarDeclKind::Const | VarDeclKind::Let | VarDeclKind::Using { .. } => return,
                 _ => {}
             }
         }
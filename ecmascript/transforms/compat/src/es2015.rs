@@ -143,7 +143,7 @@ export default function fn1() {
     for(var _len = arguments.length, args = new Array(_len), _key = 0; _key < _len; _key++){
         args[_key] = arguments[_key];
     }
-    fn2.apply(void 0, _toConsumableArray(args));
+    fn2.apply(void 0, args);
 }
 "
     );
@@ -583,6 +583,12 @@ impl Fold for BlockScoping {
 
         self.var_decl_kind = old;
 
+        // `using` declarations can't be expressed with `var`, as the resource
+        // is disposed at the end of the block.
+        if var.kind.is_using() {
+            return var;
+        }
+
         VarDecl {
             kind: VarDeclKind::Var,
             ..var
@@ -1004,7 +1010,7 @@ mod tests {
     use super::block_scoping;
     use crate::{es2015, es2015::for_of::for_of, es2017::async_to_generator};
     use swc_common::{chain, Mark};
-    use swc_ecma_parser::Syntax;
+    use swc_ecma_parser::{EsConfig, Syntax};
     use swc_ecma_transforms_testing::test;
     use swc_ecma_transforms_testing::test_exec;
 
@@ -1829,4 +1835,25 @@ expect(foo()).toBe(false);
         }
         "
     );
+
+    test!(
+        Syntax::Es(EsConfig {
+            explicit_resource_management: true,
+            ..Default::default()
+        }),
+        |_| block_scoping(),
+        using_decl,
+        "
+        {
+            using res = open();
+            let x = res.value;
+        }
+        ",
+        "
+        {
+            using res = open();
+            var x = res.value;
+        }
+        "
+    );
 }
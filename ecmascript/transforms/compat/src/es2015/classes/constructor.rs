@@ -1,6 +1,6 @@
 use super::get_prototype_of;
 use std::iter;
-use swc_atoms::JsWord;
+use swc_atoms::{js_word, JsWord};
 use swc_common::{Mark, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::helper;
@@ -347,9 +347,26 @@ pub(super) fn make_possible_return_value(mode: ReturningMode) -> Expr {
                                 }
                             {
                                 args[0].spread = None;
+                                let arg = args.pop().unwrap();
+                                // `arguments` is already an array-like object, but an arbitrary
+                                // iterable should be converted before being passed to
+                                // `Function.prototype.apply`.
+                                let arg = match *arg.expr {
+                                    Expr::Ident(Ident {
+                                        sym: js_word!("arguments"),
+                                        ..
+                                    }) => arg,
+                                    _ => CallExpr {
+                                        span: DUMMY_SP,
+                                        callee: helper!(to_consumable_array, "toConsumableArray"),
+                                        args: vec![arg],
+                                        type_args: Default::default(),
+                                    }
+                                    .as_arg(),
+                                };
                                 (
                                     quote_ident!("apply"),
-                                    vec![ThisExpr { span: DUMMY_SP }.as_arg(), args.pop().unwrap()],
+                                    vec![ThisExpr { span: DUMMY_SP }.as_arg(), arg],
                                 )
                             } else {
                                 (
@@ -109,10 +109,11 @@ impl Classes {
                                                 span: DUMMY_SP,
                                                 orig: ident,
                                                 exported: Some(quote_ident!("default")),
+                                                export_kind: ExportKind::Value,
                                             }
                                             .into()],
                                             src: None,
-                                            type_only: false,
+                                            export_kind: ExportKind::Value,
                                             asserts: None,
                                         },
                                     )) {
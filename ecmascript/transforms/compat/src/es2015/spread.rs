@@ -1,20 +1,30 @@
+use fxhash::{FxHashMap, FxHashSet};
 use serde::Deserialize;
 use std::mem;
-use swc_atoms::js_word;
-use swc_common::{util::move_map::MoveMap, Span, Spanned, DUMMY_SP};
+use swc_atoms::{js_word, JsWord};
+use swc_common::{util::move_map::MoveMap, Span, Spanned, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::ext::ExprRefExt;
 use swc_ecma_transforms_base::helper;
 use swc_ecma_utils::alias_ident_for;
+use swc_ecma_utils::find_ids;
+use swc_ecma_utils::ident::IdentLike;
 use swc_ecma_utils::is_literal;
 use swc_ecma_utils::member_expr;
 use swc_ecma_utils::prepend;
 use swc_ecma_utils::quote_ident;
 use swc_ecma_utils::undefined;
+use swc_ecma_utils::ExprExt;
 use swc_ecma_utils::ExprFactory;
+use swc_ecma_utils::Id;
 use swc_ecma_utils::StmtLike;
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+use swc_ecma_visit::{noop_fold_type, noop_visit_type, Fold, FoldWith, Node, Visit, VisitWith};
 
+/// Lowers spread elements in array literals, calls and `new` expressions.
+///
+/// The resolver should be applied before this pass. Variables are only known
+/// to store an array if they have a syntax context, so without the resolver
+/// every spread value is converted with the `toConsumableArray` helper.
 pub fn spread(c: Config) -> impl Fold {
     Spread { c }
 }
@@ -22,7 +32,46 @@ pub fn spread(c: Config) -> impl Fold {
 #[derive(Debug, Clone, Copy, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Config {
+    /// Assume that every spread value is an array.
+    ///
+    /// Spread values are passed to `.concat` and `.apply` as is, like
+    /// `[].concat(a)` for `[...a]`, which is shorter and faster but only works
+    /// for arrays. Other iterables like a `Set` or a generator are not
+    /// spread.
+    ///
+    /// By default spread values are converted with the `toConsumableArray`
+    /// helper, which follows the iterator protocol for anything that is not
+    /// an array.
     pub loose: bool,
+
+    /// Reuse the temporary variable created for a method call on an
+    /// identifier, like `foo.bar(...args);`, for later calls on the same
+    /// identifier in the same statement list.
+    ///
+    /// Only calls which are expression statements store a reusable variable.
+    /// The variable is not reused if the identifier is declared or assigned
+    /// anywhere in the program, as any function called between the calls may
+    /// assign it.
+    #[serde(default)]
+    pub reuse_temps: bool,
+
+    /// Merge chained `.concat` calls on an array literal, like
+    /// `[].concat(a).concat(b)`, into a single call.
+    ///
+    /// Calls are only merged if the arguments of the outer call don't have
+    /// side effects, so the result of the inner call can't be observed.
+    #[serde(default)]
+    pub merge_concat: bool,
+
+    /// Keep spread elements in array literals and call arguments as is, and
+    /// only lower spread arguments of `new` expressions, which older engines
+    /// historically had issues with.
+    ///
+    /// This allows including this pass unconditionally in a pipeline for
+    /// targets which support spread syntax. Other options like
+    /// [Config::merge_concat] are still applied.
+    #[serde(default)]
+    pub skip_native_spread: bool,
 }
 
 /// es2015 - `SpreadElement`
@@ -35,6 +84,22 @@ struct Spread {
 struct ActualFolder {
     c: Config,
     vars: Vec<VarDeclarator>,
+
+    /// Temporary variables which can be reused, keyed by the identifier they
+    /// store. Only used if [Config::reuse_temps] is enabled.
+    temps: FxHashMap<Id, Ident>,
+    /// Identifiers which are declared or assigned anywhere in the program.
+    /// Only used if [Config::reuse_temps] is enabled.
+    assigned: FxHashSet<Id>,
+    /// `true` if the next expression is the expression of an expression
+    /// statement in the statement list.
+    is_stmt_root: bool,
+    /// Variables which always store an array, so they can be passed to
+    /// `apply` without copying them.
+    known_arrays: FxHashSet<Id>,
+    /// Built-in objects which are never declared or assigned, so they can be
+    /// read again instead of being stored in a temporary variable.
+    globals: FxHashSet<Id>,
 }
 
 impl Fold for Spread {
@@ -52,13 +117,27 @@ impl Fold for Spread {
 impl Spread {
     fn fold_stmt_like<T>(&mut self, items: Vec<T>) -> Vec<T>
     where
-        T: StmtLike + FoldWith<ActualFolder> + FoldWith<Self>,
+        T: StmtLike
+            + FoldWith<ActualFolder>
+            + FoldWith<Self>
+            + VisitWith<AssignFinder>
+            + VisitWith<ArrayVarFinder>,
     {
+        let mut assigns = AssignFinder { ids: vec![] };
+        for item in &items {
+            item.visit_with(&Invalid { span: DUMMY_SP } as _, &mut assigns);
+        }
+
         let mut folder = ActualFolder {
             c: self.c,
-            vars: vec![],
+            known_arrays: known_arrays(&items, &assigns.ids),
+            globals: unassigned_globals(&assigns.ids),
+            ..Default::default()
         };
-        let mut items = items.move_map(|item| item.fold_with(&mut folder));
+        if folder.c.reuse_temps {
+            folder.assigned = assigns.ids.iter().cloned().collect();
+        }
+        let mut items = folder.fold_stmt_list(items);
         if !folder.vars.is_empty() {
             prepend(
                 &mut items,
@@ -75,20 +154,66 @@ impl Spread {
     }
 }
 
+impl ActualFolder {
+    /// Folds a statement list, reusing temporary variables between its
+    /// expression statements if [Config::reuse_temps] is enabled.
+    fn fold_stmt_list<T>(&mut self, items: Vec<T>) -> Vec<T>
+    where
+        T: StmtLike + FoldWith<Self>,
+    {
+        if !self.c.reuse_temps {
+            return items.move_map(|item| item.fold_with(self));
+        }
+
+        // A variable assigned in another statement list may not be assigned
+        // yet, e.g. if the list is the body of a function which is called
+        // before it.
+        let outer = mem::take(&mut self.temps);
+        let items = items.move_map(|item| {
+            self.is_stmt_root = matches!(item.as_stmt(), Some(Stmt::Expr(..)));
+            let item = item.fold_with(self);
+            self.is_stmt_root = false;
+            item
+        });
+        self.temps = outer;
+
+        items
+    }
+}
+
 impl Fold for ActualFolder {
     noop_fold_type!();
 
+    fn fold_stmts(&mut self, n: Vec<Stmt>) -> Vec<Stmt> {
+        self.fold_stmt_list(n)
+    }
+
     fn fold_expr(&mut self, e: Expr) -> Expr {
+        // Only calls which are always executed can store a reusable temporary
+        // variable.
+        let is_stmt_root = mem::take(&mut self.is_stmt_root);
+
+        // `fn?.(...args)` is handled before its children, because the call
+        // should not be converted into an `apply` call on its own.
+        let e = match e {
+            Expr::OptChain(e) if !self.c.skip_native_spread && is_opt_call_with_spread(&e) => {
+                return self.fold_opt_call(e)
+            }
+            _ => e,
+        };
+
         let e = e.fold_children_with(self);
 
         match e {
             Expr::Array(ArrayLit { span, elems }) => {
-                if !elems.iter().any(|e| match e {
-                    Some(ExprOrSpread {
-                        spread: Some(_), ..
-                    }) => true,
-                    _ => false,
-                }) {
+                if self.c.skip_native_spread
+                    || !elems.iter().any(|e| match e {
+                        Some(ExprOrSpread {
+                            spread: Some(_), ..
+                        }) => true,
+                        _ => false,
+                    })
+                {
                     return Expr::Array(ArrayLit { span, elems });
                 }
 
@@ -105,77 +230,32 @@ impl Fold for ActualFolder {
                 let has_spread = args
                     .iter()
                     .any(|ExprOrSpread { spread, .. }| spread.is_some());
-                if !has_spread {
-                    return Expr::Call(CallExpr {
+                if !has_spread || self.c.skip_native_spread {
+                    let call = CallExpr {
                         callee: ExprOrSuper::Expr(callee),
                         args,
                         span,
                         type_args,
-                    });
-                }
-                let (this, callee) = match *callee {
-                    Expr::Member(MemberExpr {
-                        obj: ExprOrSuper::Super(Super { span, .. }),
-                        ..
-                    }) => (Box::new(Expr::This(ThisExpr { span })), callee),
-
-                    Expr::Member(MemberExpr {
-                        obj: ExprOrSuper::Expr(ref expr),
-                        ..
-                    }) if expr.is_this() => (expr.clone(), callee),
-
-                    // Injected variables can be accessed without any side effect
-                    Expr::Member(MemberExpr {
-                        obj: ExprOrSuper::Expr(ref e),
-                        ..
-                    }) if e.as_ident().is_some() && e.as_ident().unwrap().span.is_dummy() => {
-                        (Box::new(Expr::Ident(e.as_ident().unwrap().clone())), callee)
+                    };
+                    if self.c.merge_concat && !has_spread {
+                        return Expr::Call(merge_concat(call));
                     }
-
-                    Expr::Ident(Ident { span, .. }) => (undefined(span), callee),
-
-                    Expr::Member(MemberExpr {
-                        span,
-                        obj: ExprOrSuper::Expr(expr),
-                        prop,
-                        computed,
-                    }) => {
-                        let ident = alias_ident_for(&expr, "_instance");
-                        self.vars.push(VarDeclarator {
-                            span: DUMMY_SP,
-                            definite: false,
-                            // Initialized by paren expression.
-                            name: Pat::Ident(ident.clone().into()),
-                            // Initialized by paren expression.
-                            init: None,
-                        });
-
-                        let this = Box::new(Expr::Ident(ident.clone()));
-                        let callee = Expr::Assign(AssignExpr {
-                            span: DUMMY_SP,
-                            left: PatOrExpr::Pat(Box::new(Pat::Ident(ident.into()))),
-                            op: op!("="),
-                            right: expr,
-                        });
-                        (
-                            this,
-                            Box::new(Expr::Member(MemberExpr {
-                                span,
-                                obj: callee.as_obj(),
-                                prop,
-                                computed,
+                    return Expr::Call(call);
+                }
+                let (this, callee) = match self.hoist_callee_obj(callee, is_stmt_root) {
+                    (Some(this), callee) => (this, callee),
+                    (None, callee) => match *callee {
+                        Expr::Ident(Ident { span, .. }) => (undefined(span), callee),
+
+                        // https://github.com/swc-project/swc/issues/400
+                        // _ => (undefined(callee.span()), callee),
+                        _ => (
+                            Box::new(Expr::This(ThisExpr {
+                                span: callee.span(),
                             })),
-                        )
-                    }
-
-                    // https://github.com/swc-project/swc/issues/400
-                    // _ => (undefined(callee.span()), callee),
-                    _ => (
-                        Box::new(Expr::This(ThisExpr {
-                            span: callee.span(),
-                        })),
-                        callee,
-                    ),
+                            callee,
+                        ),
+                    },
                 };
 
                 let args_array = if is_literal(&args) {
@@ -187,7 +267,7 @@ impl Fold for ActualFolder {
                     self.concat_args(span, args.into_iter().map(Some), false)
                 };
                 let apply = MemberExpr {
-                    span: DUMMY_SP,
+                    span,
                     obj: callee.as_callee(),
                     prop: Box::new(Ident::new(js_word!("apply"), span).into()),
                     computed: false,
@@ -232,7 +312,322 @@ impl Fold for ActualFolder {
     }
 }
 
+fn unwrap_paren_callee(callee: Box<Expr>) -> Box<Expr> {
+    match *callee {
+        Expr::Paren(ParenExpr { expr, .. }) => unwrap_paren_callee(expr),
+        _ => callee,
+    }
+}
+
+/// Returns true for optional calls with spread arguments like `fn?.(...args)`.
+fn is_opt_call_with_spread(e: &OptChainExpr) -> bool {
+    match &*e.expr {
+        Expr::Call(CallExpr {
+            callee: ExprOrSuper::Expr(..),
+            args,
+            ..
+        }) => args.iter().any(|arg| arg.spread.is_some()),
+        _ => false,
+    }
+}
+
 impl ActualFolder {
+    /// Splits the callee of a call with spread arguments into the value of
+    /// `this` and the callee, so it can be called with `apply`.
+    ///
+    /// The object of a member callee like `a.b` is stored in a temporary
+    /// variable, so it's evaluated only once. Returns `None` as `this` if the
+    /// callee is not a member expression.
+    fn hoist_callee_obj(
+        &mut self,
+        callee: Box<Expr>,
+        is_stmt_root: bool,
+    ) -> (Option<Box<Expr>>, Box<Expr>) {
+        // `(a.b)(...x)` is called with `a` as `this`, just like `a.b(...x)`.
+        let callee = unwrap_paren_callee(callee);
+
+        match *callee {
+            Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Super(Super { span, .. }),
+                ..
+            }) => (Some(Box::new(Expr::This(ThisExpr { span }))), callee),
+
+            Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(ref expr),
+                ..
+            }) if expr.is_this() => (Some(expr.clone()), callee),
+
+            // `Math.max(...a)` -> `Math.max.apply(Math, a)`
+            Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(ref expr),
+                ..
+            }) if self.is_global(expr) => (Some(expr.clone()), callee),
+
+            // Injected variables can be accessed without any side effect
+            Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(ref e),
+                ..
+            }) if e.as_ident().is_some() && e.as_ident().unwrap().span.is_dummy() => (
+                Some(Box::new(Expr::Ident(e.as_ident().unwrap().clone()))),
+                callee,
+            ),
+
+            Expr::Member(MemberExpr {
+                span,
+                obj: ExprOrSuper::Expr(ref expr),
+                ref prop,
+                computed,
+            }) if is_stmt_root
+                && expr
+                    .as_ident()
+                    .map_or(false, |i| self.temps.contains_key(&i.to_id())) =>
+            {
+                let ident = self.temps[&expr.as_ident().unwrap().to_id()].clone();
+
+                (
+                    Some(Box::new(Expr::Ident(ident.clone()))),
+                    Box::new(Expr::Member(MemberExpr {
+                        span,
+                        obj: ident.as_obj(),
+                        prop: prop.clone(),
+                        computed,
+                    })),
+                )
+            }
+
+            Expr::Member(MemberExpr {
+                span,
+                obj: ExprOrSuper::Expr(expr),
+                prop,
+                computed,
+            }) => {
+                let ident = alias_ident_for(&expr, "_instance");
+                if is_stmt_root {
+                    if let Expr::Ident(obj) = &*expr {
+                        if !self.assigned.contains(&obj.to_id()) {
+                            self.temps.insert(obj.to_id(), ident.clone());
+                        }
+                    }
+                }
+                self.vars.push(VarDeclarator {
+                    span: DUMMY_SP,
+                    definite: false,
+                    // Initialized by paren expression.
+                    name: Pat::Ident(ident.clone().into()),
+                    // Initialized by paren expression.
+                    init: None,
+                });
+
+                let this = Box::new(Expr::Ident(ident.clone()));
+                let obj = Expr::Assign(AssignExpr {
+                    span: expr.span(),
+                    left: PatOrExpr::Pat(Box::new(Pat::Ident(ident.into()))),
+                    op: op!("="),
+                    right: expr,
+                });
+                (
+                    Some(this),
+                    Box::new(Expr::Member(MemberExpr {
+                        span,
+                        obj: obj.as_obj(),
+                        prop,
+                        computed,
+                    })),
+                )
+            }
+
+            // `a?.b?.(...x)` is called with `a` as `this`, which is hoisted
+            // through the optional chain.
+            Expr::OptChain(OptChainExpr {
+                span,
+                question_dot_token,
+                expr,
+            }) if expr.is_member() => {
+                let (this, expr) = self.hoist_callee_obj(expr, is_stmt_root);
+                (
+                    this,
+                    Box::new(Expr::OptChain(OptChainExpr {
+                        span,
+                        question_dot_token,
+                        expr,
+                    })),
+                )
+            }
+
+            _ => (None, callee),
+        }
+    }
+
+    /// Converts `a.b?.(...args)` into
+    ///
+    /// ```js
+    /// (_b = (_a = a).b) === null || _b === void 0 ? void 0 : _b.apply(_a, args)
+    /// ```
+    ///
+    /// so that the callee is evaluated only once and only called if it's not
+    /// nullish. `a?.b?.(...args)` is called with `a` as `this` as well. If the
+    /// optional chaining pass runs before this pass, the call is already
+    /// lowered to a plain call.
+    fn fold_opt_call(&mut self, e: OptChainExpr) -> Expr {
+        let OptChainExpr { span, expr, .. } = e;
+        let CallExpr { callee, args, .. } = match *expr {
+            Expr::Call(call) => call,
+            _ => unreachable!(),
+        };
+        let callee = match callee {
+            ExprOrSuper::Expr(callee) => callee.fold_with(self),
+            ExprOrSuper::Super(..) => unreachable!(),
+        };
+        let args = args.fold_with(self);
+
+        // Optional calls are never reused, as they may not be executed.
+        let (this, callee) = match self.hoist_callee_obj(callee, false) {
+            (Some(this), callee) => (this, callee),
+            (None, callee) => (undefined(callee.span()), callee),
+        };
+
+        // Identifiers can be read twice, while other callees are stored in a
+        // temporary variable.
+        let (test_obj, func) = match *callee {
+            Expr::Ident(ref i) => (callee.clone(), Box::new(Expr::Ident(i.clone()))),
+            _ => {
+                let ident = alias_ident_for(&callee, "_fn");
+                self.vars.push(VarDeclarator {
+                    span: DUMMY_SP,
+                    definite: false,
+                    name: Pat::Ident(ident.clone().into()),
+                    init: None,
+                });
+
+                (
+                    Box::new(Expr::Assign(AssignExpr {
+                        span: callee.span(),
+                        left: PatOrExpr::Pat(Box::new(Pat::Ident(ident.clone().into()))),
+                        op: op!("="),
+                        right: callee,
+                    })),
+                    Box::new(Expr::Ident(ident)),
+                )
+            }
+        };
+
+        let args_array = if is_literal(&args) {
+            Expr::Array(ArrayLit {
+                span,
+                elems: expand_literal_args(args.into_iter().map(Some)),
+            })
+        } else {
+            self.concat_args(span, args.into_iter().map(Some), false)
+        };
+        let apply = MemberExpr {
+            span,
+            obj: func.clone().as_obj(),
+            prop: Box::new(Ident::new(js_word!("apply"), span).into()),
+            computed: false,
+        };
+
+        Expr::Cond(CondExpr {
+            span,
+            test: Box::new(Expr::Bin(BinExpr {
+                span,
+                op: op!("||"),
+                left: Box::new(Expr::Bin(BinExpr {
+                    span,
+                    op: op!("==="),
+                    left: test_obj,
+                    right: Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
+                })),
+                right: Box::new(Expr::Bin(BinExpr {
+                    span,
+                    op: op!("==="),
+                    left: func,
+                    right: undefined(DUMMY_SP),
+                })),
+            })),
+            cons: undefined(DUMMY_SP),
+            alt: Box::new(Expr::Call(CallExpr {
+                span,
+                callee: apply.as_callee(),
+                args: vec![this.as_arg(), args_array.as_arg()],
+                type_args: None,
+            })),
+        })
+    }
+}
+
+/// Merges `[].concat(a).concat(b)` into `[].concat(a, b)`.
+///
+/// The array literal ensures that the native `concat` is called, which is
+/// not the case for arbitrary objects.
+fn merge_concat(call: CallExpr) -> CallExpr {
+    /// Returns the object of `obj.concat`.
+    fn concat_obj(callee: &ExprOrSuper) -> Option<&Expr> {
+        match callee {
+            ExprOrSuper::Expr(callee) => match &**callee {
+                Expr::Member(MemberExpr {
+                    obj: ExprOrSuper::Expr(obj),
+                    prop,
+                    computed: false,
+                    ..
+                }) => match &**prop {
+                    Expr::Ident(Ident {
+                        sym: js_word!("concat"),
+                        ..
+                    }) => Some(obj),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    let can_merge = match concat_obj(&call.callee) {
+        Some(Expr::Call(inner)) => {
+            matches!(concat_obj(&inner.callee), Some(Expr::Array(..)))
+                && inner.args.iter().all(|arg| arg.spread.is_none())
+                && call
+                    .args
+                    .iter()
+                    .all(|arg| arg.spread.is_none() && !arg.expr.may_have_side_effects())
+        }
+        _ => false,
+    };
+    if !can_merge {
+        return call;
+    }
+
+    let CallExpr {
+        span, callee, args, ..
+    } = call;
+    let mut inner = match callee {
+        ExprOrSuper::Expr(callee) => match *callee {
+            Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(obj),
+                ..
+            }) => match *obj {
+                Expr::Call(inner) => inner,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+
+    inner.args.extend(args);
+    CallExpr { span, ..inner }
+}
+
+impl ActualFolder {
+    /// Returns `true` if `e` is a built-in object like `Math`, which can be
+    /// read twice.
+    fn is_global(&self, e: &Expr) -> bool {
+        match e {
+            Expr::Ident(i) => self.globals.contains(&i.to_id()),
+            _ => false,
+        }
+    }
+
     fn concat_args(
         &self,
         span: Span,
@@ -271,7 +666,11 @@ impl ActualFolder {
                 match spread {
                     // ...b -> toConsumableArray(b)
                     Some(span) => {
-                        //
+                        // The comments of the spread element are attached to
+                        // its start and end, so the call replacing it should
+                        // cover both to keep them.
+                        let span = span.with_hi(expr.span().hi());
+
                         make_arr!();
 
                         buf.push(match *expr {
@@ -305,6 +704,20 @@ impl ActualFolder {
                                     .as_arg()
                                 }
                             }
+                            // `new Array(n)` has holes, which are kept by `concat` but
+                            // filled with `undefined` by spread. `apply` reads holes as
+                            // `undefined`, so known arrays are only passed as is to
+                            // `apply`.
+                            Expr::Ident(ref i)
+                                if !need_array && self.known_arrays.contains(&i.to_id()) =>
+                            {
+                                if args_len == 1 {
+                                    return *expr;
+                                }
+
+                                // `concat` spreads arrays.
+                                expr.as_arg()
+                            }
                             _ => {
                                 if args_len == 1 && !need_array {
                                     return if self.c.loose {
@@ -325,7 +738,7 @@ impl ActualFolder {
                                 if args_len == 1 {
                                     return if self.c.loose {
                                         Expr::Call(CallExpr {
-                                            span: DUMMY_SP,
+                                            span,
                                             callee: ArrayLit {
                                                 span: DUMMY_SP,
                                                 elems: vec![],
@@ -455,3 +868,152 @@ fn expand_literal_args(
     expand(&mut buf, args);
     buf
 }
+
+/// Returns the variables which are initialized with `new Array(...)` and
+/// never reassigned or redeclared, like the rest parameters created by the
+/// parameters pass:
+///
+/// ```js
+/// for (var _len = arguments.length, args = new Array(_len), _key = 0; ...)
+/// ```
+///
+/// Variables without a syntax context are skipped, as two bindings in
+/// different scopes can't be told apart before the resolver has run.
+fn known_arrays<T>(items: &[T], assigned: &[Id]) -> FxHashSet<Id>
+where
+    T: VisitWith<ArrayVarFinder>,
+{
+    let mut arrays = ArrayVarFinder { ids: vec![] };
+    for item in items {
+        item.visit_with(&Invalid { span: DUMMY_SP } as _, &mut arrays);
+    }
+
+    // The declaration itself is found by `AssignFinder` too.
+    arrays
+        .ids
+        .into_iter()
+        .filter(|id| id.1 != SyntaxContext::empty())
+        .filter(|id| assigned.iter().filter(|&a| a == id).count() == 1)
+        .collect()
+}
+
+/// Built-in objects whose methods are commonly called with spread arguments.
+const BUILTIN_OBJECTS: &[&str] = &[
+    "Array", "JSON", "Math", "Number", "Object", "Promise", "Reflect", "String", "Symbol",
+    "console",
+];
+
+/// Returns the built-in objects which are not declared or assigned anywhere.
+///
+/// The resolver leaves references to globals without a syntax context, so
+/// only identifiers with an empty context refer to the global object. Without
+/// the resolver, a binding in any scope has an empty context too and shadows
+/// the global object.
+fn unassigned_globals(assigned: &[Id]) -> FxHashSet<Id> {
+    BUILTIN_OBJECTS
+        .iter()
+        .map(|&name| (JsWord::from(name), SyntaxContext::empty()))
+        .filter(|id| !assigned.contains(id))
+        .collect()
+}
+
+/// Finds variables initialized with `new Array(...)`.
+struct ArrayVarFinder {
+    ids: Vec<Id>,
+}
+
+impl Visit for ArrayVarFinder {
+    noop_visit_type!();
+
+    fn visit_var_declarator(&mut self, n: &VarDeclarator, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        if let (Pat::Ident(i), Some(init)) = (&n.name, &n.init) {
+            if let Expr::New(NewExpr { callee, .. }) = &**init {
+                if let Expr::Ident(Ident {
+                    sym: js_word!("Array"),
+                    ..
+                }) = &**callee
+                {
+                    self.ids.push(i.id.to_id());
+                }
+            }
+        }
+    }
+}
+
+/// Finds identifiers which may be reassigned or redeclared.
+struct AssignFinder {
+    ids: Vec<Id>,
+}
+
+impl Visit for AssignFinder {
+    noop_visit_type!();
+
+    fn visit_assign_expr(&mut self, n: &AssignExpr, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        if let PatOrExpr::Expr(e) = &n.left {
+            if let Expr::Ident(i) = &**e {
+                self.ids.push(i.to_id());
+            }
+        }
+    }
+
+    fn visit_class_decl(&mut self, n: &ClassDecl, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        self.ids.push(n.ident.to_id());
+    }
+
+    fn visit_fn_decl(&mut self, n: &FnDecl, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        self.ids.push(n.ident.to_id());
+    }
+
+    fn visit_class_expr(&mut self, n: &ClassExpr, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        self.ids.extend(n.ident.as_ref().map(Ident::to_id));
+    }
+
+    fn visit_fn_expr(&mut self, n: &FnExpr, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        self.ids.extend(n.ident.as_ref().map(Ident::to_id));
+    }
+
+    fn visit_import_default_specifier(&mut self, n: &ImportDefaultSpecifier, _: &dyn Node) {
+        self.ids.push(n.local.to_id());
+    }
+
+    fn visit_import_named_specifier(&mut self, n: &ImportNamedSpecifier, _: &dyn Node) {
+        self.ids.push(n.local.to_id());
+    }
+
+    fn visit_import_star_as_specifier(&mut self, n: &ImportStarAsSpecifier, _: &dyn Node) {
+        self.ids.push(n.local.to_id());
+    }
+
+    fn visit_pat(&mut self, n: &Pat, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        match n {
+            Pat::Expr(e) => {
+                if let Expr::Ident(i) = &**e {
+                    self.ids.push(i.to_id());
+                }
+            }
+            _ => self.ids.extend(find_ids::<_, Id>(n)),
+        }
+    }
+
+    fn visit_update_expr(&mut self, n: &UpdateExpr, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        if let Expr::Ident(i) = &*n.arg {
+            self.ids.push(i.to_id());
+        }
+    }
+}
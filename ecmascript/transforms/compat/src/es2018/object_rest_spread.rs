@@ -239,6 +239,7 @@ impl Fold for RestFolder {
                             span: DUMMY_SP,
                             orig: Ident::new(sym, DUMMY_SP.with_ctxt(ctxt)),
                             exported: None,
+                            export_kind: ExportKind::Value,
                         })
                         .map(ExportSpecifier::Named)
                         .collect()
@@ -248,7 +249,7 @@ impl Fold for RestFolder {
                     span,
                     specifiers,
                     src: None,
-                    type_only: false,
+                    export_kind: ExportKind::Value,
                     asserts: None,
                 };
 
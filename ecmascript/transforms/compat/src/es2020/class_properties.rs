@@ -218,10 +218,11 @@ impl ClassProperties {
                                                 span: DUMMY_SP,
                                                 orig: ident,
                                                 exported: Some(private_ident!("default")),
+                                                export_kind: ExportKind::Value,
                                             }
                                             .into()],
                                             src: None,
-                                            type_only: false,
+                                            export_kind: ExportKind::Value,
                                             asserts: None,
                                         },
                                     )) {
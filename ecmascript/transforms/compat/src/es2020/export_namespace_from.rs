@@ -48,7 +48,7 @@ impl Fold for ExportNamespaceFrom {
                                     .src
                                     .clone()
                                     .expect("`export default from` requires source"),
-                                type_only: false,
+                                import_kind: ImportKind::Value,
                                 asserts: None,
                             })));
                             extra_stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
@@ -59,10 +59,11 @@ impl Fold for ExportNamespaceFrom {
                                             span: DUMMY_SP,
                                             orig: local,
                                             exported: Some(ns.name),
+                                            export_kind: ExportKind::Value,
                                         },
                                     )],
                                     src: None,
-                                    type_only: false,
+                                    export_kind: ExportKind::Value,
                                     asserts: None,
                                 },
                             )));
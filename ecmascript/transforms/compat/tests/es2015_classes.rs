@@ -6247,3 +6247,96 @@ test!(
   }(B);
     "#
 );
+
+test!(
+    syntax(),
+    |_| spec_tr(),
+    spread_super_call_single_arg,
+    r#"
+class A extends B {
+  constructor() {
+    super(...args);
+  }
+}
+"#,
+    r#"
+var A = function(B) {
+    'use strict';
+    _inherits(A, B);
+    function A() {
+        _classCallCheck(this, A);
+        return _possibleConstructorReturn(this, _getPrototypeOf(A).apply(this, _toConsumableArray(args)));
+    }
+    return A;
+}(B);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| spec_tr(),
+    spread_super_call_single_arg_exec,
+    r#"
+class B {
+  constructor() {
+    this.args = Array.prototype.slice.call(arguments);
+  }
+}
+
+class A extends B {
+  constructor(a, b, c) {
+    const args = [a, b, c];
+    super(...args);
+  }
+}
+
+expect(new A(1, 2, 3).args).toEqual([1, 2, 3]);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| spec_tr(),
+    spread_super_call_iterable_exec,
+    r#"
+class B {
+  constructor() {
+    this.args = Array.prototype.slice.call(arguments);
+  }
+}
+
+const set = new Set([1, 2, 3]);
+
+class A extends B {
+  constructor() {
+    super(...set);
+  }
+}
+
+expect(new A().args).toEqual([1, 2, 3]);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| spec_tr(),
+    spread_super_call_multiple_args_exec,
+    r#"
+class B {
+  constructor() {
+    this.args = Array.prototype.slice.call(arguments);
+  }
+}
+
+class A extends B {
+  constructor(a, b) {
+    const args = [a, b];
+    super("a", ...args, "z");
+  }
+}
+
+const a = new A(1, 2);
+expect(a.args).toEqual(["a", 1, 2, "z"]);
+expect(a).toBeInstanceOf(B);
+"#
+);
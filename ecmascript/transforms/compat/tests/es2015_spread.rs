@@ -1,11 +1,17 @@
-use swc_common::chain;
+use swc_common::{chain, comments::SingleThreadedComments, BytePos, FileName, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_parser::{Parser, StringInput};
+use swc_ecma_transforms_base::helpers::{inject_helpers, Helpers, HELPERS};
+use swc_ecma_transforms_base::resolver::resolver;
 use swc_ecma_transforms_compat::es2015::block_scoping;
 use swc_ecma_transforms_compat::es2015::parameters;
 use swc_ecma_transforms_compat::es2015::spread;
 use swc_ecma_transforms_compat::es2015::spread::Config;
+use swc_ecma_transforms_compat::es2020::optional_chaining;
 use swc_ecma_transforms_testing::test;
 use swc_ecma_transforms_testing::test_exec;
-use swc_ecma_visit::Fold;
+use swc_ecma_visit::{Fold, FoldWith};
 
 fn syntax() -> ::swc_ecma_parser::Syntax {
     Default::default()
@@ -77,6 +83,18 @@ test!(
     "new C(a, b, c, c, d, e);"
 );
 
+test!(
+    syntax(),
+    |_| spread(Config {
+        loose: true,
+        ..Default::default()
+    }),
+    custom_new_noop_with_spread_sibling,
+    "new C(a); new D(...x);",
+    "new C(a);
+_construct(D, [].concat(x));"
+);
+
 // this_context
 test!(
     syntax(),
@@ -895,3 +913,1119 @@ foo("foo", "bar");
 
 "#
 );
+
+test!(
+    syntax(),
+    |_| tr(),
+    spread_in_template_literal,
+    r#"
+`x${[...a]}y`;
+
+"#,
+    r#"
+`x${_toConsumableArray(a)}y`;
+
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    spread_in_tagged_template_tag,
+    "f(...a)`str`;
+f(1, ...a)`x${b}y`;",
+    "f.apply(void 0, _toConsumableArray(a))`str`;
+f.apply(void 0, [1].concat(_toConsumableArray(a)))`x${b}y`;"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    spread_in_tagged_template_tag_exec,
+    r#"
+function makeTag(a, b) {
+    return function (strings, value) {
+        return a + b + strings.join(value);
+    };
+}
+const opts = ["<", "!"];
+expect(makeTag(...opts)`a${1}b`).toBe("<!a1b");
+expect(makeTag(">", ...opts)`c`).toBe("><c");
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    spread_array_constructor,
+    "Array(...a);",
+    "Array.apply(void 0, _toConsumableArray(a));"
+);
+
+test!(
+    syntax(),
+    |_| spread(Config {
+        loose: true,
+        ..Default::default()
+    }),
+    spread_array_constructor_loose,
+    "Array(...a);",
+    "Array.apply(void 0, a);"
+);
+
+test!(
+    syntax(),
+    |_| spread(Config {
+        loose: true,
+        ..Default::default()
+    }),
+    spread_array_of,
+    "Array.of(...a);",
+    "Array.of.apply(Array, a);"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    spread_array_constructor_exec,
+    r#"
+const a = [1, 2, 3];
+expect(Array(...a)).toEqual([1, 2, 3]);
+expect(Array.of(...a)).toEqual([1, 2, 3]);
+expect(Array.of(...[7])).toEqual([7]);
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    this_in_arrow_in_callee,
+    "this.arr.filter(x => this.keep(x)).push(...args);",
+    r#"
+var _instance;
+(_instance = this.arr.filter((x)=>this.keep(x))).push.apply(_instance, _toConsumableArray(args));
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    this_in_arrow_body_call,
+    r#"
+class A {
+    f() {
+        return () => this.arr.map(x => this.g(x)).concat(...args);
+    }
+}
+"#,
+    r#"
+var _instance;
+class A {
+    f() {
+        return ()=>(_instance = this.arr.map((x)=>this.g(x))).concat.apply(_instance, _toConsumableArray(args));
+    }
+}
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    this_in_callee_exec,
+    r#"
+class A {
+    constructor() {
+        this.arr = [1, 2, 3];
+        this.factor = 10;
+    }
+    scale(x) {
+        return x * this.factor;
+    }
+    run(args) {
+        const f = () => this.arr.map(x => this.scale(x)).concat(...args);
+        return f();
+    }
+    self(a, b) {
+        return [this, a, b];
+    }
+    callSelf(args) {
+        return [1].map(() => this.self(...args))[0];
+    }
+}
+
+const a = new A();
+expect(a.run([4, 5])).toEqual([10, 20, 30, 4, 5]);
+expect(a.callSelf([7, 8])).toEqual([a, 7, 8]);
+"#
+);
+
+fn tr_reuse_temps() -> impl Fold {
+    chain!(
+        parameters(),
+        spread(Config {
+            reuse_temps: true,
+            ..Default::default()
+        })
+    )
+}
+
+test!(
+    syntax(),
+    |_| tr_reuse_temps(),
+    reuse_temps_same_ident,
+    r#"
+foob.add(...a);
+foob.add(...b);
+"#,
+    r#"
+var _foob;
+(_foob = foob).add.apply(_foob, _toConsumableArray(a));
+_foob.add.apply(_foob, _toConsumableArray(b));
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    reuse_temps_disabled,
+    r#"
+foob.add(...a);
+foob.add(...b);
+"#,
+    r#"
+var _foob, _foob1;
+(_foob = foob).add.apply(_foob, _toConsumableArray(a));
+(_foob1 = foob).add.apply(_foob1, _toConsumableArray(b));
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr_reuse_temps(),
+    reuse_temps_reassigned,
+    r#"
+foob.add(...a);
+foob = other;
+foob.add(...b);
+"#,
+    r#"
+var _foob, _foob1;
+(_foob = foob).add.apply(_foob, _toConsumableArray(a));
+foob = other;
+(_foob1 = foob).add.apply(_foob1, _toConsumableArray(b));
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr_reuse_temps(),
+    reuse_temps_reassigned_in_args,
+    r#"
+foob.add(...(foob = other, a));
+foob.add(...b);
+"#,
+    r#"
+var _foob, _foob1;
+(_foob = foob).add.apply(_foob, _toConsumableArray((foob = other, a)));
+(_foob1 = foob).add.apply(_foob1, _toConsumableArray(b));
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr_reuse_temps(),
+    reuse_temps_reassigned_by_call,
+    r#"
+foob.add(...a);
+reset();
+foob.add(...b);
+function reset() {
+    foob = other;
+}
+"#,
+    r#"
+var _foob, _foob1;
+(_foob = foob).add.apply(_foob, _toConsumableArray(a));
+reset();
+(_foob1 = foob).add.apply(_foob1, _toConsumableArray(b));
+function reset() {
+    foob = other;
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr_reuse_temps(),
+    reuse_temps_fn_body,
+    r#"
+function f() {
+    foob.add(...a);
+    foob.add(...b);
+}
+"#,
+    r#"
+var _foob;
+function f() {
+    (_foob = foob).add.apply(_foob, _toConsumableArray(a));
+    _foob.add.apply(_foob, _toConsumableArray(b));
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr_reuse_temps(),
+    reuse_temps_not_shared_with_fn_body,
+    r#"
+foob.add(...a);
+function f() {
+    foob.add(...b);
+}
+"#,
+    r#"
+var _foob, _foob1;
+(_foob = foob).add.apply(_foob, _toConsumableArray(a));
+function f() {
+    (_foob1 = foob).add.apply(_foob1, _toConsumableArray(b));
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr_reuse_temps(),
+    reuse_temps_side_effects,
+    r#"
+foo().add(...a);
+foo().add(...b);
+foo.bar.add(...a);
+foo.bar.add(...b);
+"#,
+    r#"
+var _instance, _instance1, _bar, _bar1;
+(_instance = foo()).add.apply(_instance, _toConsumableArray(a));
+(_instance1 = foo()).add.apply(_instance1, _toConsumableArray(b));
+(_bar = foo.bar).add.apply(_bar, _toConsumableArray(a));
+(_bar1 = foo.bar).add.apply(_bar1, _toConsumableArray(b));
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr_reuse_temps(),
+    reuse_temps_conditional,
+    r#"
+if (c) foob.add(...a);
+foob.add(...b);
+"#,
+    r#"
+var _foob, _foob1;
+if (c) (_foob = foob).add.apply(_foob, _toConsumableArray(a));
+(_foob1 = foob).add.apply(_foob1, _toConsumableArray(b));
+"#
+);
+
+test!(
+    syntax(),
+    |_| spread(Config {
+        loose: true,
+        ..Default::default()
+    }),
+    await_call_member,
+    r#"
+async function foo() {
+    await obj.method(...args);
+}
+"#,
+    r#"
+var _obj;
+async function foo() {
+    await (_obj = obj).method.apply(_obj, args);
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    await_call_args,
+    r#"
+async function foo() {
+    return await f(a, ...args);
+}
+"#,
+    r#"
+async function foo() {
+    return await f.apply(void 0, [a].concat(_toConsumableArray(args)));
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| chain!(tr(), inject_helpers()),
+    no_spread_no_helpers,
+    r#"
+function foo(a, b) {
+    return [a, b].concat(bar(a), baz.apply(void 0, b));
+}
+"#,
+    r#"
+function foo(a, b) {
+    return [a, b].concat(bar(a), baz.apply(void 0, b));
+}
+"#
+);
+
+#[test]
+fn spreads_inject_helper_once() {
+    ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(
+            FileName::Anon,
+            "foo(...a);
+const b = [...a, ...c];
+function bar(...args) {
+    return new Foo(...args, ...b);
+}"
+            .into(),
+        );
+        let module = Parser::new(syntax(), StringInput::from(&*fm), None)
+            .parse_module()
+            .map_err(|e| e.into_diagnostic(handler).emit())?;
+
+        let module = HELPERS.set(&Helpers::new(false), || {
+            module.fold_with(&mut chain!(tr(), inject_helpers()))
+        });
+
+        let count = |name: &str| {
+            module
+                .body
+                .iter()
+                .filter(|item| match item {
+                    ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f))) => &*f.ident.sym == name,
+                    _ => false,
+                })
+                .count()
+        };
+        assert_eq!(count("_toConsumableArray"), 1);
+        assert_eq!(count("_construct"), 1);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+test!(
+    syntax(),
+    |_| spread(Config {
+        skip_native_spread: true,
+        ..Default::default()
+    }),
+    skip_native_spread,
+    r#"
+const arr = [...a];
+foo(...a);
+obj.foo(b, ...a);
+new Foo(...a);
+"#,
+    r#"
+const arr = [...a];
+foo(...a);
+obj.foo(b, ...a);
+_construct(Foo, _toConsumableArray(a));
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    nested_member_callee_keeps_base,
+    "a.b.c(...x);",
+    "var _b;
+(_b = a.b).c.apply(_b, _toConsumableArray(x));"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    parenthesized_member_callee_keeps_base,
+    "(a.b)(...x);",
+    "var _a;
+(_a = a).b.apply(_a, _toConsumableArray(x));"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    member_callee_context_exec,
+    r#"
+const a = {
+    b: {
+        c() {
+            return this;
+        },
+    },
+};
+const x = [1, 2];
+
+expect(a.b.c(...x)).toBe(a.b);
+expect((a.b.c)(...x)).toBe(a.b);
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    computed_member_callee_side_effects,
+    "obj[getKey()](...args);",
+    "var _obj;
+(_obj = obj)[getKey()].apply(_obj, _toConsumableArray(args));"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    computed_member_callee_side_effects_exec,
+    r#"
+const log = [];
+const obj = {
+    method(...args) {
+        log.push("call");
+        return [this, ...args];
+    },
+};
+function getObj() {
+    log.push("obj");
+    return obj;
+}
+function getKey() {
+    log.push("key");
+    return "method";
+}
+function getArgs() {
+    log.push("args");
+    return [1, 2];
+}
+
+expect(getObj()[getKey()](...getArgs())).toEqual([obj, 1, 2]);
+expect(log).toEqual(["obj", "key", "args", "call"]);
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    leading_holes_before_spread,
+    "var arr = [,, ...c];",
+    "var arr = [,,].concat(_toConsumableArray(c));"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    trailing_hole_after_spread,
+    "var arr = [...c,,];",
+    "var arr = _toConsumableArray(c).concat([,]);"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    holes_between_spreads,
+    "var arr = [, ...a,, ...b];",
+    "var arr = [,].concat(_toConsumableArray(a), [,], _toConsumableArray(b));"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    holes_around_spreads_exec,
+    r#"
+const a = [1, 2];
+const b = [3];
+
+const leading = [,, ...a];
+expect(leading.length).toBe(4);
+expect(0 in leading).toBe(false);
+expect(1 in leading).toBe(false);
+expect(leading[2]).toBe(1);
+
+const trailing = [...a,,];
+expect(trailing.length).toBe(3);
+expect(2 in trailing).toBe(false);
+
+const mixed = [, ...a,, ...b];
+expect(mixed.length).toBe(5);
+expect(0 in mixed).toBe(false);
+expect(3 in mixed).toBe(false);
+expect(mixed[4]).toBe(3);
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    spread_conditional,
+    "f(...(cond ? a : b));",
+    "f.apply(void 0, _toConsumableArray(cond ? a : b));"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    spread_conditional_exec,
+    r#"
+function f() {
+    return Array.prototype.slice.call(arguments);
+}
+const a = [1, 2];
+const b = [3];
+
+expect(f(...(a.length > 1 ? a : b))).toEqual([1, 2]);
+expect(f(0, ...(a.length > 2 ? a : b))).toEqual([0, 3]);
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    object_spread_argument,
+    "f(...a, {...b});",
+    "f.apply(void 0, _toConsumableArray(a).concat([{...b}]));"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    object_spread_argument_exec,
+    r#"
+function f() {
+    return Array.prototype.slice.call(arguments);
+}
+const a = [1, 2];
+const b = { x: 1 };
+
+const args = f(...a, {...b});
+expect(args.length).toBe(3);
+expect(args[2]).toEqual({ x: 1 });
+expect(args[2]).not.toBe(b);
+"#
+);
+
+fn tr_merge_concat() -> impl Fold {
+    chain!(
+        parameters(),
+        spread(Config {
+            merge_concat: true,
+            ..Default::default()
+        })
+    )
+}
+
+test!(
+    syntax(),
+    |_| tr_merge_concat(),
+    merge_concat_array_literal,
+    "
+var x = [].concat(a).concat(b);
+var y = [1].concat(a).concat([2], b).concat(c);
+",
+    "
+var x = [].concat(a, b);
+var y = [1].concat(a, [2], b, c);
+"
+);
+
+test!(
+    syntax(),
+    |_| chain!(
+        parameters(),
+        spread(Config {
+            loose: true,
+            merge_concat: true,
+            ..Default::default()
+        })
+    ),
+    merge_concat_loose_spread,
+    "var x = [...a].concat(b);",
+    "var x = [].concat(a, b);"
+);
+
+test!(
+    syntax(),
+    |_| tr_merge_concat(),
+    merge_concat_not_array_literal,
+    "var x = arr.concat(a).concat(b);",
+    "var x = arr.concat(a).concat(b);"
+);
+
+test!(
+    syntax(),
+    |_| tr_merge_concat(),
+    merge_concat_side_effects,
+    "var x = [].concat(a).concat(a.push(1));",
+    "var x = [].concat(a).concat(a.push(1));"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr_merge_concat(),
+    merge_concat_exec,
+    r#"
+const a = [1, 2];
+const b = [3];
+expect([].concat(a).concat(b, 4)).toEqual([1, 2, 3, 4]);
+
+const arr = [];
+arr.concat = () => {
+    throw new Error('Should not be called');
+};
+expect(() => arr.concat(a).concat(b)).toThrow();
+
+const c = [1];
+expect([].concat(c).concat(c.push(2))).toEqual([1, 2]);
+"#
+);
+
+#[test]
+fn apply_spans() {
+    ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(FileName::Anon, "foo.bar(...a);".into());
+        let module = Parser::new(syntax(), StringInput::from(&*fm), None)
+            .parse_module()
+            .map_err(|e| e.into_diagnostic(handler).emit())?;
+
+        let call_span = match &module.body[0] {
+            ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => expr.span(),
+            item => unreachable!("{:?}", item),
+        };
+        let obj_span = call_span.with_hi(call_span.lo() + BytePos(3));
+
+        let module = HELPERS.set(&Helpers::new(false), || module.fold_with(&mut tr()));
+
+        let apply = match &module.body[1] {
+            ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match &**expr {
+                Expr::Call(CallExpr {
+                    callee: ExprOrSuper::Expr(callee),
+                    ..
+                }) => match &**callee {
+                    Expr::Member(member) => member,
+                    callee => unreachable!("{:?}", callee),
+                },
+                expr => unreachable!("{:?}", expr),
+            },
+            item => unreachable!("{:?}", item),
+        };
+        assert_eq!(apply.span, call_span);
+
+        // (_foo = foo).bar
+        let assign = match &apply.obj {
+            ExprOrSuper::Expr(obj) => match &**obj {
+                Expr::Member(MemberExpr {
+                    obj: ExprOrSuper::Expr(obj),
+                    ..
+                }) => match &**obj {
+                    Expr::Assign(assign) => assign,
+                    obj => unreachable!("{:?}", obj),
+                },
+                obj => unreachable!("{:?}", obj),
+            },
+            obj => unreachable!("{:?}", obj),
+        };
+        assert_eq!(assign.span, obj_span);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    spread_optional_chain,
+    "f(...a?.b);",
+    "f.apply(void 0, _toConsumableArray(a?.b));"
+);
+
+test!(
+    syntax(),
+    |_| chain!(optional_chaining(), tr()),
+    spread_optional_chain_lowered,
+    "f(...a?.b);",
+    "f.apply(void 0, _toConsumableArray(a === null || a === void 0 ? void 0 : a.b));"
+);
+
+test_exec!(
+    syntax(),
+    |_| chain!(optional_chaining(), tr()),
+    spread_optional_chain_exec,
+    r#"
+function f(x, y) {
+    return x + y;
+}
+
+const a = { b: [1, 2] };
+expect(f(...a?.b)).toBe(3);
+
+// Like native spread, spreading `undefined` throws.
+const c = null;
+expect(() => f(...c?.b)).toThrow();
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    spread_optional_call,
+    "fn?.(...args);",
+    "fn === null || fn === void 0 ? void 0 : fn.apply(void 0, _toConsumableArray(args));"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    spread_optional_method_call,
+    "a.b?.(...args);",
+    r#"
+var _a, _b;
+(_b = (_a = a).b) === null || _b === void 0 ? void 0 : _b.apply(_a, _toConsumableArray(args));
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    spread_optional_parenthesized_method_call,
+    "(a.b)?.(...args);",
+    r#"
+var _a, _b;
+(_b = (_a = a).b) === null || _b === void 0 ? void 0 : _b.apply(_a, _toConsumableArray(args));
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    spread_optional_chain_method_call,
+    "a?.b?.(...args);",
+    r#"
+var _a, _fn;
+(_fn = (_a = a)?.b) === null || _fn === void 0 ? void 0 : _fn.apply(_a, _toConsumableArray(args));
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    spread_optional_chain_method_call_exec,
+    r#"
+const obj = {
+    fn(...args) {
+        return [this, ...args];
+    },
+};
+const args = [1, 2];
+
+expect(obj?.fn?.(...args)).toEqual([obj, 1, 2]);
+expect(null?.fn?.(...args)).toBeUndefined();
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    spread_optional_call_exec,
+    r#"
+let calls = 0;
+const obj = {
+    get fn() {
+        calls++;
+        return function (...args) {
+            return [this, ...args];
+        };
+    },
+};
+const args = [1, 2];
+
+expect(obj.fn?.(...args)).toEqual([obj, 1, 2]);
+expect(calls).toBe(1);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    spread_optional_call_undefined_exec,
+    r#"
+const obj = {};
+const args = [1, 2];
+
+expect(obj.fn?.(...args)).toBeUndefined();
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| chain!(optional_chaining(), tr()),
+    spread_optional_call_lowered_exec,
+    r#"
+const obj = {
+    fn(...args) {
+        return [this, ...args];
+    },
+};
+const args = [1, 2];
+
+expect(obj.fn?.(...args)).toEqual([obj, 1, 2]);
+expect(obj.missing?.(...args)).toBeUndefined();
+"#
+);
+
+test!(
+    syntax(),
+    |_| chain!(resolver(), tr()),
+    spread_rest_param_call,
+    "function f(...rest) {
+    g(...rest);
+}",
+    "function f() {
+    for(var _len = arguments.length, rest = new Array(_len), _key = 0; _key < _len; _key++){
+        rest[_key] = arguments[_key];
+    }
+    g.apply(void 0, rest);
+}"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    spread_rest_param_call_unresolved,
+    "function f(...rest) {
+    g(...rest);
+}",
+    "function f() {
+    for(var _len = arguments.length, rest = new Array(_len), _key = 0; _key < _len; _key++){
+        rest[_key] = arguments[_key];
+    }
+    g.apply(void 0, _toConsumableArray(rest));
+}"
+);
+
+test!(
+    syntax(),
+    |_| chain!(resolver(), tr()),
+    spread_rest_param_array,
+    "function f(...rest) {
+    return [...rest];
+}",
+    "function f() {
+    for(var _len = arguments.length, rest = new Array(_len), _key = 0; _key < _len; _key++){
+        rest[_key] = arguments[_key];
+    }
+    return _toConsumableArray(rest);
+}"
+);
+
+test!(
+    syntax(),
+    |_| chain!(resolver(), tr()),
+    spread_reassigned_rest_param,
+    "function f(...rest) {
+    rest = h();
+    g(...rest);
+}",
+    "function f() {
+    for(var _len = arguments.length, rest = new Array(_len), _key = 0; _key < _len; _key++){
+        rest[_key] = arguments[_key];
+    }
+    rest = h();
+    g.apply(void 0, _toConsumableArray(rest));
+}"
+);
+
+test_exec!(
+    syntax(),
+    |_| chain!(resolver(), tr()),
+    spread_rest_param_exec,
+    r#"
+function f(...rest) {
+    return g(...rest);
+}
+function g() {
+    return Array.prototype.slice.call(arguments);
+}
+function copy(...rest) {
+    const copied = [...rest];
+    copied.push(3);
+    return [rest, copied];
+}
+
+expect(f(1, 2)).toEqual([1, 2]);
+expect(copy(1, 2)).toEqual([[1, 2], [1, 2, 3]]);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    spread_array_with_holes_exec,
+    r#"
+var a = new Array(3);
+function g() {
+    return Array.prototype.slice.call(arguments);
+}
+
+// Spread fills holes with `undefined`, so `map` visits every element.
+expect([...a].map((_, i) => i)).toEqual([0, 1, 2]);
+expect([1, ...a].map((_, i) => i)).toEqual([0, 1, 2, 3]);
+expect(g(...a).map((_, i) => i)).toEqual([0, 1, 2]);
+expect(g(0, ...a).map((_, i) => i)).toEqual([0, 1, 2, 3]);
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    spread_iterable,
+    "const s = new Set([1, 2]);
+[...s];
+f(...s);",
+    "const s = new Set([1, 2]);
+_toConsumableArray(s);
+f.apply(void 0, _toConsumableArray(s));"
+);
+
+test!(
+    syntax(),
+    |_| spread(Config {
+        loose: true,
+        ..Default::default()
+    }),
+    spread_iterable_loose,
+    "const s = new Set([1, 2]);
+[...s];
+f(...s);",
+    "const s = new Set([1, 2]);
+[].concat(s);
+f.apply(void 0, s);"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    spread_iterable_exec,
+    r#"
+function* gen() {
+    yield 1;
+    yield 2;
+}
+function f() {
+    return Array.prototype.slice.call(arguments);
+}
+
+const s = new Set([1, 2, 2, 3]);
+expect([...s]).toEqual([1, 2, 3]);
+expect([0, ...s, 4]).toEqual([0, 1, 2, 3, 4]);
+expect(f(...s)).toEqual([1, 2, 3]);
+
+expect([...gen()]).toEqual([1, 2]);
+expect(f(0, ...gen())).toEqual([0, 1, 2]);
+expect([...new Map([["a", 1]]).keys()]).toEqual(["a"]);
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    spread_global_callee,
+    "Math.max(...a);
+console.log(...a);",
+    "Math.max.apply(Math, _toConsumableArray(a));
+console.log.apply(console, _toConsumableArray(a));"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    spread_shadowed_global_callee,
+    "var Math = foo();
+Math.max(...a);
+function f(console) {
+    console.log(...a);
+}",
+    "var _Math, _console;
+var Math = foo();
+(_Math = Math).max.apply(_Math, _toConsumableArray(a));
+function f(console) {
+    (_console = console).log.apply(_console, _toConsumableArray(a));
+}"
+);
+
+test!(
+    syntax(),
+    |_| chain!(resolver(), tr()),
+    spread_shadowed_global_callee_resolved,
+    "Array.of(...a);
+function f(Array) {
+    Array.of(...a);
+}",
+    "var _Array;
+Array.of.apply(Array, _toConsumableArray(a));
+function f(Array1) {
+    (_Array = Array1).of.apply(_Array, _toConsumableArray(a));
+}"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    spread_global_callee_exec,
+    r#"
+const a = [1, 5, 3];
+expect(Math.max(...a)).toBe(5);
+expect(Math.min(...a, 0)).toBe(0);
+expect(String.fromCharCode(...[97, 98])).toBe("ab");
+"#
+);
+
+#[test]
+fn spread_arg_comments() {
+    let output = ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(
+            FileName::Anon,
+            "f(/* a */ ...args);\ng(1, /* b */ ...rest);".into(),
+        );
+        let comments = SingleThreadedComments::default();
+        let module = Parser::new(syntax(), StringInput::from(&*fm), Some(&comments))
+            .parse_module()
+            .map_err(|e| e.into_diagnostic(handler).emit())?;
+
+        let module = HELPERS.set(&Helpers::new(false), || module.fold_with(&mut tr()));
+
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: Default::default(),
+                comments: Some(&comments),
+                wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+                cm,
+            };
+            emitter.emit_module(&module).unwrap();
+        }
+
+        Ok(String::from_utf8(buf).unwrap())
+    })
+    .unwrap();
+
+    assert!(
+        output.contains("/* a */ _toConsumableArray(args)"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("/* b */ _toConsumableArray(rest)"),
+        "{}",
+        output
+    );
+}
@@ -0,0 +1,302 @@
+use fxhash::FxHashSet;
+use swc_atoms::{js_word, JsWord};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::find_ids;
+use swc_ecma_utils::ident::IdentLike;
+use swc_ecma_utils::private_ident;
+use swc_ecma_utils::Id;
+use swc_ecma_visit::{noop_fold_type, noop_visit_type, Fold, Node, Visit, VisitWith};
+
+/// Rewrites top-level CommonJS export assignments into ES module exports.
+///
+///  - `module.exports = X` becomes `export default X`.
+///  - `exports.foo = X` becomes `export { X as foo }` if `X` is a top-level
+///    binding which is never reassigned, and `const _foo = X; export { _foo as
+///    foo }` otherwise, as CommonJS exports the value at the time of the
+///    assignment. The private binding keeps `foo` from shadowing a global of
+///    the same name.
+///
+/// The module is left untouched if the mapping is ambiguous, e.g. if both
+/// `module.exports =` and `exports.foo =` are used, if an export is assigned
+/// more than once, if `module` / `exports` are declared in the module, or if
+/// they are used anywhere else, like `module.exports.foo = X`, reads of
+/// `exports` or assignments nested in other statements.
+pub fn cjs_default_export_to_esm() -> impl Fold {
+    CjsDefaultExportToEsm
+}
+
+struct CjsDefaultExportToEsm;
+
+enum ExportName {
+    /// `module.exports = X`
+    Default,
+    /// `exports.foo = X`
+    Named(Ident),
+}
+
+impl Fold for CjsDefaultExportToEsm {
+    noop_fold_type!();
+
+    fn fold_module_items(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        let bindings = top_level_bindings(&items);
+        if bindings.contains(&js_word!("module")) || bindings.contains(&"exports".into()) {
+            return items;
+        }
+
+        let mut has_default = false;
+        let mut names: FxHashSet<JsWord> = Default::default();
+        for item in &items {
+            match export_name(item) {
+                Some(ExportName::Default) => {
+                    if has_default {
+                        return items;
+                    }
+                    has_default = true;
+                }
+                Some(ExportName::Named(name)) => {
+                    if !names.insert(name.sym) {
+                        return items;
+                    }
+                }
+                _ => {}
+            }
+        }
+        if has_default && !names.is_empty() {
+            return items;
+        }
+
+        let mut usage = CjsUsage::default();
+        for item in &items {
+            item.visit_with(&Invalid { span: DUMMY_SP } as _, &mut usage);
+        }
+        // Each export assignment refers to `module` or `exports` once.
+        if usage.cjs_refs != names.len() + has_default as usize {
+            return items;
+        }
+
+        let mut buf = Vec::with_capacity(items.len());
+        for item in items {
+            let name = match export_name(&item) {
+                Some(name) => name,
+                None => {
+                    buf.push(item);
+                    continue;
+                }
+            };
+            let expr = match item {
+                ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match *expr {
+                    Expr::Assign(AssignExpr { right, .. }) => right,
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+
+            match name {
+                ExportName::Default => buf.push(ModuleItem::ModuleDecl(
+                    ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                        span: DUMMY_SP,
+                        expr,
+                    }),
+                )),
+                ExportName::Named(exported) => {
+                    export_named(&mut buf, &bindings, &usage.assigned, exported, expr)
+                }
+            }
+        }
+
+        buf
+    }
+}
+
+fn export_named(
+    buf: &mut Vec<ModuleItem>,
+    bindings: &FxHashSet<JsWord>,
+    assigned: &FxHashSet<JsWord>,
+    exported: Ident,
+    expr: Box<Expr>,
+) {
+    let orig = match *expr {
+        // `exports.foo = foo;` refers to an existing binding, so it can be
+        // exported directly unless an assignment would change the
+        // exported value.
+        Expr::Ident(ref i) if bindings.contains(&i.sym) && !assigned.contains(&i.sym) => i.clone(),
+
+        _ => {
+            let local = private_ident!(exported.span, format!("_{}", exported.sym));
+
+            buf.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Const,
+                declare: false,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(local.clone().into()),
+                    init: Some(expr),
+                    definite: false,
+                }],
+            }))));
+            local
+        }
+    };
+
+    let exported = if orig.sym == exported.sym {
+        None
+    } else {
+        Some(exported)
+    };
+
+    buf.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
+        NamedExport {
+            span: DUMMY_SP,
+            specifiers: vec![ExportSpecifier::Named(ExportNamedSpecifier {
+                span: DUMMY_SP,
+                orig,
+                exported,
+                export_kind: ExportKind::Value,
+            })],
+            src: None,
+            export_kind: ExportKind::Value,
+            asserts: None,
+        },
+    )));
+}
+
+/// Returns the name exported by `item` if it's a CommonJS export assignment.
+fn export_name(item: &ModuleItem) -> Option<ExportName> {
+    let assign = match item {
+        ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match &**expr {
+            Expr::Assign(assign) if assign.op == op!("=") => assign,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let member = match &assign.left {
+        PatOrExpr::Expr(e) => match &**e {
+            Expr::Member(m) => m,
+            _ => return None,
+        },
+        PatOrExpr::Pat(p) => match &**p {
+            Pat::Expr(e) => match &**e {
+                Expr::Member(m) => m,
+                _ => return None,
+            },
+            _ => return None,
+        },
+    };
+
+    let obj = match &member.obj {
+        ExprOrSuper::Expr(obj) => match &**obj {
+            Expr::Ident(obj) => obj,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let prop = match (&*member.prop, member.computed) {
+        (Expr::Ident(prop), false) => prop,
+        _ => return None,
+    };
+
+    match (&*obj.sym, &*prop.sym) {
+        ("module", "exports") => Some(ExportName::Default),
+        ("exports", _) => Some(ExportName::Named(prop.clone())),
+        _ => None,
+    }
+}
+
+/// Counts the references to `module` and `exports`, and collects the names of
+/// reassigned bindings.
+#[derive(Default)]
+struct CjsUsage {
+    cjs_refs: usize,
+    assigned: FxHashSet<JsWord>,
+}
+
+impl Visit for CjsUsage {
+    noop_visit_type!();
+
+    fn visit_assign_expr(&mut self, e: &AssignExpr, _: &dyn Node) {
+        e.visit_children_with(self);
+
+        match &e.left {
+            PatOrExpr::Expr(left) => {
+                if let Expr::Ident(i) = &**left {
+                    self.assigned.insert(i.sym.clone());
+                }
+            }
+            PatOrExpr::Pat(left) => match &**left {
+                Pat::Expr(left) => {
+                    if let Expr::Ident(i) = &**left {
+                        self.assigned.insert(i.sym.clone());
+                    }
+                }
+                _ => self
+                    .assigned
+                    .extend(find_ids::<_, Id>(left).into_iter().map(|id| id.0)),
+            },
+        }
+    }
+
+    fn visit_ident(&mut self, i: &Ident, _: &dyn Node) {
+        if i.sym == js_word!("module") || &*i.sym == "exports" {
+            self.cjs_refs += 1;
+        }
+    }
+
+    fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+        e.obj.visit_with(e as _, self);
+
+        if e.computed {
+            e.prop.visit_with(e as _, self);
+        }
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        if let PropName::Computed(c) = n {
+            c.visit_with(n as _, self);
+        }
+    }
+
+    fn visit_update_expr(&mut self, e: &UpdateExpr, _: &dyn Node) {
+        e.visit_children_with(self);
+
+        if let Expr::Ident(i) = &*e.arg {
+            self.assigned.insert(i.sym.clone());
+        }
+    }
+}
+
+fn top_level_bindings(items: &[ModuleItem]) -> FxHashSet<JsWord> {
+    let mut ids: Vec<Id> = vec![];
+
+    for item in items {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                ids.extend(import.specifiers.iter().map(|s| match s {
+                    ImportSpecifier::Named(s) => s.local.to_id(),
+                    ImportSpecifier::Default(s) => s.local.to_id(),
+                    ImportSpecifier::Namespace(s) => s.local.to_id(),
+                }))
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. }))
+            | ModuleItem::Stmt(Stmt::Decl(decl)) => match decl {
+                Decl::Class(c) => ids.push(c.ident.to_id()),
+                Decl::Fn(f) => ids.push(f.ident.to_id()),
+                Decl::Var(v) => ids.extend(find_ids::<_, Id>(&v.decls)),
+                _ => {}
+            },
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::Class(ClassExpr { ident: Some(i), .. }),
+                ..
+            }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::Fn(FnExpr { ident: Some(i), .. }),
+                ..
+            })) => ids.push(i.to_id()),
+            _ => {}
+        }
+    }
+
+    ids.into_iter().map(|id| id.0).collect()
+}
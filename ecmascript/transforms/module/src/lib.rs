@@ -1,12 +1,14 @@
 #![deny(unused)]
 
 pub use self::amd::amd;
+pub use self::cjs_default_export_to_esm::cjs_default_export_to_esm;
 pub use self::common_js::common_js;
 pub use self::umd::umd;
 
 #[macro_use]
 pub mod util;
 pub mod amd;
+pub mod cjs_default_export_to_esm;
 pub mod common_js;
 pub mod import_analysis;
 pub mod umd;
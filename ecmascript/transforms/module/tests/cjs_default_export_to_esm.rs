@@ -0,0 +1,180 @@
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms_module::cjs_default_export_to_esm;
+use swc_ecma_transforms_testing::test;
+use swc_ecma_visit::Fold;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        ..Default::default()
+    })
+}
+
+fn tr() -> impl Fold {
+    cjs_default_export_to_esm()
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    default_export,
+    r#"
+const foo = require('foo');
+function bar() {}
+module.exports = { foo, bar };
+"#,
+    r#"
+const foo = require('foo');
+function bar() {}
+export default { foo, bar };
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    named_export,
+    r#"
+function helper() {}
+exports.helper = helper;
+exports.renamed = helper;
+exports.value = 1 + 2;
+exports.fn = function () {};
+"#,
+    r#"
+function helper() {}
+export { helper };
+export { helper as renamed };
+const _value = 1 + 2;
+export { _value as value };
+const _fn = function () {};
+export { _fn as fn };
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    named_export_conflict,
+    r#"
+const value = 1;
+exports.value = value + 1;
+exports.default = 2;
+"#,
+    r#"
+const value = 1;
+const _value = value + 1;
+export { _value as value };
+const _default = 2;
+export { _default as default };
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    ambiguous_default_and_named,
+    r#"
+module.exports = function () {};
+exports.foo = 1;
+"#,
+    r#"
+module.exports = function () {};
+exports.foo = 1;
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    nested_assignments,
+    r#"
+if (cond) {
+    module.exports = 1;
+}
+"#,
+    r#"
+if (cond) {
+    module.exports = 1;
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    module_exports_member,
+    r#"
+module.exports = function () {};
+module.exports.foo = 1;
+"#,
+    r#"
+module.exports = function () {};
+module.exports.foo = 1;
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    exports_read,
+    r#"
+exports.foo = 1;
+console.log(exports.foo);
+"#,
+    r#"
+exports.foo = 1;
+console.log(exports.foo);
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    nested_named_assignment,
+    r#"
+exports.foo = 1;
+if (cond) {
+    exports.bar = 2;
+}
+"#,
+    r#"
+exports.foo = 1;
+if (cond) {
+    exports.bar = 2;
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    named_export_reassigned,
+    r#"
+let count = 0;
+exports.count = count;
+count += 1;
+"#,
+    r#"
+let count = 0;
+const _count = count;
+export { _count as count };
+count += 1;
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    named_export_global_read,
+    r#"
+exports.foo = function () {
+    return foo;
+};
+"#,
+    r#"
+const _foo = function () {
+    return foo;
+};
+export { _foo as foo };
+"#
+);
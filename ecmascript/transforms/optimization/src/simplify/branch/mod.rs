@@ -1443,11 +1443,7 @@ fn is_ok_to_inline_block(s: &[Stmt]) -> bool {
 
 fn is_block_scoped_stuff(s: &Stmt) -> bool {
     match s {
-        Stmt::Decl(Decl::Var(VarDecl { kind, .. }))
-            if *kind == VarDeclKind::Const || *kind == VarDeclKind::Let =>
-        {
-            return true;
-        }
+        Stmt::Decl(Decl::Var(VarDecl { kind, .. })) if kind.is_block_scoped() => true,
         Stmt::Decl(Decl::Fn(..)) | Stmt::Decl(Decl::Class(..)) => true,
         _ => false,
     }
@@ -591,6 +591,12 @@ impl VisitMut for Inlining<'_> {
 
         match self.phase {
             Phase::Analysis => match node.name {
+                // The resource of a `using` declaration is disposed when the
+                // block exits, so the declaration is kept as is.
+                Pat::Ident(ref name) if self.var_decl_kind.is_using() => {
+                    self.declare(name.to_id(), None, true, kind);
+                    self.scope.prevent_inline(&name.to_id());
+                }
                 Pat::Ident(ref name) => {
                     //
                     match &node.init {
@@ -642,7 +648,9 @@ impl VisitMut for Inlining<'_> {
             Phase::Inlining => {
                 match node.name {
                     Pat::Ident(ref name) => {
-                        if self.var_decl_kind != VarDeclKind::Const {
+                        if self.var_decl_kind != VarDeclKind::Const
+                            && !self.var_decl_kind.is_using()
+                        {
                             let id = name.to_id();
 
                             log::trace!("Trying to optimize variable declaration: {:?}", id);
@@ -320,7 +320,7 @@ impl<'a> Scope<'a> {
             match v.kind {
                 // Reading parameter is ok.
                 VarType::Param => return false,
-                VarType::Var(VarDeclKind::Let) | VarType::Var(VarDeclKind::Const) => return false,
+                VarType::Var(kind) if kind.is_block_scoped() => return false,
                 _ => {}
             }
 
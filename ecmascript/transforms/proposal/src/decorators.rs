@@ -200,10 +200,11 @@ impl Fold for Decorators {
                                 span: DUMMY_SP,
                                 orig: ident,
                                 exported: Some(quote_ident!("default")),
+                                export_kind: ExportKind::Value,
                             }
                             .into()],
                             src: None,
-                            type_only: false,
+                            export_kind: ExportKind::Value,
                             asserts: None,
                         },
                     )));
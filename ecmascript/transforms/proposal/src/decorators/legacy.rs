@@ -159,7 +159,7 @@ impl Fold for Legacy {
                 span: DUMMY_SP,
                 specifiers: replace(&mut self.exports, Default::default()),
                 src: None,
-                type_only: false,
+                export_kind: ExportKind::Value,
                 asserts: None,
             });
 
@@ -186,6 +186,7 @@ impl Fold for Legacy {
                         span: DUMMY_SP,
                         orig: export_ident.clone(),
                         exported: Some(quote_ident!("default")),
+                        export_kind: ExportKind::Value,
                     }));
 
                 return ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl {
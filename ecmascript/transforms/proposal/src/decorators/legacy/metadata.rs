@@ -338,7 +338,7 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
             _ => {}
         }
 
-        let member_expr = ts_entity_to_member_expr(&ty.type_name);
+        let member_expr = Expr::from(ty.type_name.clone());
 
         fn check_object_existed(expr: Box<Expr>) -> Box<Expr> {
             match *expr {
@@ -581,22 +581,6 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
     serialize_type_node(class_name.map(|v| &*v.sym).unwrap_or(""), &**param)
 }
 
-fn ts_entity_to_member_expr(type_name: &TsEntityName) -> Expr {
-    match type_name {
-        TsEntityName::TsQualifiedName(q) => {
-            let obj = ts_entity_to_member_expr(&q.left);
-
-            Expr::Member(MemberExpr {
-                span: DUMMY_SP,
-                obj: obj.as_obj(),
-                prop: Box::new(Expr::Ident(q.right.clone())),
-                computed: false,
-            })
-        }
-        TsEntityName::Ident(i) => Expr::Ident(i.clone()),
-    }
-}
-
 fn get_type_ann_of_pat(p: &Pat) -> Option<&TsTypeAnn> {
     match p {
         Pat::Ident(p) => &p.type_ann,
@@ -47,7 +47,7 @@ impl Fold for ExportDefaultFrom {
                                     .src
                                     .clone()
                                     .expect("`export default from` requires source"),
-                                type_only: false,
+                                import_kind: ImportKind::Value,
                                 asserts: None,
                             })));
                             extra_stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
@@ -58,10 +58,11 @@ impl Fold for ExportDefaultFrom {
                                             span: DUMMY_SP,
                                             orig: local,
                                             exported: Some(default),
+                                            export_kind: ExportKind::Value,
                                         },
                                     )],
                                     src: None,
-                                    type_only: false,
+                                    export_kind: ExportKind::Value,
                                     asserts: None,
                                 },
                             )));
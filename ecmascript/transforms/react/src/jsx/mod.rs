@@ -821,6 +821,7 @@ where
                     span: DUMMY_SP,
                     local,
                     imported: Some(Ident::new("createElement".into(), DUMMY_SP)),
+                    import_kind: ImportKind::Value,
                 });
                 prepend(
                     &mut module.body,
@@ -833,7 +834,7 @@ where
                             has_escape: false,
                             kind: Default::default(),
                         },
-                        type_only: Default::default(),
+                        import_kind: ImportKind::Value,
                         asserts: Default::default(),
                     })),
                 );
@@ -846,12 +847,14 @@ where
                     span: DUMMY_SP,
                     local,
                     imported: Some(quote_ident!("jsx")),
+                    import_kind: ImportKind::Value,
                 })
                 .into_iter()
                 .chain(self.import_jsxs.take().map(|local| ImportNamedSpecifier {
                     span: DUMMY_SP,
                     local,
                     imported: Some(quote_ident!("jsxs")),
+                    import_kind: ImportKind::Value,
                 }))
                 .chain(
                     self.import_fragment
@@ -860,6 +863,7 @@ where
                             span: DUMMY_SP,
                             local,
                             imported: Some(quote_ident!("Fragment")),
+                            import_kind: ImportKind::Value,
                         }),
                 )
                 .map(ImportSpecifier::Named)
@@ -877,7 +881,7 @@ where
                             has_escape: false,
                             kind: Default::default(),
                         },
-                        type_only: Default::default(),
+                        import_kind: ImportKind::Value,
                         asserts: Default::default(),
                     })),
                 );
@@ -0,0 +1,129 @@
+#![feature(test)]
+
+extern crate test;
+
+use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_ecma_ast::Module;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsSyntaxPositions};
+use swc_ecma_transforms_typescript::{
+    is_pure_js, strip,
+    strip::{strip_with_ts_syntax, Config},
+};
+use swc_ecma_visit::FoldWith;
+use test::Bencher;
+
+static SOURCE: &str = include_str!("assets/AjaxObservable.ts");
+
+/// Parses the asset and strips it, so the benchmarks run on a module without
+/// TypeScript syntax.
+fn js_module(cm: Lrc<SourceMap>) -> Module {
+    let fm = cm.new_source_file(FileName::Anon, SOURCE.into());
+    let lexer = Lexer::new(
+        Syntax::Typescript(Default::default()),
+        Default::default(),
+        StringInput::from(&*fm),
+        None,
+    );
+    let mut parser = Parser::new_from(lexer);
+    let module = parser.parse_module().map_err(|_| ()).unwrap();
+    module.fold_with(&mut strip())
+}
+
+/// Prints the stripped asset and parses it again, so the positions recorded by
+/// the parser belong to the module without TypeScript syntax.
+fn reparsed_js_module(cm: Lrc<SourceMap>) -> (Module, TsSyntaxPositions) {
+    let module = js_module(cm.clone());
+
+    let mut buf = vec![];
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+            comments: None,
+        };
+        emitter.emit_module(&module).unwrap();
+    }
+
+    let fm = cm.new_source_file(FileName::Anon, String::from_utf8(buf).unwrap());
+    let lexer = Lexer::new(
+        Syntax::Typescript(Default::default()),
+        Default::default(),
+        StringInput::from(&*fm),
+        None,
+    );
+    let mut parser = Parser::new_from(lexer);
+    let module = parser.parse_module().map_err(|_| ()).unwrap();
+    (module, parser.take_ts_syntax())
+}
+
+#[bench]
+fn strip_pure_js(b: &mut Bencher) {
+    b.bytes = SOURCE.len() as _;
+
+    let _ = ::testing::run_test(false, |cm, _| {
+        let module = js_module(cm);
+
+        b.iter(|| {
+            let module = module.clone();
+            test::black_box(module.fold_with(&mut strip()));
+        });
+
+        Ok(())
+    });
+}
+
+#[bench]
+fn strip_with_ts_syntax_pure_js(b: &mut Bencher) {
+    b.bytes = SOURCE.len() as _;
+
+    let _ = ::testing::run_test(false, |cm, _| {
+        let (module, ts_syntax) = reparsed_js_module(cm);
+
+        b.iter(|| {
+            let module = module.clone();
+            test::black_box(module.fold_with(&mut strip_with_ts_syntax(
+                Config::default(),
+                ts_syntax.clone(),
+            )));
+        });
+
+        Ok(())
+    });
+}
+
+#[bench]
+fn strip_reparsed_pure_js(b: &mut Bencher) {
+    b.bytes = SOURCE.len() as _;
+
+    let _ = ::testing::run_test(false, |cm, _| {
+        let (module, _) = reparsed_js_module(cm);
+
+        b.iter(|| {
+            // Same module as above, without the positions.
+            let module = module.clone();
+            test::black_box(module.fold_with(&mut strip()));
+        });
+
+        Ok(())
+    });
+}
+
+#[bench]
+fn is_pure_js_pure_js(b: &mut Bencher) {
+    b.bytes = SOURCE.len() as _;
+
+    let _ = ::testing::run_test(false, |cm, _| {
+        let module = js_module(cm);
+        assert!(is_pure_js(&module));
+
+        b.iter(|| {
+            // Cloned like above so the results can be compared.
+            let module = module.clone();
+            test::black_box(is_pure_js(&module));
+        });
+
+        Ok(())
+    });
+}
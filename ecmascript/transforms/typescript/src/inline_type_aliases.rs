@@ -0,0 +1,156 @@
+use fxhash::FxHashMap;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::add_required_parens;
+use swc_ecma_utils::ident::IdentLike;
+use swc_ecma_utils::Id;
+use swc_ecma_visit::{Fold, FoldWith, Node, Visit, VisitWith};
+
+/// Replaces the only reference to a type alias with its definition and removes
+/// the alias.
+///
+/// ```ts
+/// type T = string | number;
+/// let x: T[];
+/// ```
+///
+/// becomes
+///
+/// ```ts
+/// let x: (string | number)[];
+/// ```
+///
+/// Only top-level aliases which are not exported and not generic are inlined.
+/// Aliases which are referenced more than once or referenced by something
+/// other than a type reference, like `export { T }`, are kept, as are aliases
+/// whose name is declared again in a nested scope, like a type parameter `<T>`.
+pub fn inline_type_aliases() -> impl Fold {
+    InlineTypeAliases::default()
+}
+
+#[derive(Default)]
+struct InlineTypeAliases {
+    /// Aliases which are inlined, by name.
+    aliases: FxHashMap<Id, Box<TsType>>,
+}
+
+impl Fold for InlineTypeAliases {
+    fn fold_module(&mut self, m: Module) -> Module {
+        let mut counter = RefCounter::default();
+        for item in &m.body {
+            if let ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias))) = item {
+                if !alias.is_generic() {
+                    counter.refs.insert(alias.id.to_id(), Default::default());
+                }
+            }
+        }
+        for item in &m.body {
+            match item {
+                // The name of a top-level alias is not a reference. The names of
+                // nested aliases are counted, as they shadow the top-level one
+                // unless the resolver has given them another context.
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias))) => {
+                    alias.type_params.visit_with(alias as _, &mut counter);
+                    alias.type_ann.visit_with(alias as _, &mut counter);
+                }
+                _ => item.visit_with(&Invalid { span: DUMMY_SP } as _, &mut counter),
+            }
+        }
+
+        let mut body = Vec::with_capacity(m.body.len());
+        for item in m.body {
+            match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias)))
+                    if counter
+                        .refs
+                        .get(&alias.id.to_id())
+                        .map_or(false, RefCount::is_single_type_ref) =>
+                {
+                    self.aliases.insert(alias.id.to_id(), alias.type_ann);
+                }
+                _ => body.push(item),
+            }
+        }
+
+        if self.aliases.is_empty() {
+            return Module { body, ..m };
+        }
+
+        Module { body, ..m }.fold_children_with(self)
+    }
+
+    fn fold_ts_type(&mut self, ty: TsType) -> TsType {
+        let ty = add_required_parens(ty.fold_children_with(self));
+
+        match ty {
+            TsType::TsTypeRef(TsTypeRef {
+                type_name: TsEntityName::Ident(ref i),
+                type_params: None,
+                ..
+            }) => match self.aliases.remove(&i.to_id()) {
+                // Aliases used in the definition are inlined as well.
+                Some(def) => (*def).fold_with(self).into_unwrapped_parens(),
+                None => ty,
+            },
+            _ => ty,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct RefCount {
+    /// References in type references without type arguments, like `T` in
+    /// `let x: T`.
+    type_refs: usize,
+    /// All references.
+    total: usize,
+}
+
+impl RefCount {
+    fn is_single_type_ref(&self) -> bool {
+        self.type_refs == 1 && self.total == 1
+    }
+}
+
+#[derive(Default)]
+struct RefCounter {
+    refs: FxHashMap<Id, RefCount>,
+}
+
+impl Visit for RefCounter {
+    fn visit_ident(&mut self, i: &Ident, _: &dyn Node) {
+        if let Some(count) = self.refs.get_mut(&i.to_id()) {
+            count.total += 1;
+        }
+    }
+
+    fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+        e.obj.visit_with(e as _, self);
+
+        if e.computed {
+            e.prop.visit_with(e as _, self);
+        }
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        if let PropName::Computed(c) = n {
+            c.visit_with(n as _, self);
+        }
+    }
+
+    fn visit_ts_qualified_name(&mut self, n: &TsQualifiedName, _: &dyn Node) {
+        n.left.visit_with(n as _, self);
+    }
+
+    fn visit_ts_type_ref(&mut self, n: &TsTypeRef, _: &dyn Node) {
+        if n.type_params.is_none() {
+            if let TsEntityName::Ident(i) = &n.type_name {
+                if let Some(count) = self.refs.get_mut(&i.to_id()) {
+                    count.type_refs += 1;
+                }
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+}
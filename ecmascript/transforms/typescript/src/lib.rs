@@ -1,3 +1,9 @@
+pub use self::inline_type_aliases::inline_type_aliases;
+pub use self::namespace_to_module::namespace_to_module;
+pub use self::pure_js::is_pure_js;
 pub use self::strip::strip;
 
+pub mod inline_type_aliases;
+pub mod namespace_to_module;
+mod pure_js;
 pub mod strip;
@@ -0,0 +1,241 @@
+use fxhash::FxHashSet;
+use swc_atoms::JsWord;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::find_ids;
+use swc_ecma_utils::ident::IdentLike;
+use swc_ecma_utils::Id;
+use swc_ecma_visit::{noop_fold_type, Fold, Node, Visit, VisitWith};
+
+/// Hoists the exports of top-level namespaces into module-level exports.
+///
+/// ```ts
+/// namespace N {
+///     export const x = 1;
+///     export function f() {}
+/// }
+/// ```
+///
+/// becomes
+///
+/// ```ts
+/// export const x = 1;
+/// export function f() {}
+/// ```
+///
+/// A namespace is only converted if it's used as a module boundary, i.e.
+///
+///  - it contains nothing but exported declarations,
+///  - it's not referenced anywhere, including qualified names like `N.x` in
+///    values and types,
+///  - its name is not shared with another declaration, although multiple
+///    blocks of the same namespace are converted together, and
+///  - the hoisted names don't conflict with other top-level bindings.
+///
+/// Other namespaces are left untouched.
+pub fn namespace_to_module() -> impl Fold {
+    NamespaceToModule
+}
+
+struct NamespaceToModule;
+
+impl Fold for NamespaceToModule {
+    noop_fold_type!();
+
+    fn fold_module_items(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        // Names of the namespaces and the names they export, in source order.
+        let mut namespaces: Vec<(Id, Vec<JsWord>)> = vec![];
+        // Names declared by other items, which can't be converted.
+        let mut blocked: FxHashSet<Id> = Default::default();
+        let mut bindings: FxHashSet<JsWord> = Default::default();
+
+        for item in &items {
+            match convertible_namespace(item) {
+                Some((id, block)) => {
+                    bindings.insert(id.0.clone());
+
+                    let exported = exported_names(block);
+                    match namespaces.iter_mut().find(|(ns, _)| *ns == id) {
+                        Some((_, names)) => names.extend(exported),
+                        None => namespaces.push((id, exported)),
+                    }
+                }
+                None => {
+                    for id in top_level_ids(item) {
+                        bindings.insert(id.0.clone());
+                        blocked.insert(id);
+                    }
+                }
+            }
+        }
+
+        let mut convert: FxHashSet<Id> = Default::default();
+        for (id, names) in namespaces {
+            if blocked.contains(&id) || is_referenced(&items, &id) {
+                continue;
+            }
+
+            // Merged namespace blocks may declare the same name more than once,
+            // e.g. for interfaces, so conflicts are only checked against other
+            // declarations.
+            let names: FxHashSet<JsWord> = names.into_iter().collect();
+            if names.iter().any(|name| bindings.contains(name)) {
+                continue;
+            }
+
+            bindings.extend(names);
+            convert.insert(id);
+        }
+
+        if convert.is_empty() {
+            return items;
+        }
+
+        let mut buf = Vec::with_capacity(items.len());
+        for item in items {
+            let should_convert = match convertible_namespace(&item) {
+                Some((id, _)) => convert.contains(&id),
+                None => false,
+            };
+            if !should_convert {
+                buf.push(item);
+                continue;
+            }
+
+            match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(TsModuleDecl {
+                    body: Some(TsNamespaceBody::TsModuleBlock(block)),
+                    ..
+                }))) => buf.extend(block.body),
+                _ => unreachable!(),
+            }
+        }
+
+        buf
+    }
+}
+
+/// Returns the name and the body of `item` if it's a namespace which only
+/// contains exported declarations.
+fn convertible_namespace(item: &ModuleItem) -> Option<(Id, &TsModuleBlock)> {
+    let m = match item {
+        ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(m))) if !m.declare && !m.global => m,
+        _ => return None,
+    };
+
+    let id = match &m.id {
+        TsModuleName::Ident(i) => i.to_id(),
+        TsModuleName::Str(..) => return None,
+    };
+    let block = match &m.body {
+        Some(TsNamespaceBody::TsModuleBlock(block)) => block,
+        _ => return None,
+    };
+
+    if block
+        .body
+        .iter()
+        .all(|item| matches!(item, ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(..))))
+    {
+        Some((id, block))
+    } else {
+        None
+    }
+}
+
+fn exported_names(block: &TsModuleBlock) -> Vec<JsWord> {
+    block
+        .body
+        .iter()
+        .flat_map(top_level_ids)
+        .map(|id| id.0)
+        .collect()
+}
+
+/// Returns the names declared by `item`.
+fn top_level_ids(item: &ModuleItem) -> Vec<Id> {
+    match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => import
+            .specifiers
+            .iter()
+            .map(|s| match s {
+                ImportSpecifier::Named(s) => s.local.to_id(),
+                ImportSpecifier::Default(s) => s.local.to_id(),
+                ImportSpecifier::Namespace(s) => s.local.to_id(),
+            })
+            .collect(),
+        ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import)) => vec![import.id.to_id()],
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. }))
+        | ModuleItem::Stmt(Stmt::Decl(decl)) => match decl {
+            Decl::Class(c) => vec![c.ident.to_id()],
+            Decl::Fn(f) => vec![f.ident.to_id()],
+            Decl::Var(v) => find_ids(&v.decls),
+            Decl::TsInterface(i) => vec![i.id.to_id()],
+            Decl::TsTypeAlias(a) => vec![a.id.to_id()],
+            Decl::TsEnum(e) => vec![e.id.to_id()],
+            Decl::TsModule(m) => match &m.id {
+                TsModuleName::Ident(i) => vec![i.to_id()],
+                TsModuleName::Str(..) => vec![],
+            },
+        },
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl: DefaultDecl::Class(ClassExpr { ident: Some(i), .. }),
+            ..
+        }))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl: DefaultDecl::Fn(FnExpr { ident: Some(i), .. }),
+            ..
+        })) => vec![i.to_id()],
+        _ => vec![],
+    }
+}
+
+/// Returns true if the namespace `id` is referenced anywhere in `items`.
+///
+/// The declarations of the namespace itself are ignored, but their bodies are
+/// not.
+fn is_referenced(items: &[ModuleItem], id: &Id) -> bool {
+    let mut v = RefFinder { id, found: false };
+
+    for item in items {
+        match convertible_namespace(item) {
+            Some((ns, block)) if ns == *id => {
+                block.visit_with(&Invalid { span: DUMMY_SP }, &mut v)
+            }
+            _ => item.visit_with(&Invalid { span: DUMMY_SP }, &mut v),
+        }
+    }
+
+    v.found
+}
+
+struct RefFinder<'a> {
+    id: &'a Id,
+    found: bool,
+}
+
+impl Visit for RefFinder<'_> {
+    fn visit_ident(&mut self, i: &Ident, _: &dyn Node) {
+        if i.sym == self.id.0 && i.span.ctxt() == self.id.1 {
+            self.found = true;
+        }
+    }
+
+    fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+        e.obj.visit_with(e as _, self);
+
+        if e.computed {
+            e.prop.visit_with(e as _, self);
+        }
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        if let PropName::Computed(c) = n {
+            c.visit_with(n as _, self);
+        }
+    }
+
+    fn visit_ts_qualified_name(&mut self, n: &TsQualifiedName, _: &dyn Node) {
+        n.left.visit_with(n as _, self);
+    }
+}
@@ -0,0 +1,140 @@
+use swc_atoms::js_word;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Node, Visit, VisitWith};
+
+/// Returns `true` if `module` doesn't contain any TypeScript syntax, like type
+/// annotations, `as` expressions, enums or accessibility modifiers.
+///
+/// Such a module can be used without [strip](crate::strip()), unless the
+/// unused imports should be removed like `tsc` does. The check stops at the
+/// first TypeScript node, so it's much cheaper than the transform itself.
+pub fn is_pure_js(module: &Module) -> bool {
+    let mut finder = TsFinder { found: false };
+    module.visit_with(&Invalid { span: DUMMY_SP } as _, &mut finder);
+    !finder.found
+}
+
+struct TsFinder {
+    found: bool,
+}
+
+macro_rules! found {
+    ($($name:ident($T:ty)),* $(,)?) => {
+        $(
+            fn $name(&mut self, _: &$T, _: &dyn Node) {
+                self.found = true;
+            }
+        )*
+    };
+}
+
+macro_rules! found_if {
+    ($name:ident($T:ty), |$n:ident| $cond:expr) => {
+        fn $name(&mut self, $n: &$T, _: &dyn Node) {
+            if $cond {
+                self.found = true;
+                return;
+            }
+
+            $n.visit_children_with(self);
+        }
+    };
+}
+
+macro_rules! skip_if_found {
+    ($($name:ident($T:ty)),* $(,)?) => {
+        $(
+            fn $name(&mut self, n: &$T, _: &dyn Node) {
+                if !self.found {
+                    n.visit_children_with(self);
+                }
+            }
+        )*
+    };
+}
+
+impl Visit for TsFinder {
+    found!(
+        visit_ts_type_ann(TsTypeAnn),
+        visit_ts_type_param_decl(TsTypeParamDecl),
+        visit_ts_type_param_instantiation(TsTypeParamInstantiation),
+        visit_ts_expr_with_type_args(TsExprWithTypeArgs),
+        visit_ts_as_expr(TsAsExpr),
+        visit_ts_non_null_expr(TsNonNullExpr),
+        visit_ts_type_assertion(TsTypeAssertion),
+        visit_ts_const_assertion(TsConstAssertion),
+        visit_ts_param_prop(TsParamProp),
+        visit_ts_index_signature(TsIndexSignature),
+        visit_ts_interface_decl(TsInterfaceDecl),
+        visit_ts_type_alias_decl(TsTypeAliasDecl),
+        visit_ts_enum_decl(TsEnumDecl),
+        visit_ts_module_decl(TsModuleDecl),
+        visit_ts_import_equals_decl(TsImportEqualsDecl),
+        visit_ts_export_assignment(TsExportAssignment),
+        visit_ts_namespace_export_decl(TsNamespaceExportDecl),
+    );
+
+    skip_if_found!(
+        visit_module_item(ModuleItem),
+        visit_stmt(Stmt),
+        visit_expr(Expr),
+        visit_pat(Pat),
+        visit_class_member(ClassMember),
+    );
+
+    found_if!(visit_ident(Ident), |n| n.optional);
+    found_if!(visit_array_pat(ArrayPat), |n| n.optional);
+    found_if!(visit_object_pat(ObjectPat), |n| n.optional);
+
+    // `function f(this: Foo) {}`
+    found_if!(visit_param(Param), |n| match &n.pat {
+        Pat::Ident(i) => i.id.sym == js_word!("this"),
+        _ => false,
+    });
+
+    // Overloads
+    found_if!(visit_function(Function), |n| n.body.is_none());
+
+    found_if!(visit_var_decl(VarDecl), |n| n.declare);
+    found_if!(visit_var_declarator(VarDeclarator), |n| n.definite);
+    found_if!(visit_fn_decl(FnDecl), |n| n.declare);
+    found_if!(visit_class_decl(ClassDecl), |n| n.declare);
+    found_if!(visit_class(Class), |n| n.is_abstract);
+
+    found_if!(visit_class_prop(ClassProp), |n| {
+        n.accessibility.is_some()
+            || n.is_abstract
+            || n.is_optional
+            || n.is_override
+            || n.readonly
+            || n.declare
+            || n.definite
+    });
+    found_if!(visit_private_prop(PrivateProp), |n| {
+        n.accessibility.is_some()
+            || n.is_abstract
+            || n.is_optional
+            || n.is_override
+            || n.readonly
+            || n.definite
+    });
+    found_if!(visit_class_method(ClassMethod), |n| {
+        n.accessibility.is_some() || n.is_abstract || n.is_optional || n.is_override
+    });
+    found_if!(visit_private_method(PrivateMethod), |n| {
+        n.accessibility.is_some() || n.is_abstract || n.is_optional || n.is_override
+    });
+    found_if!(visit_constructor(Constructor), |n| {
+        n.accessibility.is_some() || n.is_optional || n.body.is_none()
+    });
+
+    found_if!(visit_import_decl(ImportDecl), |n| n.import_kind.is_type());
+    found_if!(visit_import_named_specifier(ImportNamedSpecifier), |n| {
+        n.import_kind.is_type()
+    });
+    found_if!(visit_named_export(NamedExport), |n| n.export_kind.is_type());
+    found_if!(visit_export_named_specifier(ExportNamedSpecifier), |n| {
+        n.export_kind.is_type()
+    });
+}
@@ -1,10 +1,16 @@
 use fxhash::FxHashMap;
 use fxhash::FxHashSet;
 use serde::{Deserialize, Serialize};
-use std::{borrow::Borrow, mem::take};
+use std::{
+    borrow::Borrow,
+    cell::RefCell,
+    mem::{replace, take},
+    rc::Rc,
+};
 use swc_atoms::{js_word, JsWord};
 use swc_common::{util::move_map::MoveMap, Span, Spanned, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::*;
+use swc_ecma_parser::TsSyntaxPositions;
 use swc_ecma_transforms_base::ext::MapWithMut;
 use swc_ecma_utils::member_expr;
 use swc_ecma_utils::private_ident;
@@ -37,6 +43,42 @@ impl Default for ImportsNotUsedAsValues {
     }
 }
 
+/// How enums are lowered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum EnumMode {
+    /// ```js
+    /// var E;
+    /// (function (E) {
+    ///     E[E["A"] = 0] = "A";
+    /// })(E || (E = {}));
+    /// ```
+    #[serde(rename = "iife")]
+    Iife,
+    /// A constant object without reverse mappings, which is the runtime part
+    /// of
+    ///
+    /// ```ts
+    /// const E = { A: 0 } as const;
+    /// type E = (typeof E)[keyof typeof E];
+    /// ```
+    ///
+    /// The declaration has no side effects, so bundlers can remove unused
+    /// enums.
+    ///
+    /// Enums with members which can't be computed at compile time and merged
+    /// enums are lowered like [EnumMode::Iife].
+    #[serde(rename = "constObjectAndType")]
+    ConstObjectAndType,
+}
+
+/// This value defaults to `Iife`
+impl Default for EnumMode {
+    fn default() -> Self {
+        Self::Iife
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Config {
     #[serde(default)]
@@ -58,6 +100,9 @@ pub struct Config {
     /// get this backward-compatible output.
     #[serde(default)]
     pub use_define_for_class_fields: bool,
+
+    #[serde(default)]
+    pub enum_mode: EnumMode,
 }
 
 pub fn strip_with_config(config: Config) -> impl Fold {
@@ -67,11 +112,40 @@ pub fn strip_with_config(config: Config) -> impl Fold {
     })
 }
 
+/// Same as [strip_with_config], but statements which don't contain any
+/// typescript syntax according to `ts_syntax` are not visited.
+///
+/// `ts_syntax` should be obtained from the parser which produced the module,
+/// using [swc_ecma_parser::Parser::take_ts_syntax].
+pub fn strip_with_ts_syntax(config: Config, ts_syntax: TsSyntaxPositions) -> impl Fold {
+    as_folder(Strip {
+        config,
+        ts_syntax: Some(ts_syntax),
+        ..Default::default()
+    })
+}
+
 /// Strips type annotations out.
 pub fn strip() -> impl Fold {
     strip_with_config(Default::default())
 }
 
+/// Same as [strip], but also returns the names of import bindings removed
+/// because they are type-only or not used as values.
+///
+/// The set is filled when the returned pass is applied, so a bundler can use
+/// it afterwards to prune imports which became unused.
+pub fn strip_with_removed() -> (impl Fold, Rc<RefCell<FxHashSet<JsWord>>>) {
+    let removed: Rc<RefCell<FxHashSet<JsWord>>> = Default::default();
+
+    let pass = as_folder(Strip {
+        removed: removed.clone(),
+        ..Default::default()
+    });
+
+    (pass, removed)
+}
+
 #[derive(Default)]
 struct Strip {
     config: Config,
@@ -90,10 +164,28 @@ struct Strip {
     ///
     /// This field is filled by [Visit] impl and [VisitMut] impl.
     decl_names: FxHashSet<Id>,
+    /// Names of enums declared more than once in the statements being
+    /// visited. The declarations are merged, so they are never lowered to a
+    /// constant object.
+    merged_enums: FxHashSet<Id>,
     in_var_pat: bool,
+
+    /// Names of removed import bindings.
+    removed: Rc<RefCell<FxHashSet<JsWord>>>,
+
+    /// Positions of typescript syntax recorded by the parser.
+    ts_syntax: Option<TsSyntaxPositions>,
 }
 
 impl Strip {
+    /// Returns `false` if the parser didn't see typescript syntax in `span`.
+    fn may_contain_ts(&self, span: Span) -> bool {
+        match self.ts_syntax {
+            Some(ref ts_syntax) => span.is_dummy() || ts_syntax.contains_ts(span),
+            None => true,
+        }
+    }
+
     /// Creates an uninitialized variable if `name` is not in scope.
     fn create_uninit_var(&mut self, span: Span, name: Id) -> Option<VarDeclarator> {
         if !self.decl_names.insert(name.clone()) {
@@ -110,6 +202,15 @@ impl Strip {
             definite: false,
         })
     }
+
+    fn record_removed_import(&self, s: &ImportSpecifier) {
+        let local = match s {
+            ImportSpecifier::Named(s) => &s.local,
+            ImportSpecifier::Default(s) => &s.local,
+            ImportSpecifier::Namespace(s) => &s.local,
+        };
+        self.removed.borrow_mut().insert(local.sym.clone());
+    }
 }
 
 #[derive(Default)]
@@ -207,28 +308,12 @@ impl Strip {
                         for param in &constructor.params {
                             match param {
                                 ParamOrTsParamProp::TsParamProp(param_prop) => {
-                                    let ident = match &param_prop.param {
-                                        TsParamPropParam::Ident(ident) => ident.id.clone(),
-                                        TsParamPropParam::Assign(pat) => {
-                                            pat.clone().left.ident().unwrap().id
-                                        }
-                                    };
-                                    let param_class_field = ClassMember::ClassProp(ClassProp {
-                                        span: class.span,
-                                        key: Box::new(Expr::Ident(ident)),
-                                        value: None,
-                                        type_ann: None,
-                                        is_static: false,
-                                        decorators: param_prop.decorators.clone(),
-                                        computed: false,
-                                        accessibility: param_prop.accessibility.clone(),
-                                        is_abstract: false,
-                                        is_optional: false,
-                                        is_override: false,
-                                        readonly: param_prop.readonly,
-                                        declare: false,
-                                        definite: false,
-                                    });
+                                    let ident = param_prop.param.binding().id.clone();
+                                    let (mut prop, _) = param_prop.expand(ident);
+                                    prop.span = class.span;
+                                    prop.type_ann = None;
+                                    prop.is_override = false;
+                                    let param_class_field = ClassMember::ClassProp(prop);
                                     param_class_fields.push(param_class_field);
                                 }
                                 _ => {}
@@ -431,10 +516,11 @@ impl Strip {
                                                 js_word!("default"),
                                                 DUMMY_SP,
                                             )),
+                                            export_kind: ExportKind::Value,
                                         }
                                         .into()],
                                         src: None,
-                                        type_only: false,
+                                        export_kind: ExportKind::Value,
                                         asserts: None,
                                     },
                                 )) {
@@ -562,10 +648,8 @@ impl Strip {
         vec![]
     }
 
-    fn handle_enum<T>(&mut self, e: TsEnumDecl, stmts: &mut Vec<T>)
-    where
-        T: StmtLike,
-    {
+    /// Computes the values of the members of an enum.
+    fn enum_member_values(e: &TsEnumDecl) -> Vec<(TsEnumMember, Expr)> {
         /// Called only for enums.
         ///
         /// If both of the default value and the initialization is None, this
@@ -718,18 +802,15 @@ impl Strip {
             Err(())
         }
 
-        let id = e.id.clone();
-
         let mut default = 0;
         let mut values = Default::default();
-        let members = e
-            .members
+        e.members
             .clone()
             .into_iter()
             .map(|m| -> Result<_, ()> {
                 let id_span = m.id.span();
                 let val = compute(
-                    &e,
+                    e,
                     id_span,
                     &mut values,
                     Some(default),
@@ -768,7 +849,67 @@ impl Strip {
                 Ok((m, val))
             })
             .collect::<Result<Vec<_>, _>>()
-            .unwrap_or_else(|_| panic!("invalid value for enum is detected"));
+            .unwrap_or_else(|_| panic!("invalid value for enum is detected"))
+    }
+
+    /// Lowers `e` to a constant object if [EnumMode::ConstObjectAndType] is
+    /// used, and returns `e` as is otherwise.
+    ///
+    /// Enums with members which can't be computed, or which are merged with
+    /// another declaration, are also returned as is.
+    fn enum_to_const_object(&mut self, e: TsEnumDecl) -> Result<VarDecl, TsEnumDecl> {
+        if self.config.enum_mode != EnumMode::ConstObjectAndType {
+            return Err(e);
+        }
+
+        let members = Self::enum_member_values(&e);
+        if members.iter().any(|(_, val)| !val.is_lit())
+            || self.merged_enums.contains(&e.id.to_id())
+            || !self.decl_names.insert(e.id.to_id())
+        {
+            return Err(e);
+        }
+
+        let props = members
+            .into_iter()
+            .map(|(m, val)| {
+                let key = match m.id {
+                    TsEnumMemberId::Ident(i) => PropName::Ident(Ident::new(
+                        i.sym,
+                        i.span.with_ctxt(SyntaxContext::empty()),
+                    )),
+                    TsEnumMemberId::Str(s) => PropName::Str(s),
+                };
+
+                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key,
+                    value: Box::new(val),
+                })))
+            })
+            .collect();
+
+        Ok(VarDecl {
+            span: e.span,
+            kind: VarDeclKind::Const,
+            declare: false,
+            decls: vec![VarDeclarator {
+                span: e.id.span,
+                name: Pat::Ident(e.id.into()),
+                init: Some(Box::new(Expr::Object(ObjectLit {
+                    span: DUMMY_SP,
+                    props,
+                }))),
+                definite: false,
+            }],
+        })
+    }
+
+    fn handle_enum<T>(&mut self, e: TsEnumDecl, stmts: &mut Vec<T>)
+    where
+        T: StmtLike,
+    {
+        let id = e.id.clone();
+        let members = Self::enum_member_values(&e);
 
         let is_all_str = members.iter().all(|(_, v)| match v {
             Expr::Lit(Lit::Str(..)) => true,
@@ -805,6 +946,7 @@ impl Strip {
                                 .into_iter()
                                 .enumerate()
                                 .map(|(_, (m, val))| {
+                                    let span = m.span;
                                     let value = match m.id {
                                         TsEnumMemberId::Str(s) => s,
                                         TsEnumMemberId::Ident(i) => Str {
@@ -837,8 +979,12 @@ impl Strip {
                                     };
 
                                     // Foo[Foo["a"] = 0] = "a";
+                                    //
+                                    // The span of the member is used so that
+                                    // comments attached to the member are
+                                    // emitted before the assignment.
                                     AssignExpr {
-                                        span: DUMMY_SP,
+                                        span,
                                         left: PatOrExpr::Expr(Box::new(Expr::Member(MemberExpr {
                                             obj: id.clone().as_obj(),
                                             span: DUMMY_SP,
@@ -1156,6 +1302,13 @@ impl Visit for Strip {
         n.visit_children_with(self);
     }
 
+    fn visit_export_named_specifier(&mut self, n: &ExportNamedSpecifier, _: &dyn Node) {
+        let old = self.is_type_only_export;
+        self.is_type_only_export |= n.export_kind.is_type();
+        n.visit_children_with(self);
+        self.is_type_only_export = old;
+    }
+
     fn visit_decl(&mut self, n: &Decl, _: &dyn Node) {
         self.handle_decl(n);
 
@@ -1209,7 +1362,7 @@ impl Visit for Strip {
         self.non_top_level = false;
         n.iter().for_each(|n| {
             if let ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) = n {
-                self.is_type_only_export = export.type_only;
+                self.is_type_only_export = export.export_kind.is_type();
             }
             n.visit_with(&Invalid { span: DUMMY_SP }, self);
             self.is_type_only_export = false;
@@ -1296,46 +1449,12 @@ impl VisitMut for Strip {
             params.move_map(|param| match param {
                 ParamOrTsParamProp::Param(..) => param,
                 ParamOrTsParamProp::TsParamProp(param) => {
-                    let (ident, param) = match param.param {
-                        TsParamPropParam::Ident(i) => (
-                            i.clone(),
-                            Param {
-                                span: DUMMY_SP,
-                                decorators: Default::default(),
-                                pat: Pat::Ident(i),
-                            },
-                        ),
-                        TsParamPropParam::Assign(AssignPat {
-                            span, left, right, ..
-                        }) if left.is_ident() => {
-                            let i = left.ident().unwrap();
-
-                            (
-                                i.clone(),
-                                Param {
-                                    span: DUMMY_SP,
-                                    decorators: Default::default(),
-                                    pat: Pat::Assign(AssignPat {
-                                        span,
-                                        left: Box::new(Pat::Ident(i)),
-                                        right,
-                                        type_ann: None,
-                                    }),
-                                },
-                            )
-                        }
-                        _ => unreachable!("destructuring pattern inside TsParameterProperty"),
-                    };
-                    let assign_expr = Box::new(Expr::Assign(AssignExpr {
-                        span: DUMMY_SP,
-                        left: PatOrExpr::Expr(Box::new(
-                            ThisExpr { span: DUMMY_SP }.make_member(ident.id.clone()),
-                        )),
-                        op: op!("="),
-                        right: Box::new(Expr::Ident(ident.id)),
-                    }));
-                    assign_exprs.push(assign_expr);
+                    let ident = param.param.binding().id.clone();
+                    let (_, assign) = param.expand(ident.clone());
+                    assign_exprs.push(assign.expr().unwrap().expr);
 
+                    let mut param = param.to_param(ident);
+                    param.span = DUMMY_SP;
                     ParamOrTsParamProp::Param(param)
                 }
             })
@@ -1395,29 +1514,41 @@ impl VisitMut for Strip {
     fn visit_mut_import_decl(&mut self, import: &mut ImportDecl) {
         self.is_side_effect_import = import.specifiers.is_empty();
 
-        import.specifiers.retain(|s| match *s {
-            ImportSpecifier::Default(ImportDefaultSpecifier { ref local, .. })
-            | ImportSpecifier::Named(ImportNamedSpecifier { ref local, .. })
-            | ImportSpecifier::Namespace(ImportStarAsSpecifier { ref local, .. }) => {
-                // If the import is shadowed by a concrete local declaration, TSC
-                // assumes the import is a type and removes it.
-                let decl = self.scope.decls.get(&local.to_id());
-                match decl {
-                    Some(&DeclInfo {
-                        has_concrete: true, ..
-                    }) => return false,
-                    _ => {}
-                }
-                // If no shadowed declaration, check if the import is referenced.
-                let entry = self.scope.referenced_idents.get(&local.to_id());
-                match entry {
-                    Some(&DeclInfo {
-                        has_concrete: false,
-                        ..
-                    }) => false,
-                    _ => true,
+        import.specifiers.retain(|s| {
+            let keep = match *s {
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                    import_kind: ImportKind::Type,
+                    ..
+                }) => false,
+                ImportSpecifier::Default(ImportDefaultSpecifier { ref local, .. })
+                | ImportSpecifier::Named(ImportNamedSpecifier { ref local, .. })
+                | ImportSpecifier::Namespace(ImportStarAsSpecifier { ref local, .. }) => {
+                    // If the import is shadowed by a concrete local declaration, TSC
+                    // assumes the import is a type and removes it.
+                    let decl = self.scope.decls.get(&local.to_id());
+                    match decl {
+                        Some(&DeclInfo {
+                            has_concrete: true, ..
+                        }) => false,
+                        // If no shadowed declaration, check if the import is referenced.
+                        _ => {
+                            let entry = self.scope.referenced_idents.get(&local.to_id());
+                            match entry {
+                                Some(&DeclInfo {
+                                    has_concrete: false,
+                                    ..
+                                }) => false,
+                                _ => true,
+                            }
+                        }
+                    }
                 }
+            };
+
+            if !keep {
+                self.record_removed_import(s);
             }
+            keep
         });
 
         if import.specifiers.is_empty() && !self.is_side_effect_import {
@@ -1436,11 +1567,13 @@ impl VisitMut for Strip {
     fn visit_mut_private_prop(&mut self, prop: &mut PrivateProp) {
         prop.visit_mut_children_with(self);
         prop.readonly = false;
+        prop.definite = false;
     }
 
     fn visit_mut_class_prop(&mut self, prop: &mut ClassProp) {
         prop.visit_mut_children_with(self);
         prop.readonly = false;
+        prop.definite = false;
     }
 
     fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
@@ -1467,6 +1600,11 @@ impl VisitMut for Strip {
     fn visit_mut_stmts(&mut self, orig: &mut Vec<Stmt>) {
         self.visit_mut_stmt_like(orig);
         // Second pass
+        let merged_enums = merged_enums(orig.iter().filter_map(|stmt| match stmt {
+            Stmt::Decl(decl) => Some(decl),
+            _ => None,
+        }));
+        let old_merged_enums = replace(&mut self.merged_enums, merged_enums);
         let mut stmts = Vec::with_capacity(orig.len());
         for mut item in take(orig) {
             self.is_side_effect_import = false;
@@ -1483,6 +1621,14 @@ impl VisitMut for Strip {
                 }
 
                 Stmt::Decl(Decl::TsEnum(e)) => {
+                    let e = match self.enum_to_const_object(e) {
+                        Ok(decl) => {
+                            stmts.push(Stmt::Decl(Decl::Var(decl)));
+                            continue;
+                        }
+                        Err(e) => e,
+                    };
+
                     // var Foo;
                     // (function (Foo) {
                     //     Foo[Foo["a"] = 0] = "a";
@@ -1508,12 +1654,15 @@ impl VisitMut for Strip {
                 | Stmt::Decl(Decl::TsTypeAlias(..)) => continue,
 
                 _ => {
-                    item.visit_mut_with(self);
+                    if self.may_contain_ts(item.span()) {
+                        item.visit_mut_with(self);
+                    }
                     stmts.push(item);
                 }
             };
         }
 
+        self.merged_enums = old_merged_enums;
         *orig = stmts
     }
 
@@ -1597,6 +1746,12 @@ impl VisitMut for Strip {
         self.visit_mut_stmt_like(items);
         items.visit_with(&Invalid { span: DUMMY_SP }, self);
 
+        let merged_enums = merged_enums(items.iter().filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. }))
+            | ModuleItem::Stmt(Stmt::Decl(decl)) => Some(decl),
+            _ => None,
+        }));
+        let old_merged_enums = replace(&mut self.merged_enums, merged_enums);
         let mut stmts = Vec::with_capacity(items.len());
         for mut item in take(items) {
             self.is_side_effect_import = false;
@@ -1707,12 +1862,19 @@ impl VisitMut for Strip {
                 }
 
                 // Always strip type only import / exports
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    import_kind: ImportKind::Type,
+                    ref specifiers,
+                    ..
+                })) => {
+                    specifiers
+                        .iter()
+                        .for_each(|s| self.record_removed_import(s));
+                    continue;
+                }
                 ModuleItem::Stmt(Stmt::Empty(..))
-                | ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-                    type_only: true, ..
-                }))
                 | ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
-                    type_only: true,
+                    export_kind: ExportKind::Type,
                     ..
                 })) => continue,
 
@@ -1728,6 +1890,19 @@ impl VisitMut for Strip {
                     decl: Decl::TsEnum(e),
                     ..
                 })) => {
+                    let e = match self.enum_to_const_object(e) {
+                        Ok(decl) => {
+                            stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(
+                                ExportDecl {
+                                    span: decl.span,
+                                    decl: Decl::Var(decl),
+                                },
+                            )));
+                            continue;
+                        }
+                        Err(e) => e,
+                    };
+
                     if let Some(var) = self.create_uninit_var(e.span, e.id.to_id()) {
                         stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
                             span: e.span,
@@ -1743,6 +1918,14 @@ impl VisitMut for Strip {
                     self.handle_enum(e, &mut stmts)
                 }
                 ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum(e))) => {
+                    let e = match self.enum_to_const_object(e) {
+                        Ok(decl) => {
+                            stmts.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(decl))));
+                            continue;
+                        }
+                        Err(e) => e,
+                    };
+
                     // var Foo;
                     // (function (Foo) {
                     //     Foo[Foo["a"] = 0] = "a";
@@ -1817,10 +2000,14 @@ impl VisitMut for Strip {
                 ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(mut export)) => {
                     // if specifier become empty, we remove export statement.
 
-                    if export.type_only {
+                    if export.export_kind.is_type() {
                         export.specifiers.clear();
                     }
                     export.specifiers.retain(|s| match *s {
+                        ExportSpecifier::Named(ExportNamedSpecifier {
+                            export_kind: ExportKind::Type,
+                            ..
+                        }) => false,
                         ExportSpecifier::Named(ExportNamedSpecifier { ref orig, .. }) => {
                             if let Some(e) =
                                 self.scope.decls.get(&(orig.sym.clone(), orig.span.ctxt()))
@@ -1841,6 +2028,8 @@ impl VisitMut for Strip {
                     )))
                 }
 
+                ModuleItem::Stmt(ref stmt) if !self.may_contain_ts(stmt.span()) => stmts.push(item),
+
                 _ => {
                     item.visit_mut_with(self);
                     stmts.push(item)
@@ -1848,6 +2037,7 @@ impl VisitMut for Strip {
             };
         }
 
+        self.merged_enums = old_merged_enums;
         *items = stmts;
     }
 
@@ -1859,26 +2049,23 @@ impl VisitMut for Strip {
 
 fn module_ref_to_expr(r: TsModuleRef) -> Expr {
     match r {
-        TsModuleRef::TsEntityName(name) => ts_entity_name_to_expr(name),
+        TsModuleRef::TsEntityName(name) => name.into(),
         _ => unimplemented!("export import A = B where B != TsEntityName\nB: {:?}", r),
     }
 }
 
-fn ts_entity_name_to_expr(n: TsEntityName) -> Expr {
-    match n {
-        TsEntityName::Ident(i) => i.into(),
-        TsEntityName::TsQualifiedName(q) => {
-            let TsQualifiedName { left, right } = *q;
-
-            MemberExpr {
-                span: DUMMY_SP,
-                obj: ExprOrSuper::Expr(Box::new(ts_entity_name_to_expr(left))),
-                prop: Box::new(right.into()),
-                computed: false,
+/// Returns the names of enums which are declared more than once in `decls`.
+fn merged_enums<'a>(decls: impl Iterator<Item = &'a Decl>) -> FxHashSet<Id> {
+    let mut seen = FxHashSet::default();
+    let mut merged = FxHashSet::default();
+    for decl in decls {
+        if let Decl::TsEnum(e) = decl {
+            if !seen.insert(e.id.to_id()) {
+                merged.insert(e.id.to_id());
             }
-            .into()
         }
     }
+    merged
 }
 
 fn create_prop_pat(obj: &Ident, pat: Pat) -> Pat {
@@ -0,0 +1,134 @@
+use swc_ecma_parser::{Syntax, TsConfig};
+use swc_ecma_transforms_testing::test;
+use swc_ecma_transforms_typescript::inline_type_aliases;
+use swc_ecma_visit::Fold;
+
+fn syntax() -> Syntax {
+    Syntax::Typescript(TsConfig {
+        ..Default::default()
+    })
+}
+
+fn tr() -> impl Fold {
+    inline_type_aliases()
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    inline_single_use,
+    r#"
+type T = string | number;
+let x: T;
+"#,
+    r#"
+let x: string | number;
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    inline_single_use_with_parens,
+    r#"
+type T = string | number;
+let x: T[];
+"#,
+    r#"
+let x: (string | number)[];
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    inline_chain,
+    r#"
+type A = string;
+type B = A | number;
+let x: B;
+"#,
+    r#"
+let x: string | number;
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    keep_multi_use,
+    r#"
+type T = string | number;
+let x: T;
+let y: T;
+"#,
+    r#"
+type T = string | number;
+let x: T;
+let y: T;
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    keep_generic,
+    r#"
+type T<U> = U | number;
+let x: T<string>;
+"#,
+    r#"
+type T<U> = U | number;
+let x: T<string>;
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    keep_exported,
+    r#"
+type T = string | number;
+let x: T;
+export { T };
+"#,
+    r#"
+type T = string | number;
+let x: T;
+export { T };
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    keep_shadowed_by_alias,
+    r#"
+type T = string;
+function foo() {
+    type T = number;
+    let x: T;
+}
+"#,
+    r#"
+type T = string;
+function foo() {
+    type T = number;
+    let x: T;
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    keep_shadowed_by_type_param,
+    r#"
+type T = string;
+function foo<T>(x: T) {}
+"#,
+    r#"
+type T = string;
+function foo<T>(x: T) {}
+"#
+);
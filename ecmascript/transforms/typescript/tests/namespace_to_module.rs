@@ -0,0 +1,128 @@
+use swc_ecma_parser::{Syntax, TsConfig};
+use swc_ecma_transforms_testing::test;
+use swc_ecma_transforms_typescript::namespace_to_module;
+use swc_ecma_visit::Fold;
+
+fn syntax() -> Syntax {
+    Syntax::Typescript(TsConfig {
+        ..Default::default()
+    })
+}
+
+fn tr() -> impl Fold {
+    namespace_to_module()
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    convert_exports,
+    r#"
+namespace N {
+    export const x = 1;
+    export function f() {
+        return x;
+    }
+}
+"#,
+    r#"
+export const x = 1;
+export function f() {
+    return x;
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    convert_merged_blocks,
+    r#"
+namespace N {
+    export interface Foo {}
+}
+namespace N {
+    export class Bar implements Foo {}
+}
+"#,
+    r#"
+export interface Foo {}
+export class Bar implements Foo {}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    bail_qualified_value,
+    r#"
+namespace N {
+    export const x = 1;
+}
+console.log(N.x);
+"#,
+    r#"
+namespace N {
+    export const x = 1;
+}
+console.log(N.x);
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    bail_qualified_type,
+    r#"
+namespace N {
+    export interface Foo {}
+}
+let foo: N.Foo;
+"#,
+    r#"
+namespace N {
+    export interface Foo {}
+}
+let foo: N.Foo;
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    bail_not_exported,
+    r#"
+namespace N {
+    const x = 1;
+    export function f() {
+        return x;
+    }
+}
+"#,
+    r#"
+namespace N {
+    const x = 1;
+    export function f() {
+        return x;
+    }
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    bail_conflict,
+    r#"
+const x = 2;
+namespace N {
+    export const x = 1;
+}
+"#,
+    r#"
+const x = 2;
+namespace N {
+    export const x = 1;
+}
+"#
+);
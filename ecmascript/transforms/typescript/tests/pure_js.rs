@@ -0,0 +1,129 @@
+use swc_common::FileName;
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+use swc_ecma_transforms_typescript::{
+    is_pure_js, strip,
+    strip::{strip_with_ts_syntax, Config},
+};
+use swc_ecma_visit::FoldWith;
+
+fn check(src: &str) -> bool {
+    ::testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let lexer = Lexer::new(
+            Syntax::Typescript(Default::default()),
+            Default::default(),
+            StringInput::from(&*fm),
+            None,
+        );
+        let mut parser = Parser::new_from(lexer);
+        let module = parser.parse_module().map_err(|_| ()).unwrap();
+
+        Ok(is_pure_js(&module))
+    })
+    .unwrap()
+}
+
+#[test]
+fn pure_js() {
+    assert!(check(
+        "import foo from 'foo';
+        export class A extends foo {
+            #x = 1;
+            static y;
+            constructor(a, { b }, ...c) {
+                super(a, b, c);
+            }
+            get x() {
+                return this.#x;
+            }
+        }
+        export default function (a = 1) {
+            return a?.b ?? (a, [a]);
+        }"
+    ));
+}
+
+#[test]
+fn type_annotations() {
+    assert!(!check("let a: string;"));
+    assert!(!check("function f(a = 1): void {}"));
+    assert!(!check("const f = <T>(a) => a;"));
+    assert!(!check("function f(a?) {}"));
+}
+
+#[test]
+fn type_declarations() {
+    assert!(!check("interface A {}"));
+    assert!(!check("type A = string;"));
+    assert!(!check("enum A { B }"));
+    assert!(!check("namespace A {}"));
+    assert!(!check("declare const a;"));
+    assert!(!check("function f();\nfunction f() {}"));
+}
+
+#[test]
+fn expressions() {
+    assert!(!check("a as any;"));
+    assert!(!check("a!;"));
+    assert!(!check("f<string>();"));
+    assert!(!check("a = { b: c as const };"));
+}
+
+#[test]
+fn class_members() {
+    assert!(!check("class A { private a = 1; }"));
+    assert!(!check("class A { readonly a = 1; }"));
+    assert!(!check("class A { a!; }"));
+    assert!(!check("abstract class A {}"));
+    assert!(!check("class A { constructor(public a) {} }"));
+    assert!(!check("class A implements B {}"));
+}
+
+#[test]
+fn type_only_imports() {
+    assert!(!check("import type { A } from 'a';"));
+    assert!(!check("export type { A } from 'a';"));
+    assert!(!check("import a = require('a');"));
+    assert!(!check("export = a;"));
+}
+
+#[test]
+fn strip_with_ts_syntax_matches_strip() {
+    ::testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(
+            FileName::Anon,
+            "import { A, b } from 'a';
+            function f(x) {
+                if (x) {
+                    return g(x as A);
+                }
+                return b(x);
+            }
+            function g(y?: number) {
+                let z!: string;
+                return [y, z];
+            }
+            enum E { C }
+            class H { i = 1; j; }
+            const k = (l) => l!.m;"
+                .into(),
+        );
+        let lexer = Lexer::new(
+            Syntax::Typescript(Default::default()),
+            Default::default(),
+            StringInput::from(&*fm),
+            None,
+        );
+        let mut parser = Parser::new_from(lexer);
+        let module = parser.parse_module().map_err(|_| ()).unwrap();
+        let ts_syntax = parser.take_ts_syntax();
+        assert!(ts_syntax.has_ts());
+
+        let expected = module.clone().fold_with(&mut strip());
+        let actual = module.fold_with(&mut strip_with_ts_syntax(Config::default(), ts_syntax));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    })
+    .unwrap();
+}
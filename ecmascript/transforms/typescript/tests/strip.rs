@@ -1,5 +1,6 @@
-use swc_common::chain;
-use swc_ecma_parser::{Syntax, TsConfig};
+use swc_common::{chain, comments::SingleThreadedComments, FileName};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_parser::{Parser, StringInput, Syntax, TsConfig};
 use swc_ecma_transforms_base::resolver::resolver;
 use swc_ecma_transforms_compat::es2017::async_to_generator;
 use swc_ecma_transforms_compat::es2020::class_properties;
@@ -9,8 +10,8 @@ use swc_ecma_transforms_proposal::decorators;
 use swc_ecma_transforms_testing::test;
 use swc_ecma_transforms_testing::test_exec;
 use swc_ecma_transforms_typescript::strip;
-use swc_ecma_transforms_typescript::strip::strip_with_config;
-use swc_ecma_visit::Fold;
+use swc_ecma_transforms_typescript::strip::{strip_with_config, strip_with_removed};
+use swc_ecma_visit::{Fold, FoldWith};
 
 fn tr() -> impl Fold {
     strip()
@@ -2005,7 +2006,7 @@ export default (identifier: string, level = 0, b = "", m = false) => {
     const proseTypes = new Map();
 
     // deno-lint-ignore ban-types
-    const prose = (l, i, nl, bp): string => {
+    const prose = (l, i, nl, bp) => {
         return i(l) + bp + "prose {" + nl +
             i(l + 1) + "color: #374151;" + nl +
             i(l + 1) + "max-width: 65ch;" + nl +
@@ -2298,7 +2299,7 @@ export default (identifier: string, level = 0, b = "", m = false) => {
     proseTypes.set("prose", prose);
 
     // deno-lint-ignore ban-types
-    const proseSm = (l, i, nl, bp): string => {
+    const proseSm = (l, i, nl, bp) => {
         return i(l) + bp + "prose-sm {" + nl +
             i(l + 1) + "font-size: 0.875rem;" + nl +
             i(l + 1) + "line-height: 1.7142857;" + nl +
@@ -2486,7 +2487,7 @@ export default (identifier: string, level = 0, b = "", m = false) => {
     proseTypes.set("prose-sm", proseSm);
 
     // deno-lint-ignore ban-types
-    const proseLg = (l, i, nl, bp): string => {
+    const proseLg = (l, i, nl, bp) => {
         return i(l) + bp + "prose-lg {" + nl +
             i(l + 1) + "font-size: 1.125rem;" + nl +
             i(l + 1) + "line-height: 1.7777778;" + nl +
@@ -2674,7 +2675,7 @@ export default (identifier: string, level = 0, b = "", m = false) => {
     proseTypes.set("prose-lg", proseLg);
 
     // deno-lint-ignore ban-types
-    const proseXl = (l, i, nl, bp): string => {
+    const proseXl = (l, i, nl, bp) => {
         return i(l) + bp + "prose-xl {" + nl +
             i(l + 1) + "font-size: 1.25rem;" + nl +
             i(l + 1) + "line-height: 1.8;" + nl +
@@ -3976,3 +3977,259 @@ to!(
     console.log({ foo: 1 });
     "
 );
+
+#[test]
+fn enum_member_comments() {
+    let output = ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(
+            FileName::Anon,
+            "enum E {
+    /** doc */
+    A = 1,
+}"
+            .into(),
+        );
+        let comments = SingleThreadedComments::default();
+        let module = Parser::new(
+            Syntax::Typescript(Default::default()),
+            StringInput::from(&*fm),
+            Some(&comments),
+        )
+        .parse_module()
+        .map_err(|e| e.into_diagnostic(handler).emit())?
+        .fold_with(&mut tr());
+
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: Default::default(),
+                comments: Some(&comments),
+                wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+                cm,
+            };
+            emitter.emit_module(&module).unwrap();
+        }
+
+        Ok(String::from_utf8(buf).unwrap())
+    })
+    .unwrap();
+
+    assert!(
+        output.contains("/** doc */ E[E[\"A\"] = 1] = \"A\";"),
+        "doc comment should be emitted before the assignment:\n{}",
+        output
+    );
+}
+
+to!(
+    conditional_type_multiple_infer,
+    "
+    type Pair<T> = T extends [infer A, infer B] ? [A, B] : never;
+    export const pair: Pair<[number, string]> = [1, 'a'];
+    ",
+    "
+    export const pair = [1, 'a'];
+    "
+);
+
+to!(
+    definite_assignment,
+    "
+    let x!: number;
+    class A {
+        static y!: string;
+        #z!: boolean;
+    }
+    ",
+    "
+    let x;
+    class A {
+        #z;
+    }
+    "
+);
+
+test!(
+    Syntax::Typescript(TsConfig {
+        tsx: true,
+        ..Default::default()
+    }),
+    |_| tr(),
+    jsx_type_args,
+    "const el = <Foo<string> prop={x} />;",
+    "const el = <Foo prop={x} />;",
+    ok_if_code_eq
+);
+
+to!(
+    type_only_specifiers,
+    "
+    import { type A, B, type C as D } from './a';
+    import { type E } from './e';
+    export { type A, B };
+    export { type D };
+    console.log(B);
+    ",
+    "
+    import { B } from './a';
+    export { B };
+    console.log(B);
+    "
+);
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |_| {
+        let mut config = strip::Config::default();
+        config.import_not_used_as_values = strip::ImportsNotUsedAsValues::Preserve;
+        strip_with_config(config)
+    },
+    type_only_specifiers_preserve,
+    "
+    import { type A } from './a';
+    ",
+    "
+    import './a';
+    "
+);
+
+#[test]
+fn strip_with_removed_records_type_imports() {
+    ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(
+            FileName::Anon,
+            "import type { T } from 'm';
+import { v } from 'm';
+console.log(v);"
+                .into(),
+        );
+        let module = Parser::new(
+            Syntax::Typescript(Default::default()),
+            StringInput::from(&*fm),
+            None,
+        )
+        .parse_module()
+        .map_err(|e| e.into_diagnostic(handler).emit())?;
+
+        let (mut pass, removed) = strip_with_removed();
+        let module = module.fold_with(&mut pass);
+
+        assert_eq!(module.body.len(), 2);
+        let removed = removed.borrow();
+        assert!(removed.contains(&"T".into()), "{:?}", removed);
+        assert!(!removed.contains(&"v".into()), "{:?}", removed);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+to!(
+    unique_symbol_const,
+    "
+    const x: unique symbol = Symbol();
+    declare const y: unique symbol;
+    ",
+    "
+    const x = Symbol();
+    "
+);
+
+test_with_config!(
+    enum_const_object,
+    strip::Config {
+        enum_mode: strip::EnumMode::ConstObjectAndType,
+        ..Default::default()
+    },
+    "
+    enum Direction {
+        Up,
+        Down = 4,
+        Left,
+    }
+    export enum State {
+        Open = 'open',
+        'Closed' = 'closed',
+    }
+    ",
+    "
+    const Direction = {
+        Up: 0,
+        Down: 4,
+        Left: 5
+    };
+    export const State = {
+        Open: 'open',
+        'Closed': 'closed'
+    };
+    "
+);
+
+test_with_config!(
+    enum_iife,
+    strip::Config {
+        enum_mode: strip::EnumMode::Iife,
+        ..Default::default()
+    },
+    "
+    enum Direction {
+        Up,
+        Down = 4,
+        Left,
+    }
+    ",
+    "
+    var Direction;
+    (function(Direction) {
+        Direction[Direction['Up'] = 0] = 'Up';
+        Direction[Direction['Down'] = 4] = 'Down';
+        Direction[Direction['Left'] = 5] = 'Left';
+    })(Direction || (Direction = {}));
+    "
+);
+
+test_with_config!(
+    enum_const_object_computed_member,
+    strip::Config {
+        enum_mode: strip::EnumMode::ConstObjectAndType,
+        ..Default::default()
+    },
+    "
+    enum E {
+        A = foo(),
+    }
+    ",
+    "
+    var E;
+    (function(E) {
+        E[E['A'] = foo()] = 'A';
+    })(E || (E = {}));
+    "
+);
+
+test_with_config!(
+    enum_const_object_merged,
+    strip::Config {
+        enum_mode: strip::EnumMode::ConstObjectAndType,
+        ..Default::default()
+    },
+    "
+    enum Direction {
+        Up,
+        Down,
+    }
+    enum Direction {
+        Left = 2,
+    }
+    ",
+    "
+    var Direction;
+    (function(Direction) {
+        Direction[Direction['Up'] = 0] = 'Up';
+        Direction[Direction['Down'] = 1] = 'Down';
+    })(Direction || (Direction = {}));
+    (function(Direction) {
+        Direction[Direction['Left'] = 2] = 'Left';
+    })(Direction || (Direction = {}));
+    "
+);
@@ -14,14 +14,18 @@ use crate::ident::IdentLike;
 use scoped_tls::scoped_thread_local;
 use std::{
     borrow::Cow,
+    collections::{HashMap, HashSet},
     f64::{INFINITY, NAN},
+    hash::Hash,
     num::FpCategory,
     ops::Add,
 };
 use swc_atoms::{js_word, JsWord};
 use swc_common::{errors::Handler, Mark, Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
-use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitMut, VisitMutWith, VisitWith};
+use swc_ecma_visit::{
+    noop_visit_type, Fold, FoldWith, Node, Visit, VisitMut, VisitMutWith, VisitWith,
+};
 use unicode_xid::UnicodeXID;
 
 #[macro_use]
@@ -1640,6 +1644,299 @@ pub fn is_valid_ident(s: &JsWord) -> bool {
     UnicodeXID::is_xid_start(first) && s.chars().skip(1).all(UnicodeXID::is_xid_continue)
 }
 
+/// Canonicalizes a union of string literal types by removing duplicates, e.g.
+/// `"b" | "a" | "b"` becomes `"b" | "a"`. If `sort` is true, the members are
+/// also sorted by value, so the result is `"a" | "b"`.
+///
+/// Other types, including unions with members which are not string literals,
+/// are returned unchanged.
+pub fn normalize_string_union(ty: TsType, sort: bool) -> TsType {
+    fn str_value(ty: &TsType) -> Option<&JsWord> {
+        match ty {
+            TsType::TsLitType(TsLitType {
+                lit: TsLit::Str(s), ..
+            }) => Some(&s.value),
+            _ => None,
+        }
+    }
+
+    let union = match ty {
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(union))
+            if union.types.iter().all(|ty| str_value(ty).is_some()) =>
+        {
+            union
+        }
+        _ => return ty,
+    };
+
+    let mut types = union.types;
+    if sort {
+        types.sort_by(|a, b| str_value(a).cmp(&str_value(b)));
+        types.dedup_by(|a, b| str_value(a) == str_value(b));
+    } else {
+        let mut seen = HashSet::new();
+        types.retain(|ty| seen.insert(str_value(ty).cloned()));
+    }
+
+    if types.len() == 1 {
+        return *types.pop().unwrap();
+    }
+
+    TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(TsUnionType {
+        span: union.span,
+        types,
+    }))
+}
+
+/// Returns true if `any` appears anywhere in `ty`, including type arguments
+/// and parameters of function types.
+pub fn contains_any(ty: &TsType) -> bool {
+    let mut v = AnyFinder { found: false };
+    ty.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+    v.found
+}
+
+struct AnyFinder {
+    found: bool,
+}
+
+impl Visit for AnyFinder {
+    fn visit_ts_keyword_type(&mut self, n: &TsKeywordType, _: &dyn Node) {
+        if n.kind == TsKeywordTypeKind::TsAnyKeyword {
+            self.found = true;
+        }
+    }
+}
+
+/// Returns the element types of `ty` if it's a tuple type which can be used as
+/// the type of a rest parameter, like `(...args: [string, number]) => void`.
+///
+/// Labels are ignored, while optional and rest elements are returned as
+/// [TsOptionalType] and [TsRestType]. Parentheses and `readonly` are looked
+/// through. Tuples with a required element after an optional one, an optional
+/// element after a rest element or multiple rest elements are rejected.
+pub fn tuple_as_rest_params(ty: &TsType) -> Option<Vec<&TsType>> {
+    let tuple = match ty {
+        TsType::TsTupleType(tuple) => tuple,
+        TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. })
+        | TsType::TsTypeOperator(TsTypeOperator {
+            op: TsTypeOperatorOp::ReadOnly,
+            type_ann,
+            ..
+        }) => return tuple_as_rest_params(type_ann),
+        _ => return None,
+    };
+
+    let mut seen_optional = false;
+    let mut seen_rest = false;
+    for elem in &tuple.elem_types {
+        match elem.ty {
+            TsType::TsOptionalType(..) => {
+                if seen_rest {
+                    return None;
+                }
+                seen_optional = true;
+            }
+            TsType::TsRestType(..) => {
+                if seen_rest {
+                    return None;
+                }
+                seen_rest = true;
+            }
+            _ => {
+                if seen_optional {
+                    return None;
+                }
+            }
+        }
+    }
+
+    Some(tuple.elem_types.iter().map(|elem| &elem.ty).collect())
+}
+
+/// Returns true if `ty` is an access by `number`, like `T[number]`, which is
+/// the element type of an array or tuple type `T`.
+///
+/// Parentheses around the index type are looked through.
+pub fn is_array_element_access(ty: &TsIndexedAccessType) -> bool {
+    ty.index_type.keyword_kind() == Some(TsKeywordTypeKind::TsNumberKeyword)
+}
+
+/// Creates a pass which applies `f` to every typescript type, including types
+/// in annotations, declarations and expressions like `x as T`.
+///
+/// Types are mapped bottom-up, so `f` receives types whose children are
+/// already mapped.
+pub fn map_types<F>(f: F) -> impl Fold
+where
+    F: FnMut(TsType) -> TsType,
+{
+    TypeMapper { f }
+}
+
+struct TypeMapper<F> {
+    f: F,
+}
+
+impl<F> Fold for TypeMapper<F>
+where
+    F: FnMut(TsType) -> TsType,
+{
+    fn fold_ts_type(&mut self, ty: TsType) -> TsType {
+        let ty = ty.fold_children_with(self);
+        (self.f)(ty)
+    }
+}
+
+/// Creates a pass which removes parentheses from typescript types where they
+/// don't affect precedence, e.g. `((A | B))` becomes `A | B`.
+///
+/// Parentheses which are required are kept, so `(A | B)[]` is unchanged. Nested
+/// parentheses like `((A | B))[]` are collapsed into a single pair.
+pub fn simplify_ts_types() -> impl Fold {
+    ParenSimplifier
+}
+
+struct ParenSimplifier;
+
+impl Fold for ParenSimplifier {
+    fn fold_ts_type(&mut self, ty: TsType) -> TsType {
+        // Children are unwrapped first, and parentheses which are required are
+        // added back while handling the parent.
+        let ty = ty.fold_children_with(self);
+
+        match ty {
+            TsType::TsParenthesizedType(p) => *p.type_ann,
+            _ => add_required_parens(ty),
+        }
+    }
+}
+
+/// Wraps the types nested directly in `ty` in parentheses if they are
+/// required by precedence, e.g. `A | B` in `(A | B)[]`.
+///
+/// This is useful after replacing types, as the original parentheses are lost
+/// if a type reference is replaced with a union type.
+pub fn add_required_parens(ty: TsType) -> TsType {
+    match ty {
+        TsType::TsArrayType(t) => TsType::TsArrayType(TsArrayType {
+            elem_type: wrap_if_needed(t.elem_type, TsTypeContext::ArrayElement),
+            ..t
+        }),
+        TsType::TsIndexedAccessType(t) => TsType::TsIndexedAccessType(TsIndexedAccessType {
+            obj_type: wrap_if_needed(t.obj_type, TsTypeContext::ArrayElement),
+            ..t
+        }),
+        TsType::TsOptionalType(t) => TsType::TsOptionalType(TsOptionalType {
+            type_ann: wrap_if_needed(t.type_ann, TsTypeContext::ArrayElement),
+            ..t
+        }),
+        TsType::TsTypeOperator(t) => TsType::TsTypeOperator(TsTypeOperator {
+            type_ann: wrap_if_needed(t.type_ann, TsTypeContext::TypeOperatorOperand),
+            ..t
+        }),
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(t)) => {
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(TsUnionType {
+                types: t
+                    .types
+                    .into_iter()
+                    .map(|ty| wrap_if_needed(ty, TsTypeContext::UnionMember))
+                    .collect(),
+                ..t
+            }))
+        }
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(t)) => {
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+                TsIntersectionType {
+                    types: t
+                        .types
+                        .into_iter()
+                        .map(|ty| wrap_if_needed(ty, TsTypeContext::IntersectionMember))
+                        .collect(),
+                    ..t
+                },
+            ))
+        }
+        TsType::TsConditionalType(t) => TsType::TsConditionalType(TsConditionalType {
+            check_type: wrap_if_needed(t.check_type, TsTypeContext::ConditionalOperand),
+            extends_type: wrap_if_needed(t.extends_type, TsTypeContext::ConditionalOperand),
+            ..t
+        }),
+        _ => ty,
+    }
+}
+
+fn wrap_if_needed(ty: Box<TsType>, context: TsTypeContext) -> Box<TsType> {
+    if ty.needs_parens_in(context) {
+        wrap_in_parens(ty)
+    } else {
+        ty
+    }
+}
+
+fn wrap_in_parens(ty: Box<TsType>) -> Box<TsType> {
+    Box::new(TsType::TsParenthesizedType(TsParenthesizedType {
+        span: ty.span(),
+        type_ann: ty,
+    }))
+}
+
+/// Creates a pass which renames the root of entity names, like `A` in `A.B.C`,
+/// according to `map`.
+///
+/// This updates qualified references like `OldNs.Type` after a module is
+/// renamed. Other segments of the names are not changed.
+///
+/// Roots are matched by symbol, so a name declared in an inner scope, which
+/// shadows the module, is renamed as well. Use [rewrite_qualified_root_ids] if
+/// the resolver has been applied.
+pub fn rewrite_qualified_root(map: HashMap<JsWord, JsWord>) -> impl Fold {
+    QualifiedRootRewriter {
+        map,
+        key: |i| i.sym.clone(),
+    }
+}
+
+/// Like [rewrite_qualified_root], but `map` is keyed by [Id].
+///
+/// The syntax context tells apart the module from a name which shadows it in
+/// an inner scope, which is only possible once the resolver has been applied.
+pub fn rewrite_qualified_root_ids(map: HashMap<Id, JsWord>) -> impl Fold {
+    QualifiedRootRewriter {
+        map,
+        key: Ident::to_id,
+    }
+}
+
+struct QualifiedRootRewriter<K> {
+    map: HashMap<K, JsWord>,
+    key: fn(&Ident) -> K,
+}
+
+impl<K> Fold for QualifiedRootRewriter<K>
+where
+    K: Eq + Hash,
+{
+    fn fold_ts_entity_name(&mut self, n: TsEntityName) -> TsEntityName {
+        match n {
+            TsEntityName::Ident(i) => match self.map.get(&(self.key)(&i)) {
+                Some(sym) => TsEntityName::Ident(Ident {
+                    sym: sym.clone(),
+                    ..i
+                }),
+                None => TsEntityName::Ident(i),
+            },
+            TsEntityName::TsQualifiedName(q) => {
+                let q = *q;
+                TsEntityName::TsQualifiedName(Box::new(TsQualifiedName {
+                    left: self.fold_ts_entity_name(q.left),
+                    ..q
+                }))
+            }
+        }
+    }
+}
+
 pub fn drop_span<T>(mut t: T) -> T
 where
     T: VisitMutWith<DropSpan>,
@@ -1878,3 +2175,387 @@ pub fn prop_name_eq(p: &PropName, key: &str) -> bool {
         },
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::vec_box)]
+mod tests {
+    use super::*;
+
+    fn str_lit(value: &str) -> Box<TsType> {
+        Box::new(TsType::TsLitType(TsLitType {
+            span: DUMMY_SP,
+            lit: TsLit::Str(Str {
+                span: DUMMY_SP,
+                value: value.into(),
+                has_escape: false,
+                kind: Default::default(),
+            }),
+        }))
+    }
+
+    fn union(types: Vec<Box<TsType>>) -> TsType {
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(TsUnionType {
+            span: DUMMY_SP,
+            types,
+        }))
+    }
+
+    fn keyword(kind: TsKeywordTypeKind) -> Box<TsType> {
+        Box::new(TsType::TsKeywordType(TsKeywordType {
+            span: DUMMY_SP,
+            kind,
+        }))
+    }
+
+    fn type_ref(name: &str) -> Box<TsType> {
+        Box::new(TsType::TsTypeRef(TsTypeRef {
+            span: DUMMY_SP,
+            type_name: TsEntityName::Ident(quote_ident!(name)),
+            type_params: None,
+        }))
+    }
+
+    fn generic(name: &str, args: Vec<Box<TsType>>) -> TsType {
+        TsType::TsTypeRef(TsTypeRef {
+            span: DUMMY_SP,
+            type_name: TsEntityName::Ident(quote_ident!(name)),
+            type_params: Some(TsTypeParamInstantiation::from_types(args)),
+        })
+    }
+
+    fn array(elem_type: Box<TsType>) -> Box<TsType> {
+        Box::new(TsType::TsArrayType(TsArrayType {
+            span: DUMMY_SP,
+            elem_type,
+        }))
+    }
+
+    fn parens(ty: Box<TsType>) -> Box<TsType> {
+        Box::new(TsType::TsParenthesizedType(TsParenthesizedType {
+            span: DUMMY_SP,
+            type_ann: ty,
+        }))
+    }
+
+    /// `A.B.C` for `["A", "B", "C"]`.
+    fn entity_name(path: &[&str]) -> TsEntityName {
+        path[1..]
+            .iter()
+            .fold(TsEntityName::Ident(quote_ident!(path[0])), |left, right| {
+                TsEntityName::TsQualifiedName(Box::new(TsQualifiedName {
+                    left,
+                    right: quote_ident!(*right),
+                }))
+            })
+    }
+
+    #[test]
+    fn normalize_string_union_dedup_and_sort() {
+        assert_eq!(
+            normalize_string_union(union(vec![str_lit("b"), str_lit("a"), str_lit("b")]), true),
+            union(vec![str_lit("a"), str_lit("b")])
+        );
+        assert_eq!(
+            normalize_string_union(union(vec![str_lit("a"), str_lit("a")]), true),
+            *str_lit("a")
+        );
+    }
+
+    #[test]
+    fn normalize_string_union_dedup_only() {
+        assert_eq!(
+            normalize_string_union(union(vec![str_lit("b"), str_lit("a"), str_lit("b")]), false),
+            union(vec![str_lit("b"), str_lit("a")])
+        );
+        assert_eq!(
+            normalize_string_union(union(vec![str_lit("a"), str_lit("a")]), false),
+            *str_lit("a")
+        );
+    }
+
+    #[test]
+    fn normalize_string_union_mixed() {
+        let mixed = union(vec![
+            str_lit("b"),
+            Box::new(TsType::TsKeywordType(TsKeywordType {
+                span: DUMMY_SP,
+                kind: TsKeywordTypeKind::TsNumberKeyword,
+            })),
+            str_lit("b"),
+        ]);
+
+        assert_eq!(normalize_string_union(mixed.clone(), true), mixed);
+        assert_eq!(normalize_string_union(mixed.clone(), false), mixed);
+    }
+
+    #[test]
+    fn contains_any_in_type_args() {
+        use TsKeywordTypeKind::*;
+
+        assert!(contains_any(&generic("Array", vec![keyword(TsAnyKeyword)])));
+        assert!(contains_any(&generic(
+            "Map",
+            vec![keyword(TsStringKeyword), keyword(TsAnyKeyword)]
+        )));
+        assert!(!contains_any(&generic(
+            "Array",
+            vec![keyword(TsUnknownKeyword)]
+        )));
+    }
+
+    #[test]
+    fn contains_any_in_fn_params() {
+        let fn_type = TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(TsFnType {
+            span: DUMMY_SP,
+            params: vec![TsFnParam::Ident(BindingIdent {
+                id: quote_ident!("x"),
+                type_ann: Some(TsTypeAnn {
+                    span: DUMMY_SP,
+                    type_ann: keyword(TsKeywordTypeKind::TsAnyKeyword),
+                }),
+            })],
+            type_params: None,
+            type_ann: TsTypeAnn {
+                span: DUMMY_SP,
+                type_ann: keyword(TsKeywordTypeKind::TsVoidKeyword),
+            },
+        }));
+
+        assert!(contains_any(&fn_type));
+    }
+
+    #[test]
+    fn map_types_array_to_generic() {
+        fn array_to_generic(ty: TsType) -> TsType {
+            match ty {
+                TsType::TsArrayType(TsArrayType { elem_type, .. }) => {
+                    generic("Array", vec![elem_type])
+                }
+                _ => ty,
+            }
+        }
+        fn module(alias: Box<TsType>, as_type: Box<TsType>) -> Module {
+            Module {
+                span: DUMMY_SP,
+                body: vec![
+                    ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(TsTypeAliasDecl {
+                        span: DUMMY_SP,
+                        declare: false,
+                        id: quote_ident!("A"),
+                        type_params: None,
+                        type_ann: alias,
+                    }))),
+                    ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+                        span: DUMMY_SP,
+                        expr: Box::new(Expr::TsAs(TsAsExpr {
+                            span: DUMMY_SP,
+                            expr: Box::new(Expr::Ident(quote_ident!("x"))),
+                            type_ann: as_type,
+                        })),
+                    })),
+                ],
+                shebang: None,
+            }
+        }
+
+        // type A = T[][]; x as T[];
+        let actual = module(array(array(type_ref("T"))), array(type_ref("T")))
+            .fold_with(&mut map_types(array_to_generic));
+
+        // type A = Array<Array<T>>; x as Array<T>;
+        let expected = module(
+            Box::new(generic(
+                "Array",
+                vec![Box::new(generic("Array", vec![type_ref("T")]))],
+            )),
+            Box::new(generic("Array", vec![type_ref("T")])),
+        );
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn map_types_type_param_default() {
+        fn param(name: &str, default: Option<Box<TsType>>) -> TsTypeParam {
+            TsTypeParam {
+                span: DUMMY_SP,
+                name: quote_ident!(name),
+                is_in: false,
+                is_out: false,
+                is_const: false,
+                constraint: None,
+                default,
+            }
+        }
+
+        // <T, U = Array<T>>
+        let decl = TsTypeParamDecl::from_params(vec![
+            param("T", None),
+            param("U", Some(Box::new(generic("Array", vec![type_ref("T")])))),
+        ]);
+
+        let decl = decl.fold_with(&mut map_types(|ty| match ty {
+            TsType::TsTypeRef(TsTypeRef {
+                type_name: TsEntityName::Ident(ref i),
+                type_params: None,
+                ..
+            }) if &*i.sym == "T" => *keyword(TsKeywordTypeKind::TsNumberKeyword),
+            _ => ty,
+        }));
+
+        assert_eq!(
+            decl[1].default,
+            Some(Box::new(generic(
+                "Array",
+                vec![keyword(TsKeywordTypeKind::TsNumberKeyword)]
+            )))
+        );
+    }
+
+    #[test]
+    fn tuple_as_rest_params_elements() {
+        fn tuple(types: Vec<Box<TsType>>) -> TsType {
+            TsType::TsTupleType(TsTupleType {
+                span: DUMMY_SP,
+                elem_types: types
+                    .into_iter()
+                    .map(|ty| TsTupleElement {
+                        span: DUMMY_SP,
+                        label: None,
+                        ty: *ty,
+                    })
+                    .collect(),
+            })
+        }
+        fn optional(ty: Box<TsType>) -> Box<TsType> {
+            Box::new(TsType::TsOptionalType(TsOptionalType {
+                span: DUMMY_SP,
+                type_ann: ty,
+            }))
+        }
+
+        let string = keyword(TsKeywordTypeKind::TsStringKeyword);
+        let number = keyword(TsKeywordTypeKind::TsNumberKeyword);
+
+        let ty = tuple(vec![string.clone(), number.clone()]);
+        assert_eq!(tuple_as_rest_params(&ty), Some(vec![&*string, &*number]));
+
+        let ty = tuple(vec![string.clone(), optional(number.clone())]);
+        assert_eq!(tuple_as_rest_params(&ty).map(|types| types.len()), Some(2));
+
+        let ty = tuple(vec![optional(string.clone()), number]);
+        assert_eq!(tuple_as_rest_params(&ty), None);
+
+        let ty = TsType::TsArrayType(TsArrayType {
+            span: DUMMY_SP,
+            elem_type: string,
+        });
+        assert_eq!(tuple_as_rest_params(&ty), None);
+    }
+
+    #[test]
+    fn is_array_element_access_by_number() {
+        use TsKeywordTypeKind::*;
+
+        fn indexed(index_type: Box<TsType>) -> TsIndexedAccessType {
+            TsIndexedAccessType {
+                span: DUMMY_SP,
+                readonly: false,
+                obj_type: Box::new(generic("Array", vec![keyword(TsStringKeyword)])),
+                index_type,
+            }
+        }
+
+        assert!(is_array_element_access(&indexed(keyword(TsNumberKeyword))));
+        assert!(is_array_element_access(&indexed(Box::new(
+            TsType::TsParenthesizedType(TsParenthesizedType {
+                span: DUMMY_SP,
+                type_ann: keyword(TsNumberKeyword),
+            })
+        ))));
+        assert!(!is_array_element_access(&indexed(keyword(TsStringKeyword))));
+        assert!(!is_array_element_access(&indexed(str_lit("length"))));
+    }
+
+    #[test]
+    fn simplify_ts_types_parens() {
+        let a_or_b = || Box::new(union(vec![type_ref("A"), type_ref("B")]));
+
+        // ((A | B)) => A | B
+        assert_eq!(
+            parens(parens(a_or_b())).fold_with(&mut simplify_ts_types()),
+            a_or_b()
+        );
+
+        // ((A | B))[] => (A | B)[]
+        assert_eq!(
+            array(parens(parens(a_or_b()))).fold_with(&mut simplify_ts_types()),
+            array(parens(a_or_b()))
+        );
+
+        // ((A))[] => A[]
+        assert_eq!(
+            array(parens(parens(type_ref("A")))).fold_with(&mut simplify_ts_types()),
+            array(type_ref("A"))
+        );
+
+        // (A) | (B[]) => A | B[]
+        assert_eq!(
+            Box::new(union(vec![
+                parens(type_ref("A")),
+                parens(array(type_ref("B")))
+            ]))
+            .fold_with(&mut simplify_ts_types()),
+            Box::new(union(vec![type_ref("A"), array(type_ref("B"))]))
+        );
+    }
+
+    #[test]
+    fn rewrite_qualified_root_renames_root() {
+        let mut map = HashMap::default();
+        map.insert(JsWord::from("A"), JsWord::from("X"));
+
+        assert_eq!(
+            entity_name(&["A", "B", "C"]).fold_with(&mut rewrite_qualified_root(map.clone())),
+            entity_name(&["X", "B", "C"])
+        );
+        assert_eq!(
+            entity_name(&["B", "A", "C"]).fold_with(&mut rewrite_qualified_root(map.clone())),
+            entity_name(&["B", "A", "C"])
+        );
+        assert_eq!(
+            entity_name(&["A"]).fold_with(&mut rewrite_qualified_root(map)),
+            entity_name(&["X"])
+        );
+    }
+
+    #[test]
+    fn rewrite_qualified_root_ids_skips_shadowed() {
+        let mut map = HashMap::default();
+        map.insert(quote_ident!("A").to_id(), JsWord::from("X"));
+
+        assert_eq!(
+            entity_name(&["A", "B"]).fold_with(&mut rewrite_qualified_root_ids(map.clone())),
+            entity_name(&["X", "B"])
+        );
+
+        // An `A` declared in an inner scope has another syntax context.
+        testing::run_test(false, |_, _| {
+            let span = DUMMY_SP.apply_mark(Mark::fresh(Mark::root()));
+            let shadowed = TsEntityName::TsQualifiedName(Box::new(TsQualifiedName {
+                left: TsEntityName::Ident(quote_ident!(span, "A")),
+                right: quote_ident!("B"),
+            }));
+
+            assert_eq!(
+                shadowed
+                    .clone()
+                    .fold_with(&mut rewrite_qualified_root_ids(map)),
+                shadowed
+            );
+
+            Ok(())
+        })
+        .unwrap();
+    }
+}
@@ -532,6 +532,7 @@ define!({
         Var,
         Let,
         Const,
+        Using { is_await: bool },
     }
     pub struct VarDeclarator {
         pub span: Span,
@@ -928,7 +929,7 @@ define!({
         pub span: Span,
         pub specifiers: Vec<ImportSpecifier>,
         pub src: Str,
-        pub type_only: bool,
+        pub import_kind: ImportKind,
         pub asserts: Option<ObjectLit>,
     }
     pub struct ExportAll {
@@ -940,7 +941,7 @@ define!({
         pub span: Span,
         pub specifiers: Vec<ExportSpecifier>,
         pub src: Option<Str>,
-        pub type_only: bool,
+        pub export_kind: ExportKind,
         pub asserts: Option<ObjectLit>,
     }
     pub struct ExportDefaultDecl {
@@ -969,6 +970,7 @@ define!({
         pub span: Span,
         pub local: Ident,
         pub imported: Option<Ident>,
+        pub import_kind: ImportKind,
     }
     pub enum ExportSpecifier {
         Namespace(ExportNamespaceSpecifier),
@@ -986,6 +988,15 @@ define!({
         pub span: Span,
         pub orig: Ident,
         pub exported: Option<Ident>,
+        pub export_kind: ExportKind,
+    }
+    pub enum ImportKind {
+        Value,
+        Type,
+    }
+    pub enum ExportKind {
+        Value,
+        Type,
     }
     pub enum BinaryOp {
         EqEq,
@@ -1273,6 +1284,9 @@ define!({
     pub struct TsTypeParam {
         pub span: Span,
         pub name: Ident,
+        pub is_in: bool,
+        pub is_out: bool,
+        pub is_const: bool,
         pub constraint: Option<Box<TsType>>,
         pub default: Option<Box<TsType>>,
     }
@@ -1285,6 +1299,7 @@ define!({
         pub decorators: Vec<Decorator>,
         pub accessibility: Option<Accessibility>,
         pub readonly: bool,
+        pub is_override: bool,
         pub param: TsParamPropParam,
     }
     pub enum TsParamPropParam {
@@ -17,6 +17,67 @@ impl Parse for Args {
     }
 }
 
+/// Implements `NodeType` for an enum.
+///
+/// Variants with a single tag return it, and the others, including wildcard
+/// `#[tag("*")]` variants, return the type of the node they contain.
+pub fn expand_enum(i: &DeriveInput) -> ItemImpl {
+    let data = match i.data {
+        Data::Enum(ref data) => data,
+        _ => unreachable!("expand_enum is called with none-enum item"),
+    };
+
+    let mut arms = Quote::new_call_site();
+    for variant in &data.variants {
+        let tags = crate::enum_deserialize::variant_tags(variant);
+        let tag = match tags.first() {
+            Some(Lit::Str(s)) if tags.len() == 1 && s.value() != "*" => Some(s.clone()),
+            _ => None,
+        };
+
+        arms = match tag {
+            Some(tag) => arms.quote_with(smart_quote!(
+                Vars {
+                    Enum: &i.ident,
+                    Variant: &variant.ident,
+                    tag,
+                },
+                {
+                    Enum::Variant(..) => tag,
+                }
+            )),
+            None => arms.quote_with(smart_quote!(
+                Vars {
+                    Enum: &i.ident,
+                    Variant: &variant.ident,
+                },
+                {
+                    Enum::Variant(node) => ::swc_common::NodeType::node_type(node),
+                }
+            )),
+        };
+    }
+
+    Quote::new_call_site()
+        .quote_with(smart_quote!(
+            Vars {
+                Enum: &i.ident,
+                arms,
+            },
+            {
+                impl ::swc_common::NodeType for Enum {
+                    fn node_type(&self) -> &'static str {
+                        match self {
+                            arms
+                        }
+                    }
+                }
+            }
+        ))
+        .parse::<ItemImpl>()
+        .with_generics(i.generics.clone())
+}
+
 pub fn expand_struct(args: Args, i: DeriveInput) -> Vec<ItemImpl> {
     let mut items = vec![];
     let generics = i.generics.clone();
@@ -36,6 +97,24 @@ pub fn expand_struct(args: Args, i: DeriveInput) -> Vec<ItemImpl> {
                 }
             ))
             .parse::<ItemImpl>()
+            .with_generics(generics.clone()),
+    );
+
+    items.push(
+        Quote::new_call_site()
+            .quote_with(smart_quote!(
+                Vars {
+                    Type: i.ident.clone(),
+                },
+                {
+                    impl ::swc_common::NodeType for Type {
+                        fn node_type(&self) -> &'static str {
+                            <Self as ::swc_common::AstNode>::TYPE
+                        }
+                    }
+                }
+            ))
+            .parse::<ItemImpl>()
             .with_generics(generics),
     );
 
@@ -22,8 +22,43 @@ impl Parse for VariantAttr {
     }
 }
 
+/// Returns the values of `#[tag]` attributes of a variant.
+pub(crate) fn variant_tags(variant: &Variant) -> Punctuated<Lit, token::Comma> {
+    variant
+        .attrs
+        .iter()
+        .filter_map(|attr| -> Option<VariantAttr> {
+            if !is_attr_name(attr, "tag") {
+                return None;
+            }
+            let tags = parse2(attr.tokens.clone()).expect("failed to parse #[tag] attribute");
+
+            Some(tags)
+        })
+        .flat_map(|v| v.tags)
+        .collect()
+}
+
+/// `#[deserialize_guard(Guard)]`
+struct GuardAttr {
+    _paren_token: token::Paren,
+    ty: Type,
+}
+
+impl Parse for GuardAttr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let content;
+        let _paren_token = parenthesized!(content in input);
+        Ok(GuardAttr {
+            _paren_token,
+            ty: content.parse()?,
+        })
+    }
+}
+
 pub fn expand(
     DeriveInput {
+        attrs,
         generics,
         ident,
         data,
@@ -35,6 +70,23 @@ pub fn expand(
         _ => unreachable!("expand_enum is called with none-enum item"),
     };
 
+    // The guard is entered while the input is buffered, because buffering
+    // recurses into the whole input before any variant is deserialized.
+    let content_deserializer = match attrs
+        .iter()
+        .find(|attr| is_attr_name(attr, "deserialize_guard"))
+    {
+        Some(attr) => {
+            let GuardAttr { ty, .. } = parse2(attr.tokens.clone())
+                .expect("failed to parse #[deserialize_guard] attribute");
+
+            Quote::new_call_site().quote_with(smart_quote!(Vars { Guard: ty }, {
+                swc_common::serializer::GuardedDeserializer::<D, Guard>::new(Deserializer)
+            }))
+        }
+        None => Quote::new_call_site().quote_with(smart_quote!(Vars {}, { Deserializer })),
+    };
+
     let deserialize = {
         let mut all_tags: Punctuated<_, token::Comma> = Default::default();
         let match_type = data
@@ -55,20 +107,7 @@ pub fn expand(
                         unreachable!("#[ast_node] enum cannot contain named fields or unit variant")
                     }
                 };
-                let tags = variant
-                    .attrs
-                    .iter()
-                    .filter_map(|attr| -> Option<VariantAttr> {
-                        if !is_attr_name(attr, "tag") {
-                            return None;
-                        }
-                        let tags =
-                            parse2(attr.tokens.clone()).expect("failed to parse #[tag] attribute");
-
-                        Some(tags)
-                    })
-                    .flat_map(|v| v.tags)
-                    .collect::<Punctuated<_, token::Comma>>();
+                let tags = variant_tags(variant);
 
                 assert!(
                     !tags.is_empty(),
@@ -150,6 +189,7 @@ pub fn expand(
             .quote_with(smart_quote!(
                 Vars {
                     match_type_expr,
+                    content_deserializer,
                     Enum: &ident
                 },
                 {
@@ -161,7 +201,7 @@ pub fn expand(
                         {
                             let content =
                                 <swc_common::private::serde::de::Content as serde::Deserialize>::deserialize(
-                                    Deserializer,
+                                    content_deserializer,
                                 )?;
 
                             let ty = swc_common::serializer::Type::deserialize(
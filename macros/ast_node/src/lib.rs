@@ -25,7 +25,7 @@ pub fn derive_spanned(input: proc_macro::TokenStream) -> proc_macro::TokenStream
     )
 }
 
-#[proc_macro_derive(DeserializeEnum, attributes(tag))]
+#[proc_macro_derive(DeserializeEnum, attributes(tag, deserialize_guard))]
 pub fn derive_deserialize_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse::<DeriveInput>(input).expect("failed to parse input as DeriveInput");
 
@@ -53,6 +53,15 @@ pub fn derive_deserialize_enum(input: proc_macro::TokenStream) -> proc_macro::To
 ///
 /// This macro does not accept arguments if used on enum.
 ///
+/// ### `#[deserialize_guard(path::to::Guard)]`
+///
+/// Calls `Guard::enter::<D::Error>()` of
+/// `swc_common::serializer::DeserializeGuard` for every nested object and
+/// array while the input of the enum is buffered, and keeps the returned
+/// value until the object or array ends. If it returns an error, the error is
+/// returned instead of the node. This can be used to limit the depth of
+/// recursive nodes.
+///
 /// ## Variant attributes
 ///
 /// ### `#[tag("Expr")]`
@@ -150,6 +159,8 @@ pub fn ast_serde(
 /// `#[derive(Spanned, Fold, Clone, Debug, PartialEq, FromVariant)]` for an
 /// enum.
 ///
+/// `swc_common::NodeType` is implemented as well, for structs with a type and
+/// for enums.
 ///
 /// TODO: Delete works related to serde to `#[ast_serde]`.
 #[proc_macro_attribute]
@@ -167,7 +178,9 @@ pub fn ast_node(
                 panic!("#[ast_node] on enum does not accept any argument")
             }
 
-            item.quote_with(smart_quote!(Vars { input }, {
+            let node_type = ast_node_macro::expand_enum(&input);
+
+            item.quote_with(smart_quote!(Vars { input, node_type }, {
                 #[derive(
                     ::swc_common::FromVariant,
                     ::swc_common::Spanned,
@@ -179,6 +192,8 @@ pub fn ast_node(
                 )]
                 #[serde(untagged)]
                 input
+
+                node_type
             }))
         }
         _ => {
@@ -161,7 +161,10 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
                             for_of: compat::es2015::for_of::Config {
                                 assume_array: self.loose
                             },
-                            spread: compat::es2015::spread::Config { loose: self.loose },
+                            spread: compat::es2015::spread::Config {
+                                loose: self.loose,
+                                ..Default::default()
+                            },
                             destructuring: compat::es2015::destructuring::Config {
                                 loose: self.loose
                             },
@@ -246,7 +246,10 @@ impl Compiler {
                 let mut buf = vec![];
                 {
                     let mut emitter = Emitter {
-                        cfg: swc_ecma_codegen::Config { minify },
+                        cfg: swc_ecma_codegen::Config {
+                            minify,
+                            ..Default::default()
+                        },
                         comments: if minify { None } else { Some(&self.comments) },
                         cm: self.cm.clone(),
                         wr: Box::new(swc_ecma_codegen::text_writer::JsWriter::with_target(